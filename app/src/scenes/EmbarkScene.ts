@@ -23,6 +23,8 @@ import {
   createSetDelegateInstruction,
   type HeroLockStatus,
   createDelegateAdventureInstruction,
+  adventureNeedsMigration,
+  createMigrateAdventureInstruction,
 } from "../state/adventureChain";
 import {
   fetchPlayerEconomy,
@@ -1333,6 +1335,25 @@ export class EmbarkScene extends Phaser.Scene {
     const [adventurePda] = deriveAdventurePda(playerKey, dungeonPubkey);
     let existingAdventure: any = null;
 
+    try {
+      if (await adventureNeedsMigration(connection, adventurePda)) {
+        console.log(
+          "[EmbarkScene] Adventure session predates the current layout, migrating..."
+        );
+        await this.sendProgramTransaction([
+          await createMigrateAdventureInstruction({
+            connection,
+            payer: playerKey,
+            adventurePda,
+          }),
+        ]);
+      }
+    } catch (err) {
+      console.error("[EmbarkScene] Failed to migrate adventure:", err);
+      this.store.toast("Failed to update your adventure account.");
+      return;
+    }
+
     try {
       existingAdventure = await fetchAdventureSessionSmart(
         connection,
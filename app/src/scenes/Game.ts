@@ -3080,6 +3080,33 @@ export default class Game extends Phaser.Scene {
     });
   }
 
+  // Out of combat there is no target picker: feathers go to the first fallen
+  // hero, anything else to the living hero who needs it most.
+  private pickItemTarget(id: keyof typeof ITEM_DEFINITIONS): number {
+    const snapshots = (this.adventureSession?.heroSnapshots ?? []).slice(
+      0,
+      this.partyLength
+    );
+    if (id === "phoenix_feather") {
+      return Math.max(0, snapshots.findIndex((hero) => hero.currentHp === 0));
+    }
+    const need = (hero: ChainHeroSnapshot) =>
+      id === "healing_salve"
+        ? 1 - hero.currentHp / Math.max(1, hero.maxHp)
+        : hero.stress / Math.max(1, hero.stressMax);
+    let best = 0;
+    snapshots.forEach((hero, index) => {
+      const current = snapshots[best];
+      if (
+        hero.currentHp > 0 &&
+        (current.currentHp === 0 || need(hero) > need(current))
+      ) {
+        best = index;
+      }
+    });
+    return best;
+  }
+
   private async tryUseItem(id: keyof typeof ITEM_DEFINITIONS) {
     const def = ITEM_DEFINITIONS[id];
     if (!def?.usable) return;
@@ -3112,6 +3139,7 @@ export default class Game extends Phaser.Scene {
         adventurePda: this.adventurePda,
         itemKey,
         quantity: 1,
+        heroIndex: this.pickItemTarget(id),
       });
 
       const { blockhash, lastValidBlockHeight } = await eph.getLatestBlockhash(
@@ -68,13 +68,12 @@ import { TooltipManager } from "./town/ui/TooltipManager";
 import { RosterPanel, RosterPanelState } from "./town/RosterPanel";
 import { formatHeroTimestamp } from "./town/heroFormatting";
 import {
-  createGrantHourlyGoldInstruction,
+  createClaimDailyGoldInstruction,
   createBuyItemInstruction,
   createSellItemInstruction,
   createInitializeEconomyInstruction,
   fetchPlayerEconomy,
-  HOURLY_GRANT_AMOUNT,
-  HOURLY_GRANT_COOLDOWN_SECONDS,
+  nextFaucetDayStart,
 } from "../state/economyChain";
 
 type ToastEntry = {
@@ -107,6 +106,7 @@ type SolanaProvider = {
 };
 
 const PLAYER_ECONOMY_COOLDOWN_ERROR = 6010;
+const PLAYER_ECONOMY_FAUCET_CAP_ERROR = 6029;
 
 export class TownScene extends Phaser.Scene {
   private safe = SAFE_MARGIN;
@@ -1100,7 +1100,11 @@ export class TownScene extends Phaser.Scene {
       ? Math.max(1, Math.ceil((this.nextGrantAvailableAt - now) / 60000))
       : 0;
     const cooldownText =
-      cooldownMinutes > 0 ? `Cooldown ~${cooldownMinutes}m` : "On cooldown";
+      cooldownMinutes >= 60
+        ? `Next claim ~${Math.ceil(cooldownMinutes / 60)}h`
+        : cooldownMinutes > 0
+        ? `Next claim ~${cooldownMinutes}m`
+        : "On cooldown";
     const ready =
       hasProvider &&
       connected &&
@@ -1141,7 +1145,7 @@ export class TownScene extends Phaser.Scene {
       ? "Claiming..."
       : onCooldown
       ? cooldownText
-      : "Claim 200 gold";
+      : "Claim daily gold";
     const statusColor = ready ? "#ffe28a" : "#c1c6db";
 
     this.grantStatusText = this.add
@@ -1158,7 +1162,7 @@ export class TownScene extends Phaser.Scene {
         .setInteractive({ cursor: "pointer" })
         .on("pointerover", () => plate.setFillStyle(hoverColor))
         .on("pointerout", () => plate.setFillStyle(baseColor))
-        .on("pointerdown", () => this.requestHourlyGrant());
+        .on("pointerdown", () => this.requestDailyGrant());
     } else {
       const cursor =
         this.walletBusy || this.programBusy || this.grantBusy
@@ -1186,7 +1190,7 @@ export class TownScene extends Phaser.Scene {
     }
   }
 
-  private async requestHourlyGrant() {
+  private async requestDailyGrant() {
     if (!this.walletAddress) {
       this.showToast("Connect your wallet first.");
       return;
@@ -1219,32 +1223,36 @@ export class TownScene extends Phaser.Scene {
         this.showToast("Initializing your economy account...");
       }
 
-      const grantIx = createGrantHourlyGoldInstruction(owner);
+      const grantIx = createClaimDailyGoldInstruction(owner);
       instructions.push(grantIx);
 
+      const goldBefore = this.store.getInventory().gold;
       await this.sendProgramTransaction(instructions);
-      this.showToast(`Vault credited with ${HOURLY_GRANT_AMOUNT} gold.`);
 
       // Add a small delay to allow for blockchain propagation
       await new Promise((resolve) => setTimeout(resolve, 1500));
 
-      // Sync from chain to update local state
+      // Sync from chain to update local state; the reward depends on streak and balance
       await this.syncEconomyFromChain();
+      const credited = this.store.getInventory().gold - goldBefore;
+      this.showToast(
+        credited > 0
+          ? `Vault credited with ${credited} gold.`
+          : "Daily gold claimed."
+      );
 
-      this.nextGrantAvailableAt =
-        Date.now() + HOURLY_GRANT_COOLDOWN_SECONDS * 1000;
+      this.nextGrantAvailableAt = nextFaucetDayStart(Date.now());
       this.updateGrantButton();
     } catch (err) {
       const code = this.extractCustomErrorCode(err);
       if (code === PLAYER_ECONOMY_COOLDOWN_ERROR) {
-        this.showToast("Hourly grant still on cooldown.");
-        const retryAt = Date.now() + 60 * 1000;
-        if (this.nextGrantAvailableAt < retryAt) {
-          this.nextGrantAvailableAt = retryAt;
-        }
+        this.showToast("Daily gold already claimed today.");
+        this.nextGrantAvailableAt = nextFaucetDayStart(Date.now());
         this.updateGrantButton();
+      } else if (code === PLAYER_ECONOMY_FAUCET_CAP_ERROR) {
+        this.showToast("You have claimed all the faucet gold available.");
       } else {
-        this.handleProgramError(err, "Failed to claim hourly grant.");
+        this.handleProgramError(err, "Failed to claim daily gold.");
       }
     } finally {
       this.grantBusy = false;
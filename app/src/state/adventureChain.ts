@@ -14,6 +14,7 @@ import {
 import adventureIdl from "../idl/adventure_engine.json";
 import type { AdventureEngine } from "../types/adventure_engine";
 import { TRAIT_NONE } from "./traitCatalog";
+import { deriveEconomyStatsPda } from "./economyChain";
 export { TRAIT_NONE } from "./traitCatalog";
 
 export const ADVENTURE_ENGINE_PROGRAM_ID = new PublicKey(adventureIdl.address);
@@ -27,6 +28,7 @@ const ADVENTURE_SEED = Buffer.from("adventure");
 const HERO_LOCK_SEED = Buffer.from("hero-lock");
 const BUFFER_SEED = Buffer.from("buffer");
 const PLAYER_ECONOMY_SEED = Buffer.from("player_economy");
+const ITEM_REGISTRY_SEED = Buffer.from("item_registry");
 
 // Create instruction coder
 const instructionCoder = new BorshInstructionCoder(adventureIdl as any);
@@ -373,12 +375,18 @@ export async function createStartAdventureInstruction(options: {
   );
 
   const [adventurePda] = deriveAdventurePda(player, dungeonMint);
+  const [combatPda] = deriveCombatPda(adventurePda);
   const heroLockPdas = sortedHeroMints.map((m) => deriveHeroLockPda(m)[0]);
 
   const [playerEconomyPda] = PublicKey.findProgramAddressSync(
     [PLAYER_ECONOMY_SEED, player.toBuffer()],
     PLAYER_ECONOMY_PROGRAM_ID
   );
+  const [itemRegistryPda] = PublicKey.findProgramAddressSync(
+    [ITEM_REGISTRY_SEED],
+    PLAYER_ECONOMY_PROGRAM_ID
+  );
+  const [economyStatsPda] = deriveEconomyStatsPda(player);
 
   const data = instructionCoder.encode("start_adventure", {
     hero_mints: sortedHeroMints,
@@ -389,7 +397,10 @@ export async function createStartAdventureInstruction(options: {
     { pubkey: player, isSigner: true, isWritable: true },
     { pubkey: dungeonMint, isSigner: false, isWritable: false },
     { pubkey: adventurePda, isSigner: false, isWritable: true },
+    { pubkey: combatPda, isSigner: false, isWritable: true },
     { pubkey: playerEconomyPda, isSigner: false, isWritable: true },
+    { pubkey: itemRegistryPda, isSigner: false, isWritable: false },
+    { pubkey: economyStatsPda, isSigner: false, isWritable: true },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     { pubkey: HERO_CORE_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
@@ -412,6 +423,99 @@ export async function createStartAdventureInstruction(options: {
   return { instruction: ix, adventurePda, heroLockPdas };
 }
 
+// Mirrors AdventureSession::space in programs/adventure-engine/src/state.rs
+const SESSION_LIMITS = {
+  rooms: 40,
+  doors: 64,
+  chests: 64,
+  portals: 8,
+  traps: 32,
+  curios: 16,
+  party: 4,
+  items: 6,
+};
+const HERO_SNAPSHOT_SIZE = 37;
+const STATUS_INSTANCE_SIZE = 3;
+const ITEM_SLOT_SIZE = 3;
+const DUNGEON_POINT_SIZE = 4;
+const DUNGEON_ROOM_SIZE = 8;
+// Offset of `width` in the account data; `height` follows it
+const SESSION_WIDTH_OFFSET = 8 + 32 + 32 + 1 + 4;
+
+export function adventureSessionSpace(width: number, height: number): number {
+  const { rooms, doors, chests, portals, traps, curios, party, items } =
+    SESSION_LIMITS;
+  const fixed =
+    84 +
+    32 * party +
+    HERO_SNAPSHOT_SIZE * party +
+    DUNGEON_POINT_SIZE +
+    1 +
+    ITEM_SLOT_SIZE * items +
+    2 +
+    ITEM_SLOT_SIZE * items +
+    33 +
+    1 +
+    DUNGEON_POINT_SIZE +
+    32 +
+    1 +
+    32 * party +
+    2 +
+    32 +
+    8 +
+    STATUS_INSTANCE_SIZE * party +
+    6 +
+    party;
+  const vectors =
+    4 +
+    Math.min(width * height, 10_000) +
+    (4 + rooms * DUNGEON_ROOM_SIZE) +
+    (4 + doors * DUNGEON_POINT_SIZE) +
+    (4 + chests * DUNGEON_POINT_SIZE) +
+    (4 + portals * DUNGEON_POINT_SIZE) +
+    (4 + traps * DUNGEON_POINT_SIZE) +
+    (4 + chests) +
+    (4 + portals) +
+    (4 + traps) +
+    (4 + doors) * 2 +
+    (4 + curios * DUNGEON_POINT_SIZE) +
+    (4 + curios) * 2;
+  return fixed + vectors;
+}
+
+/** Sessions created before the trap/door/curio fields must be migrated before use. */
+export async function adventureNeedsMigration(
+  connection: Connection,
+  adventurePda: PublicKey
+): Promise<boolean> {
+  const info = await connection.getAccountInfo(adventurePda);
+  if (!info || !info.owner.equals(ADVENTURE_ENGINE_PROGRAM_ID)) {
+    return false;
+  }
+  const width = info.data.readUInt16LE(SESSION_WIDTH_OFFSET);
+  const height = info.data.readUInt16LE(SESSION_WIDTH_OFFSET + 2);
+  return info.data.length < adventureSessionSpace(width, height);
+}
+
+export async function createMigrateAdventureInstruction(options: {
+  connection: Connection;
+  payer: PublicKey;
+  adventurePda: PublicKey;
+}): Promise<TransactionInstruction> {
+  const { connection, payer, adventurePda } = options;
+  const program = getAdventureProgram(connection, payer);
+
+  return await program.methods
+    .migrateAdventure()
+    .accountsPartial({
+      payer,
+      adventure: adventurePda,
+      combat: deriveCombatPda(adventurePda)[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
 export async function createSetDelegateInstruction(options: {
   connection: Connection;
   payer: PublicKey;
@@ -454,9 +558,17 @@ export async function createDelegateAdventureInstruction(options: {
     ? [{ pubkey: validator, isSigner: false, isWritable: false }]
     : [];
 
+  const [combatPda] = deriveCombatPda(adventurePda);
+
   const ix = await program.methods
     .delegateAdventure()
-    .accountsPartial({ payer, pda: adventurePda, owner, dungeonMint })
+    .accountsPartial({
+      payer,
+      pda: adventurePda,
+      combat: combatPda,
+      owner,
+      dungeonMint,
+    })
     .remainingAccounts(rem)
     .instruction();
 
@@ -573,6 +685,8 @@ export async function createExitAdventureInstruction(options: {
     PLAYER_ECONOMY_PROGRAM_ID
   );
 
+  const [combatPda] = deriveCombatPda(adventurePda);
+
   // Always pass hero accounts to unlock them via CPI to hero-core
   const remainingAccounts = [];
 
@@ -599,6 +713,7 @@ export async function createExitAdventureInstruction(options: {
       owner,
       authority,
       adventure: adventurePda,
+      combat: combatPda,
       heroProgram: HERO_CORE_PROGRAM_ID,
       dungeon: dungeonMint,
       playerEconomy: playerEconomyPda,
@@ -717,6 +832,7 @@ export async function createSwapItemInstruction(options: {
   return instruction;
 }
 
+/** `heroIndex` picks the hero a salve, tonic or feather acts on; the torch ignores it. */
 export async function createUseItemInstruction(options: {
   connection: Connection;
   owner: PublicKey;
@@ -724,13 +840,21 @@ export async function createUseItemInstruction(options: {
   adventurePda: PublicKey;
   itemKey: number;
   quantity: number;
+  heroIndex: number;
 }): Promise<TransactionInstruction> {
-  const { connection, owner, authority, adventurePda, itemKey, quantity } =
-    options;
+  const {
+    connection,
+    owner,
+    authority,
+    adventurePda,
+    itemKey,
+    quantity,
+    heroIndex,
+  } = options;
   const program = getAdventureProgram(connection, owner);
 
   const instruction = await program.methods
-    .useItem(itemKey, quantity)
+    .useItem(itemKey, quantity, heroIndex)
     .accountsPartial({
       owner,
       authority,
@@ -1161,7 +1285,6 @@ export async function createBeginEncounterInstruction(options: {
       authority,
       adventure: adventureKey,
       combat: combatPda,
-      systemProgram: SystemProgram.programId,
     })
     .instruction();
 }
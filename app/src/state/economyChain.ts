@@ -11,15 +11,25 @@ export const PLAYER_ECONOMY_PROGRAM_ID = new PublicKey(
 );
 
 export const PLAYER_ECONOMY_SEED = Buffer.from("player_economy");
+const ITEM_REGISTRY_SEED = Buffer.from("item_registry");
+const SHOP_STATE_SEED = Buffer.from("shop_state");
+const FAUCET_CONFIG_SEED = Buffer.from("faucet_config");
+const FAUCET_LEDGER_SEED = Buffer.from("faucet_ledger");
+const ECONOMY_STATS_SEED = Buffer.from("economy_stats");
+// Mirrors ECONOMY_STATS_SHARDS
+const ECONOMY_STATS_SHARDS = 16;
 
-export const HOURLY_GRANT_AMOUNT = 200;
-export const HOURLY_GRANT_COOLDOWN_SECONDS = 60 * 60;
+// Mirrors LEGACY_ITEM_COUNTS
+const LEGACY_ITEM_COUNTS = [7, 8];
+
+// Mirrors FAUCET_DAY_SECONDS; one claim per UTC day
+export const FAUCET_DAY_SECONDS = 24 * 60 * 60;
 
 const DISCRIMINATOR = {
   initialize: Buffer.from([229, 164, 233, 147, 180, 135, 222, 91]),
   buy: Buffer.from([80, 82, 193, 201, 216, 27, 70, 184]),
   sell: Buffer.from([44, 114, 171, 76, 76, 10, 150, 246]),
-  grant: Buffer.from([209, 229, 124, 21, 172, 112, 115, 169]),
+  claimDaily: Buffer.from([88, 84, 240, 189, 151, 7, 176, 10]),
 };
 
 const ITEM_KEY_TO_INDEX: Record<ItemId, number> = {
@@ -39,6 +49,15 @@ export function derivePlayerEconomyPda(owner: PublicKey): [PublicKey, number] {
   );
 }
 
+// Each player always records into the same shard; totals are the sum of all shards
+export function deriveEconomyStatsPda(owner: PublicKey): [PublicKey, number] {
+  const shard = owner.toBuffer()[0] % ECONOMY_STATS_SHARDS;
+  return PublicKey.findProgramAddressSync(
+    [ECONOMY_STATS_SEED, Buffer.from([shard])],
+    PLAYER_ECONOMY_PROGRAM_ID
+  );
+}
+
 export function createInitializeEconomyInstruction(owner: PublicKey) {
   const [playerEconomy] = derivePlayerEconomyPda(owner);
   return new TransactionInstruction({
@@ -52,6 +71,26 @@ export function createInitializeEconomyInstruction(owner: PublicKey) {
   });
 }
 
+function shopItemKeys(owner: PublicKey) {
+  const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [itemRegistry] = PublicKey.findProgramAddressSync(
+    [ITEM_REGISTRY_SEED],
+    PLAYER_ECONOMY_PROGRAM_ID
+  );
+  const [shopState] = PublicKey.findProgramAddressSync(
+    [SHOP_STATE_SEED],
+    PLAYER_ECONOMY_PROGRAM_ID
+  );
+  const [economyStats] = deriveEconomyStatsPda(owner);
+  return [
+    { pubkey: owner, isSigner: true, isWritable: true },
+    { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: itemRegistry, isSigner: false, isWritable: false },
+    { pubkey: shopState, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
+  ];
+}
+
 export function createBuyItemInstruction(
   owner: PublicKey,
   item: ItemId,
@@ -63,17 +102,13 @@ export function createBuyItemInstruction(
   if (!Number.isFinite(quantity) || quantity <= 0 || quantity > 65535) {
     throw new Error(`Invalid quantity ${quantity}`);
   }
-  const [playerEconomy] = derivePlayerEconomyPda(owner);
   const payload = Buffer.alloc(11);
   DISCRIMINATOR.buy.copy(payload, 0);
   payload.writeUInt8(ITEM_KEY_TO_INDEX[item], 8);
   payload.writeUInt16LE(quantity, 9);
   return new TransactionInstruction({
     programId: PLAYER_ECONOMY_PROGRAM_ID,
-    keys: [
-      { pubkey: owner, isSigner: true, isWritable: true },
-      { pubkey: playerEconomy, isSigner: false, isWritable: true },
-    ],
+    keys: shopItemKeys(owner),
     data: payload,
   });
 }
@@ -89,38 +124,53 @@ export function createSellItemInstruction(
   if (!Number.isFinite(quantity) || quantity <= 0 || quantity > 65535) {
     throw new Error(`Invalid quantity ${quantity}`);
   }
-  const [playerEconomy] = derivePlayerEconomyPda(owner);
   const payload = Buffer.alloc(11);
   DISCRIMINATOR.sell.copy(payload, 0);
   payload.writeUInt8(ITEM_KEY_TO_INDEX[item], 8);
   payload.writeUInt16LE(quantity, 9);
   return new TransactionInstruction({
     programId: PLAYER_ECONOMY_PROGRAM_ID,
-    keys: [
-      { pubkey: owner, isSigner: true, isWritable: true },
-      { pubkey: playerEconomy, isSigner: false, isWritable: true },
-    ],
+    keys: shopItemKeys(owner),
     data: payload,
   });
 }
 
-export function createGrantHourlyGoldInstruction(owner: PublicKey) {
+export function createClaimDailyGoldInstruction(owner: PublicKey) {
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [faucetConfig] = PublicKey.findProgramAddressSync(
+    [FAUCET_CONFIG_SEED],
+    PLAYER_ECONOMY_PROGRAM_ID
+  );
+  const [faucetLedger] = PublicKey.findProgramAddressSync(
+    [FAUCET_LEDGER_SEED, owner.toBuffer()],
+    PLAYER_ECONOMY_PROGRAM_ID
+  );
+  const [economyStats] = deriveEconomyStatsPda(owner);
   return new TransactionInstruction({
     programId: PLAYER_ECONOMY_PROGRAM_ID,
     keys: [
       { pubkey: owner, isSigner: true, isWritable: true },
       { pubkey: playerEconomy, isSigner: false, isWritable: true },
+      { pubkey: faucetConfig, isSigner: false, isWritable: false },
+      { pubkey: faucetLedger, isSigner: false, isWritable: true },
+      { pubkey: economyStats, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
-    data: DISCRIMINATOR.grant,
+    data: DISCRIMINATOR.claimDaily,
   });
 }
 
+/** Start of the next faucet day, in milliseconds. */
+export function nextFaucetDayStart(nowMs: number): number {
+  const dayMs = FAUCET_DAY_SECONDS * 1000;
+  return (Math.floor(nowMs / dayMs) + 1) * dayMs;
+}
+
 export interface PlayerEconomyAccount {
   owner: PublicKey;
   gold: bigint;
   lastGrantTs: bigint;
-  items: number[]; // Array of 7 u16 values
+  items: number[]; // Quantity per item index, 0 when no stack is held
   bump: number;
 }
 
@@ -148,11 +198,12 @@ export async function fetchPlayerEconomy(
     // 32 bytes: owner (Pubkey)
     // 8 bytes: gold (u64)
     // 8 bytes: last_grant_ts (i64)
-    // 14 bytes: items array (7 x u16)
     // 1 byte: bump
-    // 5 bytes: reserved
+    // 1 byte: stash_tier
+    // 4 bytes: reserved
+    // 4 bytes: item stack count (u32), then 3 bytes per stack (u8 item, u16 quantity)
 
-    if (data.length < 76) {
+    if (data.length < 66) {
       console.warn("PlayerEconomy account data too short");
       return null;
     }
@@ -172,15 +223,40 @@ export async function fetchPlayerEconomy(
     const lastGrantTs = data.readBigInt64LE(offset);
     offset += 8;
 
-    // Read items array (7 x u16 = 14 bytes)
-    const items: number[] = [];
-    for (let i = 0; i < 7; i++) {
-      items.push(data.readUInt16LE(offset));
-      offset += 2;
+    const items: number[] = new Array(
+      Object.keys(ITEM_KEY_TO_INDEX).length
+    ).fill(0);
+
+    // Accounts not yet migrated still hold a fixed u16 per item before the bump
+    const legacyItemCount = LEGACY_ITEM_COUNTS.find(
+      (count) => data.length === 62 + 2 * count
+    );
+    if (legacyItemCount !== undefined) {
+      for (let i = 0; i < legacyItemCount; i++) {
+        if (i < items.length) {
+          items[i] = data.readUInt16LE(offset);
+        }
+        offset += 2;
+      }
+      const bump = data.readUInt8(offset);
+      return { owner: accountOwner, gold, lastGrantTs, items, bump };
     }
 
-    // Read bump (1 byte)
+    // Read bump (1 byte), skipping stash_tier and reserved
     const bump = data.readUInt8(offset);
+    offset += 1 + 1 + 4;
+
+    // Read item stacks; items without a client-side key are ignored
+    const stackCount = data.readUInt32LE(offset);
+    offset += 4;
+    for (let i = 0; i < stackCount && offset + 3 <= data.length; i++) {
+      const item = data.readUInt8(offset);
+      const quantity = data.readUInt16LE(offset + 1);
+      offset += 3;
+      if (item < items.length) {
+        items[item] = quantity;
+      }
+    }
 
     return {
       owner: accountOwner,
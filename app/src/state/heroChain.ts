@@ -10,6 +10,7 @@ import { Buffer } from "buffer";
 import heroIdl from "../idl/hero_core.json";
 import {
   PLAYER_ECONOMY_PROGRAM_ID,
+  deriveEconomyStatsPda,
   derivePlayerEconomyPda,
 } from "./economyChain";
 
@@ -297,16 +298,20 @@ export function createMintHeroInstruction(options: {
     };
   } else {
     const [playerEconomy] = derivePlayerEconomyPda(owner);
+    const [economyStats] = deriveEconomyStatsPda(owner);
+    const [gameVault] = deriveGameVaultPda();
     const data = instructionCoder.encode("mint_hero_paid", {});
 
     const keys = [
       { pubkey: owner, isSigner: true, isWritable: true },
       { pubkey: profilePda, isSigner: false, isWritable: true },
       { pubkey: heroMint, isSigner: false, isWritable: true },
-      { pubkey: oracleQueue, isSigner: false, isWritable: true },
-      { pubkey: programIdentity, isSigner: false, isWritable: true },
+      { pubkey: gameVault, isSigner: false, isWritable: true },
       { pubkey: playerEconomy, isSigner: false, isWritable: true },
+      { pubkey: economyStats, isSigner: false, isWritable: true },
       { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: oracleQueue, isSigner: false, isWritable: true },
+      { pubkey: programIdentity, isSigner: false, isWritable: true },
       { pubkey: VRF_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SYSVAR_SLOT_HASHES_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -330,7 +335,8 @@ export function createLevelUpHeroInstruction(options: {
   const { owner, heroId } = options;
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
-  const [playerEconomy] = derivePlayerEconomyPda(owner); // ADD THIS
+  const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
   const oracleQueue = getVrfOracleAddress();
 
   const [programIdentity] = PublicKey.findProgramAddressSync(
@@ -346,6 +352,7 @@ export function createLevelUpHeroInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: oracleQueue, isSigner: false, isWritable: true },
     { pubkey: programIdentity, isSigner: false, isWritable: true },
@@ -369,6 +376,7 @@ export function createRerollStatsInstruction(options: {
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
   const oracleQueue = getVrfOracleAddress();
 
   const [programIdentity] = PublicKey.findProgramAddressSync(
@@ -384,6 +392,7 @@ export function createRerollStatsInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: oracleQueue, isSigner: false, isWritable: true },
     { pubkey: programIdentity, isSigner: false, isWritable: true },
@@ -407,6 +416,7 @@ export function createRelieveStressInstruction(options: {
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
 
   const data = instructionCoder.encode("relieve_stress", {
     hero_id: new BN(heroIdBigInt.toString()),
@@ -416,6 +426,7 @@ export function createRelieveStressInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -435,6 +446,7 @@ export function createApplyBlessingInstruction(options: {
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
 
   const data = instructionCoder.encode("apply_blessing", {
     hero_id: new BN(heroIdBigInt.toString()),
@@ -444,6 +456,7 @@ export function createApplyBlessingInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -464,6 +477,7 @@ export function createHealHeroInstruction(options: {
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
 
   const data = instructionCoder.encode("heal_hero", {
     hero_id: new BN(heroIdBigInt.toString()),
@@ -474,6 +488,7 @@ export function createHealHeroInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -520,6 +535,7 @@ export function createCureStatusEffectInstruction(options: {
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
 
   const data = instructionCoder.encode("cure_status_effect", {
     hero_id: new BN(heroIdBigInt.toString()),
@@ -530,6 +546,7 @@ export function createCureStatusEffectInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -550,6 +567,7 @@ export function createCureNegativeTraitInstruction(options: {
   const heroIdBigInt = typeof heroId === "bigint" ? heroId : BigInt(heroId);
   const [heroMint] = deriveHeroMintPda(owner, heroIdBigInt);
   const [playerEconomy] = derivePlayerEconomyPda(owner);
+  const [economyStats] = deriveEconomyStatsPda(owner);
 
   const data = instructionCoder.encode("cure_negative_trait", {
     hero_id: new BN(heroIdBigInt.toString()),
@@ -560,6 +578,7 @@ export function createCureNegativeTraitInstruction(options: {
     { pubkey: owner, isSigner: true, isWritable: true },
     { pubkey: heroMint, isSigner: false, isWritable: true },
     { pubkey: playerEconomy, isSigner: false, isWritable: true },
+    { pubkey: economyStats, isSigner: false, isWritable: true },
     { pubkey: PLAYER_ECONOMY_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
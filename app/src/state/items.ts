@@ -84,7 +84,7 @@ export const ITEM_DEFINITIONS: Record<ItemId, ItemDefinition> = {
   phoenix_feather: {
     id: "phoenix_feather",
     name: "Phoenix Feather",
-    description: "Revives a fallen ally during combat with half HP.",
+    description: "Revives a fallen ally with half HP.",
     rarity: "rare",
     usable: true,
     maxStack: 1,
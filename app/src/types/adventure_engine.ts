@@ -86,10 +86,61 @@ export type AdventureEngine = {
               }
             ]
           }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "camp",
+      "discriminator": [
+        90,
+        69,
+        122,
+        30,
+        55,
+        184,
+        164,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "owner"
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adventure",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  110,
+                  116,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "adventure.dungeon_mint",
+                "account": "adventureSession"
+              }
+            ]
+          }
         }
       ],
       "args": []
@@ -108,8 +159,7 @@ export type AdventureEngine = {
       ],
       "accounts": [
         {
-          "name": "owner",
-          "writable": true
+          "name": "owner"
         },
         {
           "name": "authority",
@@ -410,6 +460,160 @@ export type AdventureEngine = {
             ]
           }
         },
+        {
+          "name": "bufferCombat",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combat"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                249,
+                114,
+                134,
+                167,
+                208,
+                86,
+                63,
+                183,
+                188,
+                99,
+                152,
+                144,
+                10,
+                231,
+                8,
+                168,
+                36,
+                208,
+                87,
+                211,
+                119,
+                34,
+                180,
+                183,
+                202,
+                194,
+                236,
+                47,
+                56,
+                78,
+                77,
+                71
+              ]
+            }
+          }
+        },
+        {
+          "name": "delegationRecordCombat",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combat"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
+          }
+        },
+        {
+          "name": "delegationMetadataCombat",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combat"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "delegationProgram"
+            }
+          }
+        },
+        {
+          "name": "combat",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  97,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pda"
+              }
+            ]
+          }
+        },
         {
           "name": "owner"
         },
@@ -431,6 +635,66 @@ export type AdventureEngine = {
       ],
       "args": []
     },
+    {
+      "name": "disarmTrap",
+      "discriminator": [
+        192,
+        182,
+        59,
+        7,
+        132,
+        83,
+        25,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "owner"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adventure",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  110,
+                  116,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "adventure.dungeon_mint",
+                "account": "adventureSession"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "trapIndex",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "dropItem",
       "discriminator": [
@@ -482,6 +746,68 @@ export type AdventureEngine = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
         }
       ],
       "args": [
@@ -547,6 +873,32 @@ export type AdventureEngine = {
             ]
           }
         },
+        {
+          "name": "combat",
+          "docs": [
+            "Committed and undelegated with the adventure, so the next run can reuse it"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  97,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "adventure"
+              }
+            ]
+          }
+        },
         {
           "name": "heroProgram",
           "address": "B8KfNvRUoNbF7FPeuDdZ7nfjPXz6kAex4Pye6GcpLD1E"
@@ -606,20 +958,199 @@ export type AdventureEngine = {
           "writable": true,
           "address": "MagicContext1111111111111111111111111111111"
         }
-      ],
-      "args": []
+      ],
+      "args": []
+    },
+    {
+      "name": "interact",
+      "discriminator": [
+        86,
+        195,
+        210,
+        119,
+        90,
+        185,
+        132,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "owner"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adventure",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  110,
+                  116,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "adventure.dungeon_mint",
+                "account": "adventureSession"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "curioIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "migrateAdventure",
+      "discriminator": [
+        247,
+        182,
+        194,
+        246,
+        17,
+        94,
+        163,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adventure",
+          "docs": [
+            "PDA are checked in the handler"
+          ],
+          "writable": true
+        },
+        {
+          "name": "combat",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  97,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "adventure"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "moveHero",
+      "discriminator": [
+        244,
+        56,
+        184,
+        167,
+        104,
+        7,
+        192,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "owner"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adventure",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  110,
+                  116,
+                  117,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "adventure.dungeon_mint",
+                "account": "adventureSession"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "direction",
+          "type": {
+            "defined": {
+              "name": "direction"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "moveHero",
+      "name": "openChest",
       "discriminator": [
-        244,
-        56,
-        184,
-        167,
-        104,
-        7,
-        192,
-        200
+        143,
+        8,
+        253,
+        123,
+        197,
+        30,
+        173,
+        15
       ],
       "accounts": [
         {
@@ -660,30 +1191,88 @@ export type AdventureEngine = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
         }
       ],
       "args": [
         {
-          "name": "direction",
-          "type": {
-            "defined": {
-              "name": "direction"
-            }
-          }
+          "name": "chestIndex",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "openChest",
+      "name": "openDoor",
       "discriminator": [
-        143,
-        8,
-        253,
-        123,
-        197,
-        30,
-        173,
-        15
+        11,
+        140,
+        41,
+        186,
+        69,
+        78,
+        55,
+        247
       ],
       "accounts": [
         {
@@ -728,8 +1317,16 @@ export type AdventureEngine = {
       ],
       "args": [
         {
-          "name": "chestIndex",
+          "name": "doorIndex",
           "type": "u8"
+        },
+        {
+          "name": "method",
+          "type": {
+            "defined": {
+              "name": "doorOpenMethod"
+            }
+          }
         }
       ]
     },
@@ -784,6 +1381,68 @@ export type AdventureEngine = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
         }
       ],
       "args": [
@@ -948,6 +1607,32 @@ export type AdventureEngine = {
             ]
           }
         },
+        {
+          "name": "combat",
+          "docs": [
+            "Allocated once with the session and reused by every encounter"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  97,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "adventure"
+              }
+            ]
+          }
+        },
         {
           "name": "playerEconomy",
           "writable": true,
@@ -983,6 +1668,72 @@ export type AdventureEngine = {
             }
           }
         },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -1089,6 +1840,68 @@ export type AdventureEngine = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
         }
       ],
       "args": [
@@ -1153,6 +1966,68 @@ export type AdventureEngine = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
         }
       ],
       "args": [
@@ -1225,6 +2100,68 @@ export type AdventureEngine = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
         }
       ],
       "args": [
@@ -1235,6 +2172,10 @@ export type AdventureEngine = {
         {
           "name": "quantity",
           "type": "u16"
+        },
+        {
+          "name": "heroIndex",
+          "type": "u8"
         }
       ]
     }
@@ -1279,6 +2220,32 @@ export type AdventureEngine = {
         115
       ]
     },
+    {
+      "name": "economyStats",
+      "discriminator": [
+        174,
+        168,
+        251,
+        181,
+        139,
+        237,
+        168,
+        219
+      ]
+    },
+    {
+      "name": "itemRegistry",
+      "discriminator": [
+        105,
+        0,
+        243,
+        110,
+        41,
+        184,
+        118,
+        81
+      ]
+    },
     {
       "name": "playerEconomy",
       "discriminator": [
@@ -1508,6 +2475,116 @@ export type AdventureEngine = {
       "code": 6042,
       "name": "itemNotUsable",
       "msg": "item cannot be used"
+    },
+    {
+      "code": 6043,
+      "name": "blockedInCombat",
+      "msg": "action is blocked while heroes are in combat"
+    },
+    {
+      "code": 6044,
+      "name": "trapNotFound",
+      "msg": "no trap found at this index"
+    },
+    {
+      "code": 6045,
+      "name": "trapNotRevealed",
+      "msg": "trap has not been revealed"
+    },
+    {
+      "code": 6046,
+      "name": "trapAlreadyResolved",
+      "msg": "trap was already disarmed or triggered"
+    },
+    {
+      "code": 6047,
+      "name": "trapOutOfReach",
+      "msg": "trap is out of reach"
+    },
+    {
+      "code": 6048,
+      "name": "doorNotFound",
+      "msg": "no door found at this index"
+    },
+    {
+      "code": 6049,
+      "name": "doorAlreadyOpen",
+      "msg": "door is already open"
+    },
+    {
+      "code": 6050,
+      "name": "doorOutOfReach",
+      "msg": "door is out of reach"
+    },
+    {
+      "code": 6051,
+      "name": "doorClosed",
+      "msg": "door is closed"
+    },
+    {
+      "code": 6052,
+      "name": "doorLocked",
+      "msg": "door is locked"
+    },
+    {
+      "code": 6053,
+      "name": "doorSealed",
+      "msg": "sealed doors can only be opened with a key"
+    },
+    {
+      "code": 6054,
+      "name": "noKeys",
+      "msg": "party has no keys"
+    },
+    {
+      "code": 6055,
+      "name": "forcedEncounterPending",
+      "msg": "this encounter cannot be avoided"
+    },
+    {
+      "code": 6056,
+      "name": "noCurioAtPosition",
+      "msg": "no curio at the party's position"
+    },
+    {
+      "code": 6057,
+      "name": "curioAlreadyUsed",
+      "msg": "curio has already been used"
+    },
+    {
+      "code": 6058,
+      "name": "encounterPending",
+      "msg": "an encounter is pending"
+    },
+    {
+      "code": 6059,
+      "name": "noCampsLeft",
+      "msg": "no camps left for this run"
+    },
+    {
+      "code": 6060,
+      "name": "abilityNotUsableFromRank",
+      "msg": "ability cannot be used from this rank"
+    },
+    {
+      "code": 6061,
+      "name": "targetOutOfReach",
+      "msg": "target is out of reach from this rank"
+    },
+    {
+      "code": 6062,
+      "name": "notLegacyAdventure",
+      "msg": "account is not a legacy adventure session"
+    },
+    {
+      "code": 6063,
+      "name": "invalidAdventureAccount",
+      "msg": "adventure account mismatch"
+    },
+    {
+      "code": 6064,
+      "name": "heroAlreadyAlive",
+      "msg": "hero has not fallen"
     }
   ],
   "types": [
@@ -1610,6 +2687,38 @@ export type AdventureEngine = {
           {
             "name": "lastUpdated",
             "type": "i64"
+          },
+          {
+            "name": "encounterKind",
+            "type": {
+              "defined": {
+                "name": "encounterKind"
+              }
+            }
+          },
+          {
+            "name": "log",
+            "docs": [
+              "Ring buffer of the most recent events; `log_cursor % COMBAT_LOG_CAPACITY` is the next",
+              "slot to write."
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "combatLogEntry"
+                  }
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "logCursor",
+            "docs": [
+              "Total events logged this fight."
+            ],
+            "type": "u32"
           }
         ]
       }
@@ -1837,6 +2946,111 @@ export type AdventureEngine = {
           {
             "name": "pendingEncounterSeed",
             "type": "u64"
+          },
+          {
+            "name": "traps",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "dungeonPoint"
+                }
+              }
+            }
+          },
+          {
+            "name": "curios",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "dungeonPoint"
+                }
+              }
+            }
+          },
+          {
+            "name": "trapStates",
+            "type": "bytes"
+          },
+          {
+            "name": "doorLocks",
+            "type": "bytes"
+          },
+          {
+            "name": "openedDoors",
+            "type": "bytes"
+          },
+          {
+            "name": "curioKinds",
+            "type": "bytes"
+          },
+          {
+            "name": "usedCurios",
+            "type": "bytes"
+          },
+          {
+            "name": "lingeringStatuses",
+            "docs": [
+              "Statuses picked up outside combat (e.g. from traps), applied when the next fight begins."
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "statusInstance"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "keys",
+            "docs": [
+              "Keys found this run; spent to open locked or sealed doors."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bossRoom",
+            "docs": [
+              "Index into `rooms` of the boss lair, or `BOSS_ROOM_NONE` for single-room dungeons."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bossCleared",
+            "type": "bool"
+          },
+          {
+            "name": "pendingEncounterKind",
+            "type": {
+              "defined": {
+                "name": "encounterKind"
+              }
+            }
+          },
+          {
+            "name": "blessingCombats",
+            "docs": [
+              "Combats left on a shrine blessing; each one boosts hero attack and magic."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "campsUsed",
+            "type": "u8"
+          },
+          {
+            "name": "heroResolve",
+            "docs": [
+              "Affliction or virtue per hero (`RESOLVE_*`), kept until the run ends."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
           }
         ]
       }
@@ -1869,7 +3083,123 @@ export type AdventureEngine = {
           {
             "name": "itemKey",
             "type": {
-              "option": "u8"
+              "option": "u8"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "combatLogAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "attack"
+          },
+          {
+            "name": "skill1"
+          },
+          {
+            "name": "skill2"
+          },
+          {
+            "name": "defend"
+          },
+          {
+            "name": "useItem"
+          },
+          {
+            "name": "reposition"
+          },
+          {
+            "name": "statusTick"
+          },
+          {
+            "name": "stunned"
+          },
+          {
+            "name": "refusedOrders"
+          },
+          {
+            "name": "deathsDoor"
+          },
+          {
+            "name": "deathBlowResisted"
+          },
+          {
+            "name": "summon"
+          }
+        ]
+      }
+    },
+    {
+      "name": "combatLogEntry",
+      "docs": [
+        "One thing that happened in a fight, so clients can replay every turn resolved inside a",
+        "single transaction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "actorKind",
+            "type": {
+              "defined": {
+                "name": "combatantKind"
+              }
+            }
+          },
+          {
+            "name": "actorIndex",
+            "type": "u8"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "combatLogAction"
+              }
+            }
+          },
+          {
+            "name": "targetKind",
+            "type": {
+              "defined": {
+                "name": "combatantKind"
+              }
+            }
+          },
+          {
+            "name": "targetIndex",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Damage dealt, or HP restored for heals."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "flags",
+            "docs": [
+              "`COMBAT_LOG_*` bits."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Status that landed on the target, if any."
+            ],
+            "type": {
+              "defined": {
+                "name": "statusEffect"
+              }
             }
           }
         ]
@@ -1912,6 +3242,36 @@ export type AdventureEngine = {
         ]
       }
     },
+    {
+      "name": "dailyEconomyBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "day",
+            "type": "i64"
+          },
+          {
+            "name": "goldMinted",
+            "type": "u64"
+          },
+          {
+            "name": "goldBurned",
+            "type": "u64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemSupply"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "direction",
       "type": {
@@ -1944,6 +3304,23 @@ export type AdventureEngine = {
         ]
       }
     },
+    {
+      "name": "doorOpenMethod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "hand"
+          },
+          {
+            "name": "key"
+          },
+          {
+            "name": "lockpick"
+          }
+        ]
+      }
+    },
     {
       "name": "dungeonMetadata",
       "type": {
@@ -2077,6 +3454,74 @@ export type AdventureEngine = {
         ]
       }
     },
+    {
+      "name": "economyStats",
+      "docs": [
+        "Global supply counters. Every instruction that creates or destroys gold or items records",
+        "it here, both lifetime and in a ring of daily buckets."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "shard",
+            "type": "u8"
+          },
+          {
+            "name": "goldMinted",
+            "type": "u64"
+          },
+          {
+            "name": "goldBurned",
+            "type": "u64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemSupply"
+                }
+              }
+            }
+          },
+          {
+            "name": "daily",
+            "docs": [
+              "Indexed by `day % ECONOMY_STATS_DAYS`."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "dailyEconomyBucket"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "encounterKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "standard"
+          },
+          {
+            "name": "boss"
+          },
+          {
+            "name": "ambush"
+          }
+        ]
+      }
+    },
     {
       "name": "enemyCombatant",
       "type": {
@@ -2141,6 +3586,28 @@ export type AdventureEngine = {
           },
           {
             "name": "threat",
+            "docs": [
+              "Threat each hero has built against this enemy, indexed by hero index."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "phase",
+            "docs": [
+              "Boss phase; bosses move to phase 1 once they drop to half health."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rank",
+            "docs": [
+              "Formation rank, 1 (front) to 4 (back); 0 once fallen."
+            ],
             "type": "u8"
           }
         ]
@@ -2165,6 +3632,9 @@ export type AdventureEngine = {
           },
           {
             "name": "useItem"
+          },
+          {
+            "name": "reposition"
           }
         ]
       }
@@ -2254,6 +3724,39 @@ export type AdventureEngine = {
           {
             "name": "pendingNegativeTraits",
             "type": "u8"
+          },
+          {
+            "name": "rank",
+            "docs": [
+              "Formation rank, 1 (front) to 4 (back); 0 once fallen."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "stressMax",
+            "type": "u16"
+          },
+          {
+            "name": "positiveTraits",
+            "docs": [
+              "Number of positive traits; each improves the odds of a virtue."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "resolve",
+            "docs": [
+              "Affliction or virtue (`RESOLVE_*`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "deathBlowsSurvived",
+            "docs": [
+              "Death blows resisted on Death's Door this fight. A hero at 0 HP who is still `alive`",
+              "is on Death's Door."
+            ],
+            "type": "u8"
           }
         ]
       }
@@ -2376,6 +3879,62 @@ export type AdventureEngine = {
         ]
       }
     },
+    {
+      "name": "itemDefinition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "u8"
+          },
+          {
+            "name": "buyPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "sellPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxStack",
+            "docs": [
+              "Most a player may hold; 0 = unlimited."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "usable",
+            "type": "bool"
+          },
+          {
+            "name": "lootOnly",
+            "docs": [
+              "Only found in dungeons; cannot be packed for an adventure."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "effectId",
+            "docs": [
+              "`ITEM_EFFECT_*` applied when the item is used."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "effectValue",
+            "docs": [
+              "Strength of the effect, e.g. HP healed or torch restored."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
     {
       "name": "itemInput",
       "type": {
@@ -2392,6 +3951,35 @@ export type AdventureEngine = {
         ]
       }
     },
+    {
+      "name": "itemRegistry",
+      "docs": [
+        "Admin-managed table of every item the economy knows about."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemDefinition"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "itemSlot",
       "type": {
@@ -2408,6 +3996,42 @@ export type AdventureEngine = {
         ]
       }
     },
+    {
+      "name": "itemStack",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemSupply",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "created",
+            "type": "u64"
+          },
+          {
+            "name": "destroyed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "playerEconomy",
       "type": {
@@ -2426,16 +4050,15 @@ export type AdventureEngine = {
             "type": "i64"
           },
           {
-            "name": "items",
-            "type": {
-              "array": [
-                "u16",
-                7
-              ]
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "bump",
+            "name": "stashTier",
+            "docs": [
+              "Index into `STASH_TIER_SLOTS`. Carved out of the old reserved bytes, so accounts that",
+              "predate stash tiers read as tier 0."
+            ],
             "type": "u8"
           },
           {
@@ -2443,9 +4066,22 @@ export type AdventureEngine = {
             "type": {
               "array": [
                 "u8",
-                5
+                4
               ]
             }
+          },
+          {
+            "name": "items",
+            "docs": [
+              "One stack per item id held; empty stacks are removed."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemStack"
+                }
+              }
+            }
           }
         ]
       }
@@ -2472,6 +4108,21 @@ export type AdventureEngine = {
           },
           {
             "name": "guard"
+          },
+          {
+            "name": "stun"
+          },
+          {
+            "name": "mark"
+          },
+          {
+            "name": "regen"
+          },
+          {
+            "name": "blind"
+          },
+          {
+            "name": "vulnerable"
           }
         ]
       }
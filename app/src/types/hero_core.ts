@@ -60,6 +60,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -76,6 +80,117 @@ export type HeroCore = {
         }
       ]
     },
+    {
+      "name": "applyRelicCurse",
+      "discriminator": [
+        122,
+        77,
+        59,
+        8,
+        9,
+        98,
+        241,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "curseSigner",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  108,
+                  105,
+                  99,
+                  95,
+                  99,
+                  117,
+                  114,
+                  115,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                112,
+                43,
+                28,
+                176,
+                121,
+                72,
+                141,
+                186,
+                57,
+                239,
+                160,
+                40,
+                144,
+                249,
+                121,
+                197,
+                66,
+                214,
+                20,
+                170,
+                22,
+                176,
+                219,
+                21,
+                252,
+                153,
+                153,
+                123,
+                191,
+                230,
+                24,
+                124
+              ]
+            }
+          }
+        },
+        {
+          "name": "player"
+        },
+        {
+          "name": "heroMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  101,
+                  114,
+                  111
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "account",
+                "path": "hero_mint.id",
+                "account": "heroMint"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "traitRoll",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "applyStatusEffect",
       "discriminator": [
@@ -544,6 +659,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -611,6 +730,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -898,6 +1021,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -1013,6 +1140,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -1378,6 +1509,10 @@ export type HeroCore = {
             }
           }
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -1565,6 +1700,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -1683,6 +1822,10 @@ export type HeroCore = {
           "name": "playerEconomy",
           "writable": true
         },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
         {
           "name": "playerEconomyProgram",
           "address": "8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ"
@@ -1846,6 +1989,19 @@ export type HeroCore = {
     }
   ],
   "accounts": [
+    {
+      "name": "economyStats",
+      "discriminator": [
+        174,
+        168,
+        251,
+        181,
+        139,
+        237,
+        168,
+        219
+      ]
+    },
     {
       "name": "gameVault",
       "discriminator": [
@@ -2056,6 +2212,19 @@ export type HeroCore = {
         149
       ]
     },
+    {
+      "name": "relicCurseApplied",
+      "discriminator": [
+        139,
+        42,
+        244,
+        31,
+        150,
+        205,
+        199,
+        89
+      ]
+    },
     {
       "name": "statsRerolled",
       "discriminator": [
@@ -2366,6 +2535,87 @@ export type HeroCore = {
         ]
       }
     },
+    {
+      "name": "dailyEconomyBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "day",
+            "type": "i64"
+          },
+          {
+            "name": "goldMinted",
+            "type": "u64"
+          },
+          {
+            "name": "goldBurned",
+            "type": "u64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemSupply"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "economyStats",
+      "docs": [
+        "Global supply counters. Every instruction that creates or destroys gold or items records",
+        "it here, both lifetime and in a ring of daily buckets."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "shard",
+            "type": "u8"
+          },
+          {
+            "name": "goldMinted",
+            "type": "u64"
+          },
+          {
+            "name": "goldBurned",
+            "type": "u64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemSupply"
+                }
+              }
+            }
+          },
+          {
+            "name": "daily",
+            "docs": [
+              "Indexed by `day % ECONOMY_STATS_DAYS`."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "dailyEconomyBucket"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "experienceGranted",
       "type": {
@@ -2754,6 +3004,42 @@ export type HeroCore = {
         ]
       }
     },
+    {
+      "name": "itemStack",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemSupply",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "created",
+            "type": "u64"
+          },
+          {
+            "name": "destroyed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "negativeTraitGranted",
       "type": {
@@ -2792,16 +3078,15 @@ export type HeroCore = {
             "type": "i64"
           },
           {
-            "name": "items",
-            "type": {
-              "array": [
-                "u16",
-                7
-              ]
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "bump",
+            "name": "stashTier",
+            "docs": [
+              "Index into `STASH_TIER_SLOTS`. Carved out of the old reserved bytes, so accounts that",
+              "predate stash tiers read as tier 0."
+            ],
             "type": "u8"
           },
           {
@@ -2809,9 +3094,22 @@ export type HeroCore = {
             "type": {
               "array": [
                 "u8",
-                5
+                4
               ]
             }
+          },
+          {
+            "name": "items",
+            "docs": [
+              "One stack per item id held; empty stacks are removed."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemStack"
+                }
+              }
+            }
           }
         ]
       }
@@ -2896,6 +3194,26 @@ export type HeroCore = {
         ]
       }
     },
+    {
+      "name": "relicCurseApplied",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "heroId",
+            "type": "u64"
+          },
+          {
+            "name": "traitId",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "skill",
       "type": {
@@ -61,16 +61,64 @@ export type PlayerEconomy = {
               }
             ]
           }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
         }
       ],
       "args": [
         {
           "name": "item",
-          "type": {
-            "defined": {
-              "name": "itemKey"
-            }
-          }
+          "type": "u8"
         },
         {
           "name": "quantity",
@@ -79,24 +127,53 @@ export type PlayerEconomy = {
       ]
     },
     {
-      "name": "consumeItems",
+      "name": "callbackCraft",
       "discriminator": [
-        41,
-        7,
-        105,
-        202,
-        234,
-        186,
-        105,
-        235
+        218,
+        57,
+        170,
+        224,
+        149,
+        38,
+        61,
+        69
       ],
       "accounts": [
         {
-          "name": "authority",
-          "docs": [
-            "The authority (owner or delegated program) consuming items"
-          ],
-          "signer": true
+          "name": "programIdentity",
+          "signer": true,
+          "address": "9irBy75QS2BN81FUgXuHcjqceJJRuc9oDkAe8TKVvvAw"
+        },
+        {
+          "name": "craftRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  97,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "craft_request.owner",
+                "account": "craftRequest"
+              }
+            ]
+          }
         },
         {
           "name": "playerEconomy",
@@ -124,45 +201,105 @@ export type PlayerEconomy = {
               },
               {
                 "kind": "account",
-                "path": "player_economy.owner",
-                "account": "playerEconomy"
+                "path": "craft_request.owner",
+                "account": "craftRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
               }
             ]
           }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
         }
       ],
       "args": [
         {
-          "name": "items",
+          "name": "randomness",
           "type": {
-            "vec": {
-              "defined": {
-                "name": "itemConsumption"
-              }
-            }
+            "array": [
+              "u8",
+              32
+            ]
           }
         }
       ]
     },
     {
-      "name": "depositLoot",
+      "name": "callbackIdentifyRelic",
       "discriminator": [
-        94,
-        58,
-        98,
-        177,
-        157,
-        48,
-        223,
-        179
+        237,
+        114,
+        56,
+        10,
+        80,
+        122,
+        118,
+        80
       ],
       "accounts": [
         {
-          "name": "authority",
-          "docs": [
-            "Authority adding loot (player or trusted adventure signer)"
-          ],
-          "signer": true
+          "name": "programIdentity",
+          "signer": true,
+          "address": "9irBy75QS2BN81FUgXuHcjqceJJRuc9oDkAe8TKVvvAw"
+        },
+        {
+          "name": "identifyRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  102,
+                  121,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "identify_request.owner",
+                "account": "identifyRequest"
+              }
+            ]
+          }
         },
         {
           "name": "playerEconomy",
@@ -190,41 +327,99 @@ export type PlayerEconomy = {
               },
               {
                 "kind": "account",
-                "path": "player_economy.owner",
-                "account": "playerEconomy"
+                "path": "identify_request.owner",
+                "account": "identifyRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "heroMint",
+          "writable": true
+        },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "curseSigner",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  108,
+                  105,
+                  99,
+                  95,
+                  99,
+                  117,
+                  114,
+                  115,
+                  101
+                ]
               }
             ]
           }
+        },
+        {
+          "name": "heroCoreProgram",
+          "address": "B8KfNvRUoNbF7FPeuDdZ7nfjPXz6kAex4Pye6GcpLD1E"
         }
       ],
       "args": [
         {
-          "name": "gold",
-          "type": "u64"
-        },
-        {
-          "name": "items",
+          "name": "randomness",
           "type": {
-            "vec": {
-              "defined": {
-                "name": "lootDepositItem"
-              }
-            }
+            "array": [
+              "u8",
+              32
+            ]
           }
         }
       ]
     },
     {
-      "name": "grantHourlyGold",
+      "name": "cancelCraft",
       "discriminator": [
-        209,
-        229,
-        124,
-        21,
-        172,
-        112,
-        115,
-        169
+        213,
+        113,
+        106,
+        7,
+        16,
+        203,
+        226,
+        121
       ],
       "accounts": [
         {
@@ -261,26 +456,59 @@ export type PlayerEconomy = {
               }
             ]
           }
+        },
+        {
+          "name": "craftRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  97,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
         }
       ],
       "args": []
     },
     {
-      "name": "initializePlayerEconomy",
+      "name": "cancelIdentifyRelic",
       "discriminator": [
-        229,
-        164,
-        233,
-        147,
-        180,
-        135,
-        222,
-        91
+        109,
+        185,
+        136,
+        95,
+        83,
+        86,
+        224,
+        193
       ],
       "accounts": [
         {
           "name": "owner",
-          "writable": true,
           "signer": true
         },
         {
@@ -315,32 +543,65 @@ export type PlayerEconomy = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "sellItem",
+          "name": "identifyRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  102,
+                  121,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOffer",
       "discriminator": [
-        44,
-        114,
-        171,
-        76,
-        76,
-        10,
-        150,
-        246
+        92,
+        203,
+        223,
+        40,
+        92,
+        89,
+        53,
+        119
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "seller",
           "writable": true,
           "signer": true
         },
         {
-          "name": "playerEconomy",
+          "name": "sellerEconomy",
           "writable": true,
           "pda": {
             "seeds": [
@@ -365,45 +626,169 @@ export type PlayerEconomy = {
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  100,
+                  101,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "tradeOffer"
               }
             ]
           }
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "claimCraftedItems",
+      "discriminator": [
+        163,
+        7,
+        55,
+        158,
+        159,
+        189,
+        53,
+        55
+      ],
+      "accounts": [
         {
-          "name": "item",
-          "type": {
-            "defined": {
-              "name": "itemKey"
-            }
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
           }
         },
         {
-          "name": "quantity",
-          "type": "u16"
+          "name": "craftRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  97,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "spendGold",
+      "name": "claimDailyGold",
       "discriminator": [
-        65,
-        44,
-        205,
-        30,
-        154,
-        22,
-        80,
-        58
+        88,
+        84,
+        240,
+        189,
+        151,
+        7,
+        176,
+        10
       ],
       "accounts": [
         {
           "name": "owner",
-          "docs": [
-            "Signer whose vault gold will be debited."
-          ],
+          "writable": true,
           "signer": true
         },
         {
@@ -436,214 +821,4248 @@ export type PlayerEconomy = {
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "faucetConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  97,
+                  117,
+                  99,
+                  101,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "faucetLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  97,
+                  117,
+                  99,
+                  101,
+                  116,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "playerEconomy",
-      "discriminator": [
-        13,
-        211,
-        131,
-        77,
-        0,
-        34,
-        150,
-        149
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "goldSpent",
-      "discriminator": [
-        63,
-        214,
-        7,
-        13,
-        23,
-        130,
-        114,
-        162
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "hourlyGrantClaimed",
+      "name": "consumeItems",
       "discriminator": [
-        189,
-        16,
-        196,
-        173,
+        41,
+        7,
+        105,
+        202,
+        234,
+        186,
+        105,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The authority (owner or delegated program) consuming items"
+          ],
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player_economy.owner",
+                "account": "playerEconomy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "items",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "itemConsumption"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "craft",
+      "discriminator": [
+        161,
+        233,
+        177,
+        214,
+        243,
         109,
-        32,
-        93,
-        139
+        161,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "craftRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  97,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "oracleQueue",
+          "writable": true,
+          "address": "Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh"
+        },
+        {
+          "name": "programIdentity",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vrfProgram",
+          "address": "Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz"
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "recipeId",
+          "type": "u8"
+        },
+        {
+          "name": "times",
+          "type": "u16"
+        }
       ]
     },
     {
-      "name": "itemConsumed",
-      "discriminator": [
-        93,
-        182,
-        173,
-        144,
-        136,
-        37,
-        168,
-        68
-      ]
+      "name": "depositGold",
+      "discriminator": [
+        214,
+        54,
+        155,
+        149,
+        172,
+        5,
+        212,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goldBridge",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  108,
+                  100,
+                  95,
+                  98,
+                  114,
+                  105,
+                  100,
+                  103,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "goldMint",
+          "writable": true
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Owner's token account that receives withdrawn gold or has deposited gold burned from it."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridgeLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  114,
+                  105,
+                  100,
+                  103,
+                  101,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositLoot",
+      "discriminator": [
+        94,
+        58,
+        98,
+        177,
+        157,
+        48,
+        223,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Authority adding loot (player or trusted adventure signer)"
+          ],
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player_economy.owner",
+                "account": "playerEconomy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gold",
+          "type": "u64"
+        },
+        {
+          "name": "items",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "lootDepositItem"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "fillOffer",
+      "discriminator": [
+        83,
+        15,
+        200,
+        85,
+        160,
+        80,
+        164,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "buyer",
+          "signer": true
+        },
+        {
+          "name": "buyerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sellerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.seller",
+                "account": "tradeOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  100,
+                  101,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.seller",
+                "account": "tradeOffer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "tradeOffer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "docs": [
+            "Receives the offer rent once the last unit is bought."
+          ],
+          "writable": true
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "quantity",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "identifyRelic",
+      "discriminator": [
+        244,
+        18,
+        28,
+        205,
+        158,
+        47,
+        90,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "identifyRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  102,
+                  121,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "heroMint"
+        },
+        {
+          "name": "oracleQueue",
+          "writable": true,
+          "address": "Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh"
+        },
+        {
+          "name": "programIdentity",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vrfProgram",
+          "address": "Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz"
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeEconomyStats",
+      "discriminator": [
+        190,
+        178,
+        195,
+        149,
+        249,
+        195,
+        40,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV"
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "shard",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeFaucetConfig",
+      "discriminator": [
+        217,
+        154,
+        10,
+        77,
+        188,
+        31,
+        136,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV"
+        },
+        {
+          "name": "faucetConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  97,
+                  117,
+                  99,
+                  101,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "faucetParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeGoldBridge",
+      "discriminator": [
+        150,
+        207,
+        66,
+        44,
+        200,
+        249,
+        125,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV"
+        },
+        {
+          "name": "goldBridge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  108,
+                  100,
+                  95,
+                  98,
+                  114,
+                  105,
+                  100,
+                  103,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "goldMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  108,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "dailyWithdrawLimit",
+          "type": "u64"
+        },
+        {
+          "name": "dailyDepositLimit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeItemRegistry",
+      "discriminator": [
+        62,
+        200,
+        53,
+        4,
+        244,
+        215,
+        1,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV"
+        },
+        {
+          "name": "itemRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializePlayerEconomy",
+      "discriminator": [
+        229,
+        164,
+        233,
+        147,
+        180,
+        135,
+        222,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeShop",
+      "discriminator": [
+        76,
+        158,
+        246,
+        22,
+        47,
+        236,
+        107,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV"
+        },
+        {
+          "name": "shopState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTransferConfig",
+      "discriminator": [
+        48,
+        225,
+        120,
+        250,
+        188,
+        23,
+        226,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV"
+        },
+        {
+          "name": "transferConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "dailyGoldLimit",
+          "type": "u64"
+        },
+        {
+          "name": "dailyItemLimit",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migratePlayerEconomy",
+      "discriminator": [
+        168,
+        196,
+        91,
+        239,
+        159,
+        81,
+        29,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "docs": [
+            "the handler."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "postOffer",
+      "discriminator": [
+        73,
+        150,
+        193,
+        114,
+        200,
+        133,
+        74,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sellerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  100,
+                  101,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "arg",
+                "path": "offerId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "offerId",
+          "type": "u64"
+        },
+        {
+          "name": "item",
+          "type": "u8"
+        },
+        {
+          "name": "quantity",
+          "type": "u16"
+        },
+        {
+          "name": "pricePerUnit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sellItem",
+      "discriminator": [
+        44,
+        114,
+        171,
+        76,
+        76,
+        10,
+        150,
+        246
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "item",
+          "type": "u8"
+        },
+        {
+          "name": "quantity",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setItemDefinition",
+      "discriminator": [
+        26,
+        57,
+        232,
+        12,
+        33,
+        70,
+        186,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "itemRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "definition",
+          "type": {
+            "defined": {
+              "name": "itemDefinition"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setShopItemStock",
+      "discriminator": [
+        192,
+        115,
+        103,
+        97,
+        163,
+        144,
+        132,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "shopState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "item",
+          "type": "u8"
+        },
+        {
+          "name": "dailyStock",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "spendGold",
+      "discriminator": [
+        65,
+        44,
+        205,
+        30,
+        154,
+        22,
+        80,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Signer whose vault gold will be debited."
+          ],
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferGold",
+      "discriminator": [
+        122,
+        115,
+        161,
+        203,
+        86,
+        169,
+        147,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "senderEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipientEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "recipient_economy.owner",
+                "account": "playerEconomy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "transferConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "senderLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipientLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "recipient_economy.owner",
+                "account": "playerEconomy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferItems",
+      "discriminator": [
+        164,
+        191,
+        97,
+        63,
+        101,
+        93,
+        9,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "senderEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipientEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "recipient_economy.owner",
+                "account": "playerEconomy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "itemRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "transferConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "senderLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipientLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "recipient_economy.owner",
+                "account": "playerEconomy"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "items",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "transferItem"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFaucetConfig",
+      "discriminator": [
+        206,
+        46,
+        190,
+        76,
+        145,
+        30,
+        65,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "faucetConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  97,
+                  117,
+                  99,
+                  101,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "faucetParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGoldBridge",
+      "discriminator": [
+        227,
+        13,
+        241,
+        32,
+        194,
+        77,
+        33,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "goldBridge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  108,
+                  100,
+                  95,
+                  98,
+                  114,
+                  105,
+                  100,
+                  103,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        },
+        {
+          "name": "dailyWithdrawLimit",
+          "type": "u64"
+        },
+        {
+          "name": "dailyDepositLimit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateTransferConfig",
+      "discriminator": [
+        115,
+        219,
+        220,
+        165,
+        107,
+        108,
+        78,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "transferConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "dailyGoldLimit",
+          "type": "u64"
+        },
+        {
+          "name": "dailyItemLimit",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "upgradeStash",
+      "discriminator": [
+        171,
+        20,
+        123,
+        46,
+        198,
+        75,
+        157,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawGold",
+      "discriminator": [
+        179,
+        101,
+        10,
+        15,
+        244,
+        112,
+        28,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerEconomy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  101,
+                  99,
+                  111,
+                  110,
+                  111,
+                  109,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "goldBridge",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  108,
+                  100,
+                  95,
+                  98,
+                  114,
+                  105,
+                  100,
+                  103,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "goldMint",
+          "writable": true
+        },
+        {
+          "name": "tokenAccount",
+          "docs": [
+            "Owner's token account that receives withdrawn gold or has deposited gold burned from it."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridgeLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  114,
+                  105,
+                  100,
+                  103,
+                  101,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "economyStats",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bridgeLedger",
+      "discriminator": [
+        177,
+        143,
+        250,
+        129,
+        126,
+        13,
+        82,
+        80
+      ]
+    },
+    {
+      "name": "craftRequest",
+      "discriminator": [
+        214,
+        82,
+        24,
+        179,
+        185,
+        36,
+        96,
+        216
+      ]
+    },
+    {
+      "name": "economyStats",
+      "discriminator": [
+        174,
+        168,
+        251,
+        181,
+        139,
+        237,
+        168,
+        219
+      ]
+    },
+    {
+      "name": "faucetConfig",
+      "discriminator": [
+        216,
+        31,
+        49,
+        154,
+        106,
+        125,
+        143,
+        142
+      ]
+    },
+    {
+      "name": "faucetLedger",
+      "discriminator": [
+        43,
+        139,
+        192,
+        104,
+        3,
+        186,
+        103,
+        195
+      ]
+    },
+    {
+      "name": "goldBridge",
+      "discriminator": [
+        69,
+        205,
+        45,
+        213,
+        105,
+        46,
+        117,
+        69
+      ]
+    },
+    {
+      "name": "identifyRequest",
+      "discriminator": [
+        249,
+        246,
+        85,
+        52,
+        111,
+        73,
+        213,
+        246
+      ]
+    },
+    {
+      "name": "itemRegistry",
+      "discriminator": [
+        105,
+        0,
+        243,
+        110,
+        41,
+        184,
+        118,
+        81
+      ]
+    },
+    {
+      "name": "playerEconomy",
+      "discriminator": [
+        13,
+        211,
+        131,
+        77,
+        0,
+        34,
+        150,
+        149
+      ]
+    },
+    {
+      "name": "shopState",
+      "discriminator": [
+        157,
+        142,
+        212,
+        208,
+        50,
+        57,
+        194,
+        34
+      ]
+    },
+    {
+      "name": "tradeOffer",
+      "discriminator": [
+        240,
+        30,
+        82,
+        234,
+        214,
+        166,
+        118,
+        200
+      ]
+    },
+    {
+      "name": "transferConfig",
+      "discriminator": [
+        14,
+        241,
+        112,
+        58,
+        146,
+        245,
+        1,
+        162
+      ]
+    },
+    {
+      "name": "transferLedger",
+      "discriminator": [
+        155,
+        147,
+        85,
+        131,
+        79,
+        221,
+        40,
+        4
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "craftCancelled",
+      "discriminator": [
+        173,
+        231,
+        124,
+        47,
+        123,
+        254,
+        234,
+        43
+      ]
+    },
+    {
+      "name": "craftRequested",
+      "discriminator": [
+        139,
+        69,
+        114,
+        252,
+        52,
+        90,
+        236,
+        142
+      ]
+    },
+    {
+      "name": "craftedItemsClaimed",
+      "discriminator": [
+        164,
+        128,
+        75,
+        129,
+        22,
+        135,
+        172,
+        145
+      ]
+    },
+    {
+      "name": "dailyGrantClaimed",
+      "discriminator": [
+        102,
+        117,
+        164,
+        138,
+        167,
+        184,
+        50,
+        115
+      ]
+    },
+    {
+      "name": "faucetConfigUpdated",
+      "discriminator": [
+        219,
+        24,
+        64,
+        77,
+        198,
+        205,
+        19,
+        169
+      ]
+    },
+    {
+      "name": "goldBridgeUpdated",
+      "discriminator": [
+        219,
+        171,
+        117,
+        74,
+        74,
+        210,
+        50,
+        147
+      ]
+    },
+    {
+      "name": "goldDeposited",
+      "discriminator": [
+        61,
+        78,
+        65,
+        115,
+        14,
+        108,
+        71,
+        221
+      ]
+    },
+    {
+      "name": "goldSpent",
+      "discriminator": [
+        63,
+        214,
+        7,
+        13,
+        23,
+        130,
+        114,
+        162
+      ]
+    },
+    {
+      "name": "goldTransferred",
+      "discriminator": [
+        83,
+        227,
+        51,
+        62,
+        247,
+        240,
+        188,
+        148
+      ]
+    },
+    {
+      "name": "goldWithdrawn",
+      "discriminator": [
+        102,
+        169,
+        105,
+        95,
+        109,
+        225,
+        217,
+        6
+      ]
+    },
+    {
+      "name": "itemConsumed",
+      "discriminator": [
+        93,
+        182,
+        173,
+        144,
+        136,
+        37,
+        168,
+        68
+      ]
+    },
+    {
+      "name": "itemDefinitionUpdated",
+      "discriminator": [
+        193,
+        187,
+        8,
+        155,
+        82,
+        211,
+        118,
+        28
+      ]
+    },
+    {
+      "name": "itemPurchased",
+      "discriminator": [
+        33,
+        219,
+        12,
+        58,
+        205,
+        48,
+        63,
+        143
+      ]
+    },
+    {
+      "name": "itemRegistryInitialized",
+      "discriminator": [
+        61,
+        235,
+        224,
+        232,
+        61,
+        151,
+        196,
+        249
+      ]
+    },
+    {
+      "name": "itemSold",
+      "discriminator": [
+        212,
+        37,
+        218,
+        206,
+        120,
+        171,
+        56,
+        230
+      ]
+    },
+    {
+      "name": "itemsCrafted",
+      "discriminator": [
+        224,
+        10,
+        80,
+        146,
+        251,
+        227,
+        7,
+        60
+      ]
+    },
+    {
+      "name": "itemsTransferred",
+      "discriminator": [
+        96,
+        126,
+        39,
+        142,
+        149,
+        14,
+        33,
+        108
+      ]
+    },
+    {
+      "name": "lootDeposited",
+      "discriminator": [
+        134,
+        156,
+        100,
+        60,
+        74,
+        27,
+        210,
+        100
+      ]
+    },
+    {
+      "name": "offerCancelled",
+      "discriminator": [
+        45,
+        42,
+        175,
+        214,
+        51,
+        192,
+        154,
+        9
+      ]
+    },
+    {
+      "name": "offerFilled",
+      "discriminator": [
+        173,
+        104,
+        95,
+        161,
+        144,
+        206,
+        72,
+        57
+      ]
+    },
+    {
+      "name": "offerPosted",
+      "discriminator": [
+        213,
+        49,
+        239,
+        221,
+        100,
+        153,
+        210,
+        32
+      ]
+    },
+    {
+      "name": "playerEconomyInitialized",
+      "discriminator": [
+        185,
+        66,
+        53,
+        68,
+        172,
+        168,
+        200,
+        16
+      ]
+    },
+    {
+      "name": "playerEconomyMigrated",
+      "discriminator": [
+        25,
+        252,
+        150,
+        106,
+        16,
+        58,
+        8,
+        121
+      ]
+    },
+    {
+      "name": "relicIdentified",
+      "discriminator": [
+        31,
+        87,
+        166,
+        110,
+        214,
+        117,
+        226,
+        188
+      ]
+    },
+    {
+      "name": "relicIdentifyCancelled",
+      "discriminator": [
+        123,
+        71,
+        0,
+        113,
+        248,
+        82,
+        74,
+        206
+      ]
+    },
+    {
+      "name": "relicIdentifyRequested",
+      "discriminator": [
+        77,
+        142,
+        173,
+        253,
+        6,
+        31,
+        186,
+        36
+      ]
+    },
+    {
+      "name": "shopInitialized",
+      "discriminator": [
+        247,
+        188,
+        177,
+        207,
+        96,
+        98,
+        144,
+        215
+      ]
+    },
+    {
+      "name": "shopStockConfigured",
+      "discriminator": [
+        90,
+        192,
+        198,
+        211,
+        238,
+        91,
+        235,
+        64
+      ]
+    },
+    {
+      "name": "stashUpgraded",
+      "discriminator": [
+        219,
+        197,
+        234,
+        249,
+        74,
+        40,
+        227,
+        230
+      ]
+    },
+    {
+      "name": "transferConfigUpdated",
+      "discriminator": [
+        85,
+        207,
+        12,
+        238,
+        26,
+        251,
+        27,
+        7
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "alreadyInitialized",
+      "msg": "Player economy account already initialized"
+    },
+    {
+      "code": 6001,
+      "name": "unauthorized",
+      "msg": "Unauthorized owner access"
+    },
+    {
+      "code": 6002,
+      "name": "invalidQuantity",
+      "msg": "Quantity must be greater than zero"
+    },
+    {
+      "code": 6003,
+      "name": "invalidSpendAmount",
+      "msg": "Unable to spend zero gold"
+    },
+    {
+      "code": 6004,
+      "name": "itemNotPurchasable",
+      "msg": "Item cannot be purchased"
+    },
+    {
+      "code": 6005,
+      "name": "itemNotSellable",
+      "msg": "Item cannot be sold"
+    },
+    {
+      "code": 6006,
+      "name": "stackLimitExceeded",
+      "msg": "Inventory stack limit exceeded"
+    },
+    {
+      "code": 6007,
+      "name": "insufficientStock",
+      "msg": "Not enough of the requested item"
+    },
+    {
+      "code": 6008,
+      "name": "insufficientGold",
+      "msg": "Not enough gold available"
+    },
+    {
+      "code": 6009,
+      "name": "mathOverflow",
+      "msg": "Value overflow detected"
+    },
+    {
+      "code": 6010,
+      "name": "grantOnCooldown",
+      "msg": "Daily grant already claimed"
+    },
+    {
+      "code": 6011,
+      "name": "inventoryOverflow",
+      "msg": "Inventory quantity too large"
+    },
+    {
+      "code": 6012,
+      "name": "accountNotInitialized",
+      "msg": "Player economy account is not initialized"
+    },
+    {
+      "code": 6013,
+      "name": "unknownItem",
+      "msg": "Item is not defined in the registry"
+    },
+    {
+      "code": 6014,
+      "name": "inventoryFull",
+      "msg": "Inventory has no room for another item type"
+    },
+    {
+      "code": 6015,
+      "name": "registryFull",
+      "msg": "Item registry is full"
+    },
+    {
+      "code": 6016,
+      "name": "notLegacyAccount",
+      "msg": "Player economy account does not use the legacy layout"
+    },
+    {
+      "code": 6017,
+      "name": "shopSoldOut",
+      "msg": "Shop does not have enough stock left today"
+    },
+    {
+      "code": 6018,
+      "name": "selfTransfer",
+      "msg": "Cannot transfer to your own economy account"
+    },
+    {
+      "code": 6019,
+      "name": "transferLimitExceeded",
+      "msg": "Daily transfer limit exceeded"
+    },
+    {
+      "code": 6020,
+      "name": "invalidPrice",
+      "msg": "Offer price must be greater than zero"
+    },
+    {
+      "code": 6021,
+      "name": "offerQuantityExceeded",
+      "msg": "Offer does not have that many items left"
+    },
+    {
+      "code": 6022,
+      "name": "selfTrade",
+      "msg": "Cannot fill your own offer"
+    },
+    {
+      "code": 6023,
+      "name": "unknownRecipe",
+      "msg": "Unknown crafting recipe"
+    },
+    {
+      "code": 6024,
+      "name": "craftInProgress",
+      "msg": "A randomized craft is already waiting for its result"
+    },
+    {
+      "code": 6025,
+      "name": "unexpectedCallback",
+      "msg": "Craft callback does not match a pending request"
+    },
+    {
+      "code": 6026,
+      "name": "identifyInProgress",
+      "msg": "A relic is already being identified"
+    },
+    {
+      "code": 6027,
+      "name": "stashFull",
+      "msg": "Not enough free stash slots"
+    },
+    {
+      "code": 6028,
+      "name": "stashAtMaxTier",
+      "msg": "Stash is already at the highest tier"
+    },
+    {
+      "code": 6029,
+      "name": "faucetCapReached",
+      "msg": "Lifetime faucet cap reached"
+    },
+    {
+      "code": 6030,
+      "name": "bridgePaused",
+      "msg": "Gold bridge is paused"
+    },
+    {
+      "code": 6031,
+      "name": "bridgeLimitExceeded",
+      "msg": "Daily bridge limit exceeded"
+    },
+    {
+      "code": 6032,
+      "name": "reservedItemKey",
+      "msg": "Item key is reserved"
+    },
+    {
+      "code": 6033,
+      "name": "recipientLimitExceeded",
+      "msg": "Recipient has reached their daily transfer limit"
+    },
+    {
+      "code": 6034,
+      "name": "invalidHero",
+      "msg": "Hero must be an unlocked, unburned hero owned by the player"
+    },
+    {
+      "code": 6035,
+      "name": "noPendingRequest",
+      "msg": "No randomness request is pending"
+    },
+    {
+      "code": 6036,
+      "name": "requestNotExpired",
+      "msg": "Randomness request has not timed out yet"
+    },
+    {
+      "code": 6037,
+      "name": "invalidStatsShard",
+      "msg": "Economy stats shard is out of range"
+    }
+  ],
+  "types": [
+    {
+      "name": "bridgeLedger",
+      "docs": [
+        "Per-player bridge volume in the current window."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "window",
+            "type": "i64"
+          },
+          {
+            "name": "withdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "deposited",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "craftCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipeId",
+            "type": "u8"
+          },
+          {
+            "name": "times",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "craftRequest",
+      "docs": [
+        "Per-player slot for a randomized craft waiting on VRF. Inputs are already consumed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pending",
+            "type": "bool"
+          },
+          {
+            "name": "recipeId",
+            "type": "u8"
+          },
+          {
+            "name": "times",
+            "type": "u16"
+          },
+          {
+            "name": "requestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "unclaimed",
+            "docs": [
+              "Rolled outputs that did not fit in the inventory, held until `claim_crafted_items`."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemStack"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "craftRequested",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipeId",
+            "type": "u8"
+          },
+          {
+            "name": "times",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "craftedItemsClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemStack"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "dailyEconomyBucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "day",
+            "type": "i64"
+          },
+          {
+            "name": "goldMinted",
+            "type": "u64"
+          },
+          {
+            "name": "goldBurned",
+            "type": "u64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemSupply"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "dailyGrantClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "streak",
+            "type": "u16"
+          },
+          {
+            "name": "lifetimeGranted",
+            "type": "u64"
+          },
+          {
+            "name": "nextAvailableAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "economyStats",
+      "docs": [
+        "Global supply counters. Every instruction that creates or destroys gold or items records",
+        "it here, both lifetime and in a ring of daily buckets."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "shard",
+            "type": "u8"
+          },
+          {
+            "name": "goldMinted",
+            "type": "u64"
+          },
+          {
+            "name": "goldBurned",
+            "type": "u64"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemSupply"
+                }
+              }
+            }
+          },
+          {
+            "name": "daily",
+            "docs": [
+              "Indexed by `day % ECONOMY_STATS_DAYS`."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "dailyEconomyBucket"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "faucetConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "params",
+            "type": {
+              "defined": {
+                "name": "faucetParams"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "faucetConfigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "params",
+            "type": {
+              "defined": {
+                "name": "faucetParams"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "faucetLedger",
+      "docs": [
+        "Per-player streak and lifetime totals for the daily faucet."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastClaimDay",
+            "docs": [
+              "Faucet day (`unix_timestamp / FAUCET_DAY_SECONDS`) of the last claim."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "streak",
+            "type": "u16"
+          },
+          {
+            "name": "lifetimeGranted",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "faucetParams",
+      "docs": [
+        "Admin-tuned parameters of the daily gold faucet."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseReward",
+            "type": "u64"
+          },
+          {
+            "name": "streakBonus",
+            "docs": [
+              "Added per consecutive day after the first."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxStreak",
+            "docs": [
+              "Streak length after which the reward stops growing."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "lifetimeCap",
+            "docs": [
+              "Most gold one account can ever draw from the faucet; 0 = unlimited."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "diminishThreshold",
+            "docs": [
+              "Above this balance the grant shrinks in proportion; 0 = never."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goldBridge",
+      "docs": [
+        "Bridge between internal gold and the SPL gold mint. The bridge PDA is the mint authority."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "dailyWithdrawLimit",
+            "docs": [
+              "Per-player limits per window; 0 = unlimited."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dailyDepositLimit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goldBridgeUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "dailyWithdrawLimit",
+            "type": "u64"
+          },
+          {
+            "name": "dailyDepositLimit",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "itemPurchased",
-      "discriminator": [
-        33,
-        219,
-        12,
-        58,
-        205,
-        48,
-        63,
-        143
-      ]
+      "name": "goldDeposited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "itemSold",
-      "discriminator": [
-        212,
-        37,
-        218,
-        206,
-        120,
-        171,
-        56,
-        230
-      ]
+      "name": "goldSpent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "lootDeposited",
-      "discriminator": [
-        134,
-        156,
-        100,
-        60,
-        74,
-        27,
-        210,
-        100
-      ]
+      "name": "goldTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "from",
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "playerEconomyInitialized",
-      "discriminator": [
-        185,
-        66,
-        53,
-        68,
-        172,
-        168,
-        200,
-        16
-      ]
-    }
-  ],
-  "errors": [
+      "name": "goldWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "identifyRequest",
+      "docs": [
+        "Per-player slot for a relic identification waiting on VRF. Relic and fee are already paid."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pending",
+            "type": "bool"
+          },
+          {
+            "name": "hero",
+            "docs": [
+              "Hero that takes the curse if one is rolled."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "requestedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemConsumed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemConsumption",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemDefinition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "u8"
+          },
+          {
+            "name": "buyPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "sellPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxStack",
+            "docs": [
+              "Most a player may hold; 0 = unlimited."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "usable",
+            "type": "bool"
+          },
+          {
+            "name": "lootOnly",
+            "docs": [
+              "Only found in dungeons; cannot be packed for an adventure."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "effectId",
+            "docs": [
+              "`ITEM_EFFECT_*` applied when the item is used."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "effectValue",
+            "docs": [
+              "Strength of the effect, e.g. HP healed or torch restored."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemDefinitionUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "item",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
-      "code": 6000,
-      "name": "alreadyInitialized",
-      "msg": "Player economy account already initialized"
+      "name": "itemPurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "basePrice",
+            "type": "u64"
+          },
+          {
+            "name": "unitPrice",
+            "docs": [
+              "Price actually charged per unit after the demand curve."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remainingStock",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "unauthorized",
-      "msg": "Unauthorized owner access"
+      "name": "itemRegistry",
+      "docs": [
+        "Admin-managed table of every item the economy knows about."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemDefinition"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidQuantity",
-      "msg": "Quantity must be greater than zero"
+      "name": "itemRegistryInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "itemCount",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "invalidSpendAmount",
-      "msg": "Unable to spend zero gold"
+      "name": "itemSold",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "basePrice",
+            "type": "u64"
+          },
+          {
+            "name": "unitPrice",
+            "docs": [
+              "Price actually paid per unit after demand and sell-back decay."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "itemNotPurchasable",
-      "msg": "Item cannot be purchased"
+      "name": "itemStack",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "itemNotSellable",
-      "msg": "Item cannot be sold"
+      "name": "itemSupply",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "created",
+            "type": "u64"
+          },
+          {
+            "name": "destroyed",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "stackLimitExceeded",
-      "msg": "Inventory stack limit exceeded"
+      "name": "itemsCrafted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipeId",
+            "type": "u8"
+          },
+          {
+            "name": "times",
+            "type": "u16"
+          },
+          {
+            "name": "goldSpent",
+            "type": "u64"
+          },
+          {
+            "name": "outputs",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemStack"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "insufficientStock",
-      "msg": "Not enough of the requested item"
+      "name": "itemsTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "from",
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "type": "pubkey"
+          },
+          {
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "transferItem"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "insufficientGold",
-      "msg": "Not enough gold available"
+      "name": "lootDepositItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "mathOverflow",
-      "msg": "Value overflow detected"
+      "name": "lootDeposited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "gold",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "grantOnCooldown",
-      "msg": "Hourly grant still on cooldown"
+      "name": "offerCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "returned",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "inventoryOverflow",
-      "msg": "Inventory quantity too large"
+      "name": "offerFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "pricePerUnit",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Gold burned from the seller's proceeds."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remaining",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "accountNotInitialized",
-      "msg": "Player economy account is not initialized"
-    }
-  ],
-  "types": [
-    {
-      "name": "goldSpent",
+      "name": "offerPosted",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "seller",
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "offerId",
             "type": "u64"
           },
           {
-            "name": "remaining",
+            "name": "item",
+            "type": "u8"
+          },
+          {
+            "name": "quantity",
+            "type": "u16"
+          },
+          {
+            "name": "pricePerUnit",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "hourlyGrantClaimed",
+      "name": "playerEconomy",
       "type": {
         "kind": "struct",
         "fields": [
@@ -652,18 +5071,80 @@ export type PlayerEconomy = {
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "gold",
             "type": "u64"
           },
           {
-            "name": "nextAvailableAt",
+            "name": "lastGrantTs",
             "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "stashTier",
+            "docs": [
+              "Index into `STASH_TIER_SLOTS`. Carved out of the old reserved bytes, so accounts that",
+              "predate stash tiers read as tier 0."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "items",
+            "docs": [
+              "One stack per item id held; empty stacks are removed."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "itemStack"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "itemConsumed",
+      "name": "playerEconomyInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerEconomyMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "itemTypes",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "relicIdentified",
       "type": {
         "kind": "struct",
         "fields": [
@@ -671,6 +5152,21 @@ export type PlayerEconomy = {
             "name": "owner",
             "type": "pubkey"
           },
+          {
+            "name": "hero",
+            "type": "pubkey"
+          },
+          {
+            "name": "reward",
+            "docs": [
+              "One of the `RELIC_REWARD_*` kinds."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "gold",
+            "type": "u64"
+          },
           {
             "name": "item",
             "type": "u8"
@@ -683,88 +5179,130 @@ export type PlayerEconomy = {
       }
     },
     {
-      "name": "itemConsumption",
+      "name": "relicIdentifyCancelled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "item",
-            "type": {
-              "defined": {
-                "name": "itemKey"
-              }
-            }
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "quantity",
-            "type": "u16"
+            "name": "hero",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "itemKey",
-      "repr": {
-        "kind": "rust"
-      },
+      "name": "relicIdentifyRequested",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pouchGold"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "stressTonic"
+            "name": "hero",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "shopInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "minorTorch"
+            "name": "epoch",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "shopItemState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "u8"
           },
           {
-            "name": "healingSalve"
+            "name": "dailyStock",
+            "type": "u16"
           },
           {
-            "name": "mysteryRelic"
+            "name": "stock",
+            "type": "u16"
           },
           {
-            "name": "calmingIncense"
+            "name": "demand",
+            "docs": [
+              "Net units bought minus sold; halves every epoch."
+            ],
+            "type": "i32"
           },
           {
-            "name": "phoenixFeather"
+            "name": "soldToday",
+            "docs": [
+              "Units sold back this epoch, used to decay the sell price."
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "itemPurchased",
+      "name": "shopState",
+      "docs": [
+        "Global shop book: per-item stock, restocked each epoch, and the demand that drives prices."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "item",
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "quantity",
-            "type": "u16"
+            "name": "epoch",
+            "docs": [
+              "Epoch (`unix_timestamp / SHOP_EPOCH_SECONDS`) the stock was last restocked in."
+            ],
+            "type": "i64"
           },
           {
-            "name": "unitPrice",
-            "type": "u64"
+            "name": "items",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "shopItemState"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "itemSold",
+      "name": "shopStockConfigured",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "authority",
             "type": "pubkey"
           },
           {
@@ -772,102 +5310,180 @@ export type PlayerEconomy = {
             "type": "u8"
           },
           {
-            "name": "quantity",
+            "name": "dailyStock",
             "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "stashUpgraded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "unitPrice",
+            "name": "tier",
+            "type": "u8"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          },
+          {
+            "name": "cost",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "lootDepositItem",
+      "name": "tradeOffer",
+      "docs": [
+        "Trading post listing; the offered items sit in escrow here until filled or cancelled."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
           {
             "name": "item",
-            "type": {
-              "defined": {
-                "name": "itemKey"
-              }
-            }
+            "type": "u8"
           },
           {
             "name": "quantity",
             "type": "u16"
+          },
+          {
+            "name": "pricePerUnit",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "lootDeposited",
+      "name": "transferConfig",
+      "docs": [
+        "Admin limits on player-to-player transfers. A limit of zero disables that check."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "gold",
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "dailyGoldLimit",
             "type": "u64"
+          },
+          {
+            "name": "dailyItemLimit",
+            "docs": [
+              "Total item units a player may send per window, across all item types."
+            ],
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "playerEconomy",
+      "name": "transferConfigUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "gold",
+            "name": "dailyGoldLimit",
             "type": "u64"
           },
           {
-            "name": "lastGrantTs",
-            "type": "i64"
-          },
-          {
-            "name": "items",
-            "type": {
-              "array": [
-                "u16",
-                7
-              ]
-            }
-          },
+            "name": "dailyItemLimit",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transferItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "bump",
+            "name": "item",
             "type": "u8"
           },
           {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                5
-              ]
-            }
+            "name": "quantity",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "playerEconomyInitialized",
+      "name": "transferLedger",
+      "docs": [
+        "Per-player running totals for the current transfer window. Receipts are capped by the same",
+        "daily limits as sends, so many senders can't funnel into one account."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "owner",
             "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "window",
+            "docs": [
+              "Window index (`unix_timestamp / TRANSFER_WINDOW_SECONDS`) the totals belong to."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "goldSent",
+            "type": "u64"
+          },
+          {
+            "name": "itemsSent",
+            "type": "u32"
+          },
+          {
+            "name": "goldReceived",
+            "type": "u64"
+          },
+          {
+            "name": "itemsReceived",
+            "type": "u32"
           }
         ]
       }
@@ -1,3 +1,5 @@
+use hero_core::constants::TRAIT_NONE_VALUE;
+
 use crate::constants::*;
 use crate::logic::Mulberry32;
 use crate::state::{EnemyCombatant, HeroCombatant, HeroSnapshot, StatusInstance};
@@ -11,9 +13,52 @@ pub struct EnemyDefinition {
     pub resistance: u16,
     pub speed: u16,
     pub luck: u16,
+    pub ai: AiProfile,
+}
+
+/// How an enemy weighs its options when it picks a skill and a target.
+#[derive(Clone, Copy)]
+pub struct AiProfile {
+    /// Weight on the threat a hero has built up against this enemy.
+    pub threat_weight: u32,
+    /// Weight on how badly wounded a hero is.
+    pub low_hp_weight: u32,
+    /// Weight on a hero being open to the attack: unguarded, or free of the status it applies.
+    pub vulnerability_weight: u32,
+    /// Bonus for a status skill while some reachable hero does not have that status yet.
+    pub status_skill_bias: u32,
 }
 
-const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
+/// Goes for whoever is hurting it most.
+pub const AI_BRUTE: AiProfile = AiProfile {
+    threat_weight: 3,
+    low_hp_weight: 1,
+    vulnerability_weight: 1,
+    status_skill_bias: 20,
+};
+
+/// Picks off the weakest hero.
+pub const AI_HUNTER: AiProfile = AiProfile {
+    threat_weight: 1,
+    low_hp_weight: 4,
+    vulnerability_weight: 1,
+    status_skill_bias: 20,
+};
+
+/// Spreads statuses and looks for openings.
+pub const AI_TACTICIAN: AiProfile = AiProfile {
+    threat_weight: 1,
+    low_hp_weight: 1,
+    vulnerability_weight: 4,
+    status_skill_bias: 80,
+};
+
+/// Regular enemies are drawn from the first `REGULAR_ENEMY_COUNT` definitions; bosses follow.
+pub const REGULAR_ENEMY_COUNT: usize = 11;
+pub const FIRST_BOSS_KIND: u8 = REGULAR_ENEMY_COUNT as u8;
+pub const BOSS_COUNT: usize = 2;
+
+const ENEMY_DEFINITIONS: [EnemyDefinition; REGULAR_ENEMY_COUNT + BOSS_COUNT] = [
     EnemyDefinition {
         kind: 0,
         max_hp: 86,
@@ -23,6 +68,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 12,
         speed: 9,
         luck: 7,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 1,
@@ -33,6 +79,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 11,
         speed: 8,
         luck: 6,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 2,
@@ -43,6 +90,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 10,
         speed: 10,
         luck: 7,
+        ai: AI_HUNTER,
     },
     EnemyDefinition {
         kind: 3,
@@ -53,16 +101,18 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 12,
         speed: 11,
         luck: 8,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 4,
         max_hp: 60,
         attack: 14,
         defense: 11,
-        magic: 6,
+        magic: 14,
         resistance: 9,
         speed: 10,
         luck: 9,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 5,
@@ -73,6 +123,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 11,
         speed: 10,
         luck: 8,
+        ai: AI_HUNTER,
     },
     EnemyDefinition {
         kind: 6,
@@ -83,6 +134,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 8,
         speed: 11,
         luck: 9,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 7,
@@ -93,6 +145,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 9,
         speed: 12,
         luck: 10,
+        ai: AI_HUNTER,
     },
     EnemyDefinition {
         kind: 8,
@@ -103,6 +156,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 7,
         speed: 7,
         luck: 6,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 9,
@@ -113,6 +167,7 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 12,
         speed: 9,
         luck: 6,
+        ai: AI_BRUTE,
     },
     EnemyDefinition {
         kind: 10,
@@ -123,6 +178,31 @@ const ENEMY_DEFINITIONS: [EnemyDefinition; 11] = [
         resistance: 9,
         speed: 13,
         luck: 9,
+        ai: AI_HUNTER,
+    },
+    // Orc Warchief (boss)
+    EnemyDefinition {
+        kind: 11,
+        max_hp: 220,
+        attack: 24,
+        defense: 18,
+        magic: 8,
+        resistance: 14,
+        speed: 10,
+        luck: 10,
+        ai: AI_TACTICIAN,
+    },
+    // Bone Colossus (boss)
+    EnemyDefinition {
+        kind: 12,
+        max_hp: 200,
+        attack: 21,
+        defense: 16,
+        magic: 14,
+        resistance: 18,
+        speed: 9,
+        luck: 8,
+        ai: AI_TACTICIAN,
     },
 ];
 
@@ -146,6 +226,15 @@ pub fn convert_hero_snapshot(snapshot: &HeroSnapshot, index: usize, torch: u8) -
         pending_xp: 0,
         pending_positive_traits: 0,
         pending_negative_traits: 0,
+        rank: index as u8 + 1,
+        stress_max: snapshot.stress_max,
+        positive_traits: snapshot
+            .positive_traits
+            .iter()
+            .filter(|value| **value != TRAIT_NONE_VALUE)
+            .count() as u8,
+        resolve: RESOLVE_NONE,
+        death_blows_survived: 0,
     };
 
     apply_hero_torch_bonuses(&mut hero, torch);
@@ -171,7 +260,9 @@ pub fn spawn_enemy(kind: u8, torch: u8) -> EnemyCombatant {
         speed: apply_enemy_torch_speed(def.speed, torch),
         luck: apply_enemy_torch_luck(def.luck, torch),
         statuses: [StatusInstance::default(); MAX_STATUS_PER_COMBATANT],
-        threat: 0,
+        threat: [0; MAX_PARTY],
+        phase: 0,
+        rank: 0,
     };
 
     // Nerf enemy stats by 25%
@@ -201,14 +292,48 @@ pub fn select_enemy_party(seed: u64, torch: u8) -> ([EnemyCombatant; MAX_ENEMIES
 
     let mut enemies = [EnemyCombatant::default(); MAX_ENEMIES];
     for i in 0..enemy_count {
-        let kind = (rng.next_u32() as usize) % ENEMY_DEFINITIONS.len();
+        let kind = (rng.next_u32() as usize) % REGULAR_ENEMY_COUNT;
         enemies[i as usize] = spawn_enemy(kind as u8, torch);
+        enemies[i as usize].rank = i + 1;
     }
 
     let next_state = ((rng.next_u32() as u64) << 32) ^ seed.rotate_left(7);
     (enemies, enemy_count, next_state)
 }
 
+/// A boss fight: a small escort that grows as the torch burns down, with the boss in the last
+/// slot behind them.
+pub fn select_boss_party(seed: u64, torch: u8) -> ([EnemyCombatant; MAX_ENEMIES], u8, u64) {
+    let folded = (seed as u32) ^ ((seed >> 32) as u32);
+    let mut rng = Mulberry32::new(folded);
+
+    let boss_kind = FIRST_BOSS_KIND + (rng.next_u32() as usize % BOSS_COUNT) as u8;
+    let escorts = if torch <= 33 { 2 } else { 1 };
+    let enemy_count = (1 + escorts).min(MAX_ENEMIES as u8);
+
+    let mut enemies = [EnemyCombatant::default(); MAX_ENEMIES];
+    // Escorts hold the front line; the boss waits behind them.
+    for i in 0..enemy_count - 1 {
+        let kind = (rng.next_u32() as usize) % REGULAR_ENEMY_COUNT;
+        enemies[i as usize] = spawn_enemy(kind as u8, torch);
+        enemies[i as usize].rank = i + 1;
+    }
+    let boss_slot = (enemy_count - 1) as usize;
+    enemies[boss_slot] = spawn_enemy(boss_kind, torch);
+    enemies[boss_slot].rank = enemy_count;
+
+    let next_state = ((rng.next_u32() as u64) << 32) ^ seed.rotate_left(11);
+    (enemies, enemy_count, next_state)
+}
+
+pub fn is_boss_kind(kind: u8) -> bool {
+    kind >= FIRST_BOSS_KIND && (kind as usize) < ENEMY_DEFINITIONS.len()
+}
+
+pub fn enemy_ai_profile(kind: u8) -> AiProfile {
+    get_enemy_definition(kind).ai
+}
+
 fn get_enemy_definition(kind: u8) -> &'static EnemyDefinition {
     let idx = (kind as usize) % ENEMY_DEFINITIONS.len();
     &ENEMY_DEFINITIONS[idx]
@@ -6,6 +6,8 @@ pub const MAX_ROOMS: usize = 40;
 pub const MAX_DOORS: usize = 64;
 pub const MAX_CHESTS: usize = 64;
 pub const MAX_PORTALS: usize = 8;
+pub const MAX_TRAPS: usize = 32;
+pub const MAX_CURIOS: usize = 16;
 pub const MAX_PARTY: usize = 4;
 pub const MAX_ITEMS: usize = 6;
 
@@ -14,19 +16,99 @@ pub const MAX_COMBATANTS: usize = MAX_PARTY + MAX_ENEMIES;
 pub const MAX_STATUS_PER_COMBATANT: usize = 4;
 pub const STATUS_POOL_SIZE: usize = MAX_COMBATANTS * MAX_STATUS_PER_COMBATANT;
 
+// Combat log ring buffer and entry flags
+pub const COMBAT_LOG_CAPACITY: usize = 16;
+pub const COMBAT_LOG_CRIT: u8 = 1 << 0;
+pub const COMBAT_LOG_MISSED: u8 = 1 << 1;
+pub const COMBAT_LOG_KILLED: u8 = 1 << 2;
+
 pub const RESET_INTERVAL_SECONDS: i64 = 24 * 60 * 60;
 pub const CREW_EXPIRY_SECONDS: i64 = RESET_INTERVAL_SECONDS;
 
 pub const TILE_FLOOR: u8 = 0;
 pub const TILE_WALL: u8 = 1;
+pub const TILE_SPIKE_TRAP: u8 = 2;
+pub const TILE_POISON_GAS: u8 = 3;
+pub const TILE_COLLAPSING_FLOOR: u8 = 4;
+pub const TILE_PRESSURE_PLATE: u8 = 5;
+pub const TILE_DOOR: u8 = 6;
+
+// Trap state flags (stored per trap in AdventureSession.trap_states)
+pub const TRAP_REVEALED: u8 = 1 << 0;
+pub const TRAP_DISARMED: u8 = 1 << 1;
+pub const TRAP_TRIGGERED: u8 = 1 << 2;
+
+pub const TRAP_SPAWN_CHANCE: f32 = 0.35;
+pub const SPIKE_TRAP_DAMAGE: u8 = 8;
+pub const COLLAPSE_TRAP_DAMAGE: u8 = 4;
+pub const COLLAPSE_TRAP_TORCH_BURN: u8 = 10;
+pub const PRESSURE_PLATE_STRESS: u16 = 15;
+pub const PRESSURE_PLATE_TORCH_BURN: u8 = 15;
+pub const POISON_GAS_DURATION: u8 = 3;
+
+// Door lock kinds (stored per door in AdventureSession.door_locks)
+pub const DOOR_CLOSED: u8 = 0;
+pub const DOOR_LOCKED: u8 = 1;
+pub const DOOR_SEALED: u8 = 2;
+
+pub const DOOR_LOCKED_CHANCE: f32 = 0.3;
+pub const DOOR_SEALED_CHANCE: f32 = 0.1;
+pub const MAX_KEYS: u8 = 9;
+pub const CHEST_KEY_CHANCE: f32 = 0.2;
+pub const ELITE_KEY_CHANCE_BPS: u16 = 5_000;
+pub const ELITE_ENEMY_KINDS: [u8; 2] = [2, 9]; // Elite Orc, Werebear
+pub const LOCKPICK_BASE_BPS: u32 = 3_000;
+pub const LOCKPICK_LUCK_BPS: u32 = 60;
+pub const LOCKPICK_MAX_BPS: u32 = 9_000;
+pub const LOCKPICK_FAIL_TORCH_BURN: u8 = 5;
+pub const LOCKPICK_FAIL_STRESS: u16 = 5;
+
+pub const BOSS_ROOM_NONE: u8 = u8::MAX;
+
+// Curio kinds (stored per curio in AdventureSession.curio_kinds)
+pub const CURIO_SHRINE: u8 = 0;
+pub const CURIO_CAMPFIRE: u8 = 1;
+pub const CURIO_CORPSE: u8 = 2;
+
+pub const CURIO_SPAWN_CHANCE: f32 = 0.3;
+pub const SHRINE_STRESS_MIN: u16 = 8;
+pub const SHRINE_STRESS_MAX: u16 = 16;
+pub const SHRINE_BLESSING_MIN_COMBATS: u16 = 2;
+pub const SHRINE_BLESSING_MAX_COMBATS: u16 = 4;
+pub const SHRINE_BLESSING_PERCENT: u16 = 15;
+pub const CAMPFIRE_HEAL_PERCENT: u16 = 35;
+pub const CAMPFIRE_STRESS_RELIEF: u16 = 5;
+pub const CAMPFIRE_TORCH_COST: u8 = 20;
+pub const CORPSE_LOOT_CHANCE: f32 = 0.6;
+pub const CORPSE_STRESS: u16 = 10;
+
+pub const ITEM_FIREWOOD: u8 = player_economy::ITEM_FIREWOOD;
+pub const MAX_CAMPS_PER_RUN: u8 = 2;
+pub const CAMP_BASE_HEAL_PERCENT: u16 = 20;
+pub const CAMP_HEALER_HEAL_BONUS: u16 = 10; // per living Priest or Knight Templar
+pub const CAMP_BASE_STRESS_RELIEF: u16 = 10;
+pub const CAMP_PRIEST_STRESS_BONUS: u16 = 10; // per living Priest
+pub const CAMP_AMBUSH_BASE_BPS: u32 = 2_500;
+pub const CAMP_WATCH_AMBUSH_REDUCTION_BPS: u32 = 500; // per living Knight, Soldier or Axeman
+pub const CAMP_AMBUSH_MIN_BPS: u32 = 500;
+pub const AMBUSH_INITIATIVE_BONUS: i16 = 10;
+
+// Resolve: what a hero becomes on hitting max stress. Lasts for the rest of the run.
+pub const RESOLVE_NONE: u8 = 0;
+pub const AFFLICTION_STUBBORN: u8 = 1; // may refuse orders
+pub const AFFLICTION_FRENZIED: u8 = 2; // may strike a random enemy
+pub const AFFLICTION_DESPAIRING: u8 = 3; // stresses allies every turn
+pub const VIRTUE_STALWART: u8 = 4; // bonus attack and defense
+pub const VIRTUE_INSPIRING: u8 = 5; // relieves party stress every turn
+pub const AFFLICTION_COUNT: u8 = 3;
+pub const VIRTUE_BASE_BPS: u32 = 2_500;
+pub const VIRTUE_LUCK_BPS: u32 = 50; // per point of combat luck
+pub const VIRTUE_TRAIT_BPS: u32 = 500; // per positive trait
+pub const VIRTUE_MAX_BPS: u32 = 6_000;
 
 pub const DEFAULT_COMMIT_FREQUENCY_MS: u32 = 500;
 pub const PORTAL_NONE: u8 = u8::MAX;
 
-// Loot-only items (can only be found/looted, not brought to adventures)
-pub const ITEM_POUCH_GOLD: u8 = 0;
-pub const ITEM_MYSTERY_RELIC: u8 = 4;
-
 pub const POUCH_GOLD_VALUE: u64 = 25;
 pub const DUNGEON_FEE_BPS: u64 = 300; // 3%
 pub const BPS_DENOMINATOR: u64 = 10_000;
@@ -88,4 +88,48 @@ pub enum AdventureError {
     CombatNotResolved,
     #[msg("item cannot be used")]
     ItemNotUsable,
+    #[msg("action is blocked while heroes are in combat")]
+    BlockedInCombat,
+    #[msg("no trap found at this index")]
+    TrapNotFound,
+    #[msg("trap has not been revealed")]
+    TrapNotRevealed,
+    #[msg("trap was already disarmed or triggered")]
+    TrapAlreadyResolved,
+    #[msg("trap is out of reach")]
+    TrapOutOfReach,
+    #[msg("no door found at this index")]
+    DoorNotFound,
+    #[msg("door is already open")]
+    DoorAlreadyOpen,
+    #[msg("door is out of reach")]
+    DoorOutOfReach,
+    #[msg("door is closed")]
+    DoorClosed,
+    #[msg("door is locked")]
+    DoorLocked,
+    #[msg("sealed doors can only be opened with a key")]
+    DoorSealed,
+    #[msg("party has no keys")]
+    NoKeys,
+    #[msg("this encounter cannot be avoided")]
+    ForcedEncounterPending,
+    #[msg("no curio at the party's position")]
+    NoCurioAtPosition,
+    #[msg("curio has already been used")]
+    CurioAlreadyUsed,
+    #[msg("an encounter is pending")]
+    EncounterPending,
+    #[msg("no camps left for this run")]
+    NoCampsLeft,
+    #[msg("ability cannot be used from this rank")]
+    AbilityNotUsableFromRank,
+    #[msg("target is out of reach from this rank")]
+    TargetOutOfReach,
+    #[msg("account is not a legacy adventure session")]
+    NotLegacyAdventure,
+    #[msg("adventure account mismatch")]
+    InvalidAdventureAccount,
+    #[msg("hero has not fallen")]
+    HeroAlreadyAlive,
 }
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::AdventureError;
+use crate::logic::Mulberry32;
+use crate::state::{AdventureSession, EncounterKind, ItemSlot};
+use crate::Camp;
+
+// Hero type order follows hero-core generation table.
+const HERO_ARMORED_AXEMAN: u8 = 1;
+const HERO_KNIGHT: u8 = 2;
+const HERO_KNIGHT_TEMPLAR: u8 = 3;
+const HERO_PRIEST: u8 = 4;
+const HERO_SOLDIER: u8 = 5;
+
+/// Burn a Firewood to rest. Healers in the party improve the rest, sturdy heroes keep watch
+/// against an ambush. An ambush interrupts the camp and forces a fight with enemies acting first.
+pub fn camp(ctx: Context<Camp>) -> Result<()> {
+    let adventure = &mut ctx.accounts.adventure;
+    let owner = ctx.accounts.owner.key();
+    let authority = ctx.accounts.authority.key();
+
+    let is_authorized = authority == owner || adventure.delegate == Some(authority);
+    require!(is_authorized, AdventureError::Unauthorized);
+
+    require!(adventure.is_active, AdventureError::AdventureNotActive);
+    require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
+    require!(!adventure.in_combat, AdventureError::BlockedInCombat);
+    require!(
+        adventure.pending_encounter_seed == 0,
+        AdventureError::EncounterPending
+    );
+    require!(
+        adventure.camps_used < MAX_CAMPS_PER_RUN,
+        AdventureError::NoCampsLeft
+    );
+
+    consume_firewood(adventure)?;
+    adventure.camps_used += 1;
+
+    let hero_count = (adventure.hero_count as usize).min(MAX_PARTY);
+    let mut healers: u16 = 0;
+    let mut priests: u16 = 0;
+    let mut watchers: u32 = 0;
+    for hero in adventure.hero_snapshots.iter().take(hero_count) {
+        if hero.current_hp == 0 {
+            continue;
+        }
+        match hero.hero_type {
+            HERO_PRIEST => {
+                healers += 1;
+                priests += 1;
+            }
+            HERO_KNIGHT_TEMPLAR => healers += 1,
+            HERO_ARMORED_AXEMAN | HERO_KNIGHT | HERO_SOLDIER => watchers += 1,
+            _ => {}
+        }
+    }
+
+    let clock = Clock::get()?;
+    let mut seed = adventure.seed
+        ^ ((adventure.camps_used as u32) << 24)
+        ^ (clock.slot as u32)
+        ^ (clock.unix_timestamp as u32).rotate_left(13)
+        ^ ((adventure.party_position.x as u32) << 8)
+        ^ (adventure.party_position.y as u32);
+    if seed == 0 {
+        seed = adventure.seed.wrapping_add(1);
+    }
+    let mut rng = Mulberry32::new(seed);
+
+    let ambush_bps = CAMP_AMBUSH_BASE_BPS
+        .saturating_sub(watchers * CAMP_WATCH_AMBUSH_REDUCTION_BPS)
+        .max(CAMP_AMBUSH_MIN_BPS);
+    if rng.next_u32() % 10_000 < ambush_bps {
+        let mut encounter_seed = ((rng.next_u32() as u64) << 32) | rng.next_u32() as u64;
+        if encounter_seed == 0 {
+            encounter_seed = 1;
+        }
+        adventure.pending_encounter_seed = encounter_seed;
+        adventure.pending_encounter_kind = EncounterKind::Ambush;
+        msg!("The camp is ambushed");
+        return Ok(());
+    }
+
+    let heal_percent = CAMP_BASE_HEAL_PERCENT + healers * CAMP_HEALER_HEAL_BONUS;
+    let stress_relief = CAMP_BASE_STRESS_RELIEF + priests * CAMP_PRIEST_STRESS_BONUS;
+    for hero in adventure.hero_snapshots.iter_mut().take(hero_count) {
+        if hero.current_hp == 0 {
+            continue;
+        }
+        let heal = (hero.max_hp as u16 * heal_percent / 100).max(1);
+        hero.current_hp = (hero.current_hp as u16 + heal).min(hero.max_hp as u16) as u8;
+        hero.stress = hero.stress.saturating_sub(stress_relief);
+    }
+
+    msg!(
+        "The party camps: heal {}%, stress -{}",
+        heal_percent,
+        stress_relief
+    );
+
+    Ok(())
+}
+
+fn consume_firewood(adventure: &mut AdventureSession) -> Result<()> {
+    let slot = adventure
+        .items
+        .iter_mut()
+        .find(|slot| slot.item_key == ITEM_FIREWOOD && slot.quantity > 0)
+        .ok_or(AdventureError::ItemNotFound)?;
+
+    slot.quantity -= 1;
+    if slot.quantity == 0 {
+        *slot = ItemSlot::empty();
+        adventure.item_count = adventure.item_count.saturating_sub(1);
+    }
+
+    Ok(())
+}
@@ -1,35 +1,137 @@
-use anchor_lang::prelude::AccountsClose;
 use anchor_lang::prelude::*;
 
-use crate::combat::{convert_hero_snapshot, select_enemy_party};
+use crate::combat::{
+    convert_hero_snapshot, enemy_ai_profile, is_boss_kind, select_boss_party, select_enemy_party,
+    spawn_enemy, AiProfile, FIRST_BOSS_KIND,
+};
 use crate::constants::{
-    ENEMY_AP_MAX, HERO_AP_MAX, MAX_COMBATANTS, MAX_ENEMIES, MAX_ITEMS, MAX_PARTY,
-    MAX_STATUS_PER_COMBATANT,
+    AFFLICTION_COUNT, AFFLICTION_DESPAIRING, AFFLICTION_FRENZIED, AFFLICTION_STUBBORN,
+    AMBUSH_INITIATIVE_BONUS, COMBAT_LOG_CAPACITY, COMBAT_LOG_CRIT, COMBAT_LOG_KILLED,
+    COMBAT_LOG_MISSED, ELITE_ENEMY_KINDS, ELITE_KEY_CHANCE_BPS, ENEMY_AP_MAX, HERO_AP_MAX,
+    MAX_COMBATANTS, MAX_ENEMIES, MAX_ITEMS, MAX_KEYS, MAX_PARTY, MAX_STATUS_PER_COMBATANT,
+    RESOLVE_NONE, SHRINE_BLESSING_PERCENT, VIRTUE_BASE_BPS, VIRTUE_INSPIRING, VIRTUE_LUCK_BPS,
+    VIRTUE_MAX_BPS, VIRTUE_STALWART, VIRTUE_TRAIT_BPS,
 };
 use crate::errors::AdventureError;
+use crate::item_effects::{apply_item_effect, item_effect, ItemEffect};
 use crate::state::{
-    AdventureCombat, AdventureSession, CombatResolutionState, CombatantKind, EnemyCombatant,
-    HeroCombatant, HeroSnapshot, InitiativeSlot, ItemSlot, StatusEffect, StatusInstance,
+    AdventureCombat, AdventureSession, CombatLogAction, CombatLogEntry, CombatResolutionState,
+    CombatantKind, EncounterKind, EnemyCombatant, HeroCombatant, HeroSnapshot, InitiativeSlot,
+    ItemSlot, StatusEffect, StatusInstance,
 };
 use crate::{BeginEncounter, ConcludeCombat, DeclineEncounter, SubmitCombatAction};
 
+use player_economy::state::ItemRegistry;
+
 const CRIT_MULTIPLIER_PERCENT: u16 = 150;
 const BASE_CRIT_PERCENT: u16 = 5;
 const CRIT_PER_LUCK_BPS: u16 = 50; // 0.5% per luck point
 const MAX_CRIT_PERCENT: u16 = 60;
 
+// Status tuning, per stack where it stacks.
+const MARK_DAMAGE_PERCENT: u16 = 15;
+const VULNERABLE_MITIGATION_PERCENT: u16 = 20;
+const BLIND_MISS_BPS: u16 = 2000;
+const REGEN_HEAL_PER_STACK: u16 = 3;
+// Stun is resisted more readily than other statuses.
+const STUN_RESIST_BPS_PER_POINT: i32 = 70;
+const STATUS_RESIST_BPS_PER_POINT: i32 = 45;
+
+// Stress and resolve tuning.
+const CRIT_STRESS: u16 = 8;
+const STUBBORN_REFUSE_BPS: u16 = 2500;
+const FRENZIED_LASH_OUT_BPS: u16 = 3000;
+const DESPAIR_ALLY_STRESS: u16 = 4;
+const INSPIRING_PARTY_RELIEF: u16 = 5;
+const STALWART_STAT_PERCENT: u16 = 15;
+
+// Death's Door: stress on arrival and per resisted death blow, and the death blow resist.
+const DEATHS_DOOR_STRESS: u16 = 10;
+const DEATH_BLOW_STRESS: u16 = 15;
+const DEATH_BLOW_BASE_RESIST_BPS: u32 = 5_000;
+const DEATH_BLOW_RESIST_BPS_PER_POINT: u32 = 40;
+const DEATH_BLOW_TRAIT_BPS: u32 = 300;
+const DEATH_BLOW_MAX_RESIST_BPS: u32 = 8_500;
+// Chance per death blow survived that the ordeal leaves a negative trait.
+const DEATH_BLOW_TRAIT_CHANCE_BPS: u16 = 3_500;
+
+// Enemy AI: one point of threat per this much damage taken, a quarter of all threat fades each
+// enemy turn, and scores get up to this much random jitter.
+const DAMAGE_PER_THREAT: u16 = 2;
+const THREAT_DECAY_DIVISOR: u8 = 4;
+const AI_JITTER: u32 = 32;
+
 #[derive(Clone, Copy)]
 struct AbilitySpec {
     cost: u8,
-    #[allow(dead_code)]
     kind: HeroActionKind,
     damage_type: DamageType,
     power_percent: u16,
     status: Option<StatusApplication>,
-    #[allow(dead_code)]
     target: Targeting,
+    /// Ranks (bitmask, bit 0 = rank 1) the user may act from.
+    usable_ranks: u8,
+    /// Ranks (bitmask) of the opposing side the ability can reach.
+    target_ranks: u8,
+    /// Ranks a surviving target is pushed back.
+    knockback: u8,
+    area: AreaOfEffect,
+    /// Share of the ability's power that reaches secondary targets of an area ability.
+    falloff_percent: u16,
+    /// Threat the user draws from every enemy on top of the threat from damage dealt.
+    threat: u8,
+    /// Stress dealt to each hero the ability hits.
+    stress: u16,
+    /// Enemy kind called into an empty enemy slot.
+    summon: Option<u8>,
+}
+
+impl AbilitySpec {
+    const fn ranged(mut self) -> Self {
+        self.target_ranks = RANKS_ALL;
+        self
+    }
+
+    const fn with_knockback(mut self, ranks: u8) -> Self {
+        self.knockback = ranks;
+        self
+    }
+
+    const fn with_stress(mut self, stress: u16) -> Self {
+        self.stress = stress;
+        self
+    }
+
+    const fn with_area(mut self, area: AreaOfEffect, falloff_percent: u16) -> Self {
+        self.area = area;
+        self.falloff_percent = falloff_percent;
+        self
+    }
+
+    /// The ability as it lands on a secondary target.
+    fn splashed(mut self) -> Self {
+        self.power_percent = self.power_percent.saturating_mul(self.falloff_percent) / 100;
+        self.knockback = 0;
+        self
+    }
+}
+
+/// Who besides the chosen target an ability reaches.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AreaOfEffect {
+    Single,
+    /// The combatants one rank in front of and behind the target.
+    Adjacent,
+    /// Every living combatant on the target's side.
+    All,
 }
 
+const RANKS_ALL: u8 = 0b1111;
+const RANKS_FRONT: u8 = 0b0011;
+const RANKS_FRONT_THREE: u8 = 0b0111;
+const RANKS_BACK_THREE: u8 = 0b1110;
+const RANKS_BACK: u8 = 0b1100;
+
 #[derive(Clone, Copy)]
 struct StatusApplication {
     effect: StatusEffect,
@@ -51,11 +153,14 @@ enum Targeting {
     Enemy,
     Ally,
     SelfOnly,
-    #[allow(dead_code)]
     None,
 }
 
 // Hero type order follows hero-core generation table.
+const HERO_ARCHER: u8 = 0;
+const HERO_PRIEST: u8 = 4;
+const HERO_WIZARD: u8 = 7;
+
 const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
     // Archer
     HeroAbilitySet {
@@ -64,8 +169,21 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
             kind: HeroActionKind::Skill1,
             damage_type: DamageType::Physical,
             power_percent: 165,
-            status: None,
+            status: Some(StatusApplication {
+                effect: StatusEffect::Mark,
+                base_duration: 3,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_BACK_THREE,
+            target_ranks: RANKS_ALL,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
@@ -79,6 +197,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: 0,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_BACK,
+            target_ranks: RANKS_BACK_THREE,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Armored Axeman
@@ -90,6 +216,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
             power_percent: 175,
             status: None,
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
@@ -103,17 +237,39 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: -5,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT_THREE,
+            knockback: 0,
+            area: AreaOfEffect::Adjacent,
+            falloff_percent: 50,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Knight
     HeroAbilitySet {
+        // Shield bash: taunts every enemy and may stun
         skill1: AbilitySpec {
             cost: 2,
             kind: HeroActionKind::Skill1,
             damage_type: DamageType::Physical,
             power_percent: 170,
-            status: None,
+            status: Some(StatusApplication {
+                effect: StatusEffect::Stun,
+                base_duration: 1,
+                stacks: 1,
+                resist_modifier: -5,
+            }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT,
+            knockback: 1,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 20,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
@@ -127,6 +283,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: -5,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Knight Templar
@@ -138,6 +302,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
             power_percent: 180,
             status: None,
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT_THREE,
+            target_ranks: RANKS_FRONT,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
@@ -151,6 +323,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: -5,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT_THREE,
+            target_ranks: RANKS_FRONT,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Priest
@@ -160,16 +340,42 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
             kind: HeroActionKind::Skill1,
             damage_type: DamageType::Magical,
             power_percent: 160,
-            status: None,
+            status: Some(StatusApplication {
+                effect: StatusEffect::Blind,
+                base_duration: 2,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_BACK_THREE,
+            target_ranks: RANKS_FRONT_THREE,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
             kind: HeroActionKind::Skill2,
             damage_type: DamageType::Heal,
-            power_percent: 0,
-            status: None,
+            power_percent: 160,
+            status: Some(StatusApplication {
+                effect: StatusEffect::Regen,
+                base_duration: 2,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
             target: Targeting::Ally,
+            usable_ranks: RANKS_ALL,
+            target_ranks: RANKS_ALL,
+            knockback: 0,
+            area: AreaOfEffect::All,
+            falloff_percent: 60,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Soldier
@@ -181,6 +387,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
             power_percent: 170,
             status: None,
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT,
+            knockback: 1,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
@@ -194,6 +408,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: 0,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT_THREE,
+            target_ranks: RANKS_FRONT_THREE,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Swordsman
@@ -205,14 +427,35 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
             power_percent: 180,
             status: None,
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
             kind: HeroActionKind::Skill2,
             damage_type: DamageType::Physical,
             power_percent: 205,
-            status: None,
+            status: Some(StatusApplication {
+                effect: StatusEffect::Vulnerable,
+                base_duration: 3,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_FRONT,
+            target_ranks: RANKS_FRONT,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
     // Wizard
@@ -229,6 +472,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: 0,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_BACK_THREE,
+            target_ranks: RANKS_BACK_THREE,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
         skill2: AbilitySpec {
             cost: 3,
@@ -242,6 +493,14 @@ const HERO_ABILITY_SETS: [HeroAbilitySet; 8] = [
                 resist_modifier: 0,
             }),
             target: Targeting::Enemy,
+            usable_ranks: RANKS_BACK,
+            target_ranks: RANKS_ALL,
+            knockback: 0,
+            area: AreaOfEffect::All,
+            falloff_percent: 60,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     },
 ];
@@ -259,11 +518,11 @@ struct EnemyAbilitySet {
     skill2: AbilitySpec,
 }
 
-const ENEMY_ABILITY_SETS: [EnemyAbilitySet; 11] = [
-    // Armored Orc
+const ENEMY_ABILITY_SETS: [EnemyAbilitySet; 13] = [
+    // Armored Orc: shields the line
     EnemyAbilitySet {
         basic: ability_enemy_physical(120, None),
-        skill1: ability_enemy_physical(150, None),
+        skill1: ability_enemy_guard(2),
         skill2: ability_enemy_physical(
             155,
             Some(StatusApplication {
@@ -277,7 +536,7 @@ const ENEMY_ABILITY_SETS: [EnemyAbilitySet; 11] = [
     // Armored Skeleton
     EnemyAbilitySet {
         basic: ability_enemy_physical(125, None),
-        skill1: ability_enemy_physical(160, None),
+        skill1: ability_enemy_physical(160, None).with_stress(8),
         skill2: ability_enemy_physical(
             165,
             Some(StatusApplication {
@@ -291,7 +550,7 @@ const ENEMY_ABILITY_SETS: [EnemyAbilitySet; 11] = [
     // Elite Orc
     EnemyAbilitySet {
         basic: ability_enemy_physical(135, None),
-        skill1: ability_enemy_physical(160, None),
+        skill1: ability_enemy_physical(160, None).with_area(AreaOfEffect::Adjacent, 50),
         skill2: ability_enemy_physical(
             175,
             Some(StatusApplication {
@@ -306,18 +565,35 @@ const ENEMY_ABILITY_SETS: [EnemyAbilitySet; 11] = [
     EnemyAbilitySet {
         basic: ability_enemy_physical(125, None),
         skill1: ability_enemy_physical(165, None),
-        skill2: ability_enemy_physical(170, None),
+        skill2: ability_enemy_physical(
+            170,
+            Some(StatusApplication {
+                effect: StatusEffect::Vulnerable,
+                base_duration: 2,
+                stacks: 1,
+                resist_modifier: -5,
+            }),
+        ),
     },
-    // Orc
+    // Orc: hexes from any rank and patches up the warband
     EnemyAbilitySet {
         basic: ability_enemy_physical(120, None),
-        skill1: ability_enemy_physical(145, None),
-        skill2: ability_enemy_physical(165, None),
+        skill1: ability_enemy_magical(145, None),
+        skill2: ability_enemy_heal(200),
     },
     // Orc Rider
     EnemyAbilitySet {
         basic: ability_enemy_physical(140, None),
-        skill1: ability_enemy_physical(155, None),
+        skill1: ability_enemy_physical(
+            155,
+            Some(StatusApplication {
+                effect: StatusEffect::Stun,
+                base_duration: 1,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
+        )
+        .with_knockback(1),
         skill2: ability_enemy_physical(165, None),
     },
     // Skeleton (Sword)
@@ -328,30 +604,137 @@ const ENEMY_ABILITY_SETS: [EnemyAbilitySet; 11] = [
     },
     // Skeleton (Bow)
     EnemyAbilitySet {
-        basic: ability_enemy_physical(110, None),
-        skill1: ability_enemy_physical(135, None),
-        skill2: ability_enemy_physical(155, None),
+        basic: ability_enemy_physical(110, None).ranged(),
+        skill1: ability_enemy_physical(
+            135,
+            Some(StatusApplication {
+                effect: StatusEffect::Mark,
+                base_duration: 3,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
+        )
+        .ranged(),
+        skill2: ability_enemy_physical(155, None).ranged(),
     },
     // Slime
     EnemyAbilitySet {
         basic: ability_enemy_physical(110, None),
-        skill1: ability_enemy_physical(125, None),
-        skill2: ability_enemy_physical(140, None),
+        skill1: ability_enemy_physical(
+            125,
+            Some(StatusApplication {
+                effect: StatusEffect::Vulnerable,
+                base_duration: 3,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
+        ),
+        // Splits off a fresh slime
+        skill2: ability_enemy_summon(ENEMY_KIND_SLIME),
     },
     // Werebear
     EnemyAbilitySet {
         basic: ability_enemy_physical(140, None),
-        skill1: ability_enemy_physical(165, None),
-        skill2: ability_enemy_physical(175, None),
+        skill1: ability_enemy_physical(165, None).with_area(AreaOfEffect::Adjacent, 60),
+        skill2: ability_enemy_physical(175, None).with_knockback(1),
     },
     // Werewolf
     EnemyAbilitySet {
         basic: ability_enemy_physical(130, None),
-        skill1: ability_enemy_physical(155, None),
-        skill2: ability_enemy_physical(170, None),
+        skill1: ability_enemy_physical(
+            155,
+            Some(StatusApplication {
+                effect: StatusEffect::Blind,
+                base_duration: 2,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
+        ),
+        skill2: ability_enemy_physical(170, None).with_stress(12),
+    },
+    // Orc Warchief (boss)
+    EnemyAbilitySet {
+        basic: ability_enemy_physical(130, None),
+        skill1: ability_enemy_physical(160, None).with_stress(10),
+        skill2: ability_enemy_physical(
+            180,
+            Some(StatusApplication {
+                effect: StatusEffect::Bleed,
+                base_duration: 3,
+                stacks: 1,
+                resist_modifier: -10,
+            }),
+        ),
+    },
+    // Bone Colossus (boss)
+    EnemyAbilitySet {
+        basic: ability_enemy_physical(125, None),
+        skill1: ability_enemy_magical(
+            150,
+            Some(StatusApplication {
+                effect: StatusEffect::Chill,
+                base_duration: 2,
+                stacks: 1,
+                resist_modifier: -5,
+            }),
+        )
+        .with_stress(15),
+        // Raises a skeleton to fill the ranks
+        skill2: ability_enemy_summon(ENEMY_KIND_SKELETON_SWORD),
+    },
+];
+
+// Second-phase ability sets, indexed by `kind - FIRST_BOSS_KIND`.
+const BOSS_ENRAGED_ABILITY_SETS: [EnemyAbilitySet; 2] = [
+    // Orc Warchief: blood frenzy
+    EnemyAbilitySet {
+        basic: ability_enemy_physical(150, None),
+        skill1: ability_enemy_physical(
+            185,
+            Some(StatusApplication {
+                effect: StatusEffect::Bleed,
+                base_duration: 3,
+                stacks: 2,
+                resist_modifier: -10,
+            }),
+        ),
+        skill2: ability_enemy_physical(215, None).with_area(AreaOfEffect::Adjacent, 70),
+    },
+    // Bone Colossus: grave frost
+    EnemyAbilitySet {
+        basic: ability_enemy_magical(140, None),
+        skill1: ability_enemy_magical(
+            170,
+            Some(StatusApplication {
+                effect: StatusEffect::Chill,
+                base_duration: 3,
+                stacks: 2,
+                resist_modifier: -10,
+            }),
+        ),
+        skill2: ability_enemy_magical(
+            190,
+            Some(StatusApplication {
+                effect: StatusEffect::Poison,
+                base_duration: 3,
+                stacks: 2,
+                resist_modifier: -5,
+            }),
+        )
+        .with_area(AreaOfEffect::All, 50),
     },
 ];
 
+const ENEMY_KIND_SKELETON_SWORD: u8 = 6;
+const ENEMY_KIND_SLIME: u8 = 8;
+
+// Enemy support: heals are only considered once an ally is missing this share of HP.
+const ENEMY_HEAL_THRESHOLD_PERCENT: u32 = 30;
+
+// Bosses enrage at or below this share of max HP.
+const BOSS_ENRAGE_HP_PERCENT: u16 = 50;
+const BOSS_ENRAGE_ATTACK_PERCENT: u16 = 20;
+
 const fn ability_enemy_physical(
     power_percent: u16,
     status: Option<StatusApplication>,
@@ -363,6 +746,75 @@ const fn ability_enemy_physical(
         power_percent,
         status,
         target: Targeting::Enemy,
+        usable_ranks: RANKS_ALL,
+        target_ranks: RANKS_FRONT,
+        knockback: 0,
+        area: AreaOfEffect::Single,
+        falloff_percent: 100,
+        threat: 0,
+        stress: 0,
+        summon: None,
+    }
+}
+
+const fn ability_enemy_magical(
+    power_percent: u16,
+    status: Option<StatusApplication>,
+) -> AbilitySpec {
+    AbilitySpec {
+        cost: 1,
+        kind: HeroActionKind::Attack,
+        damage_type: DamageType::Magical,
+        power_percent,
+        status,
+        target: Targeting::Enemy,
+        usable_ranks: RANKS_ALL,
+        target_ranks: RANKS_ALL,
+        knockback: 0,
+        area: AreaOfEffect::Single,
+        falloff_percent: 100,
+        threat: 0,
+        stress: 0,
+        summon: None,
+    }
+}
+
+/// Heal the most wounded ally (the user included) for a share of the user's magic.
+const fn ability_enemy_heal(power_percent: u16) -> AbilitySpec {
+    AbilitySpec {
+        damage_type: DamageType::Heal,
+        target: Targeting::Ally,
+        target_ranks: RANKS_ALL,
+        ..ability_enemy_magical(power_percent, None)
+    }
+}
+
+/// Guard the front-most unguarded ally. `power_percent` only weighs the choice against
+/// attacks.
+const fn ability_enemy_guard(duration: u8) -> AbilitySpec {
+    AbilitySpec {
+        damage_type: DamageType::Support,
+        target: Targeting::Ally,
+        ..ability_enemy_magical(
+            140,
+            Some(StatusApplication {
+                effect: StatusEffect::Guard,
+                base_duration: duration,
+                stacks: 1,
+                resist_modifier: 0,
+            }),
+        )
+    }
+}
+
+/// Call an enemy of `kind` into an empty slot. `power_percent` only weighs the choice
+/// against attacks.
+const fn ability_enemy_summon(kind: u8) -> AbilitySpec {
+    AbilitySpec {
+        damage_type: DamageType::Support,
+        target: Targeting::None,
+        summon: Some(kind),
+        ..ability_enemy_magical(150, None)
     }
 }
 
@@ -386,59 +838,109 @@ pub fn begin_encounter(ctx: Context<BeginEncounter>) -> Result<()> {
         AdventureError::NoPendingEncounter
     );
     require!(
-        adventure.combat_account == Pubkey::default(),
+        adventure.combat_account == Pubkey::default() && !combat.active,
         AdventureError::CombatAlreadyActive
     );
 
     let hero_count = adventure.hero_count.min(MAX_PARTY as u8);
     require!(hero_count > 0, AdventureError::InvalidHeroCount);
 
-    combat.adventure = adventure.key();
-    combat.bump = ctx.bumps.combat;
+    // The account is reused across fights, so every per-fight field is overwritten below
     combat.active = true;
     combat.round = 1;
     combat.turn_cursor = 0;
     combat.torch = adventure.torch;
     combat.hero_count = hero_count;
 
-    let (enemies, enemy_count, next_state) =
-        select_enemy_party(adventure.pending_encounter_seed, adventure.torch);
+    let encounter_kind = adventure.pending_encounter_kind;
+    let (enemies, enemy_count, next_state) = match encounter_kind {
+        EncounterKind::Boss => select_boss_party(adventure.pending_encounter_seed, adventure.torch),
+        EncounterKind::Standard | EncounterKind::Ambush => {
+            select_enemy_party(adventure.pending_encounter_seed, adventure.torch)
+        }
+    };
+    combat.encounter_kind = encounter_kind;
     combat.enemies = enemies;
     combat.enemy_count = enemy_count;
     combat.rng_state = next_state;
     combat.loot_seed = adventure.pending_encounter_seed;
     combat.pending_resolution = CombatResolutionState::Active;
     combat.last_updated = Clock::get()?.unix_timestamp;
+    combat.log = [CombatLogEntry::default(); COMBAT_LOG_CAPACITY];
+    combat.log_cursor = 0;
 
     // Populate hero combatants
     for idx in 0..MAX_PARTY {
         if (idx as u8) < hero_count {
             let snapshot = adventure.hero_snapshots[idx];
             combat.heroes[idx] = convert_hero_snapshot(&snapshot, idx, adventure.torch);
+
+            // Carry over statuses picked up while exploring (e.g. trap poison)
+            let lingering = adventure.lingering_statuses[idx];
+            if !lingering.is_empty() && combat.heroes[idx].alive {
+                combat.heroes[idx].statuses[0] = lingering;
+            }
+            adventure.lingering_statuses[idx].clear();
+
+            // Afflictions and virtues last the whole run; stress that hit the cap while
+            // exploring is checked as the fight begins
+            let mut hero = combat.heroes[idx];
+            hero.resolve = adventure.hero_resolve[idx];
+            if hero.resolve == VIRTUE_STALWART {
+                apply_stalwart(&mut hero);
+            } else if hero.resolve == RESOLVE_NONE
+                && hero.alive
+                && hero.stress_max > 0
+                && hero.stress >= hero.stress_max
+            {
+                resolve_check(combat, &mut hero);
+            }
+            combat.heroes[idx] = hero;
         } else {
             combat.heroes[idx] = HeroCombatant::default();
         }
     }
 
+    // A shrine blessing empowers the party for a limited number of fights
+    if adventure.blessing_combats > 0 {
+        for hero in combat.heroes.iter_mut().take(hero_count as usize) {
+            hero.attack = hero
+                .attack
+                .saturating_add(hero.attack * SHRINE_BLESSING_PERCENT / 100);
+            hero.magic = hero
+                .magic
+                .saturating_add(hero.magic * SHRINE_BLESSING_PERCENT / 100);
+        }
+        adventure.blessing_combats -= 1;
+    }
+
     // Ensure unused enemy slots are cleared
     for idx in enemy_count as usize..MAX_ENEMIES {
         combat.enemies[idx] = EnemyCombatant::default();
     }
+    compact_ranks(combat);
 
     let hero_snapshot = combat.heroes;
     let enemy_snapshot = combat.enemies;
     let hero_count = combat.hero_count;
     let enemy_count = combat.enemy_count;
+    let enemy_initiative_bonus = if encounter_kind == EncounterKind::Ambush {
+        AMBUSH_INITIATIVE_BONUS
+    } else {
+        0
+    };
     combat.initiative_len = build_initiative_order(
         &mut combat.initiative,
         &hero_snapshot,
         &enemy_snapshot,
         hero_count,
         enemy_count,
+        enemy_initiative_bonus,
     );
 
     adventure.combat_account = combat.key();
     adventure.pending_encounter_seed = 0;
+    adventure.pending_encounter_kind = EncounterKind::Standard;
     adventure.in_combat = true;
 
     Ok(())
@@ -450,6 +952,7 @@ pub fn submit_combat_action(
 ) -> Result<()> {
     let adventure = &mut ctx.accounts.adventure;
     let combat = &mut ctx.accounts.combat;
+    let registry = &ctx.accounts.item_registry;
     let owner = ctx.accounts.owner.key();
     let authority = ctx.accounts.authority.key();
 
@@ -528,9 +1031,25 @@ pub fn submit_combat_action(
         }
     }
 
-    execute_hero_action(adventure, combat, instruction, start.chill_stacks)?;
-    if check_victory(combat) {
-        return Ok(());
+    let acts = if start.stunned {
+        msg!("Hero {} is stunned and loses the turn", hero_index);
+        log_self_event(
+            combat,
+            CombatantKind::Hero,
+            hero_index as u8,
+            CombatLogAction::Stunned,
+        );
+        false
+    } else {
+        apply_resolve_turn(combat, hero_index)
+    };
+    if !acts {
+        end_hero_turn(&mut combat.heroes[hero_index]);
+    } else {
+        execute_hero_action(adventure, combat, registry, instruction, start.chill_stacks)?;
+        if check_victory(combat) {
+            return Ok(());
+        }
     }
 
     advance_turn_pointer(combat, turn_slot);
@@ -539,7 +1058,7 @@ pub fn submit_combat_action(
         if check_defeat(combat) || check_victory(combat) {
             break;
         }
-        let Some((slot_idx, kind, idx)) = current_actor(combat) else {
+        let Some((_, kind, idx)) = current_actor(combat) else {
             break;
         };
         if kind == CombatantKind::Hero {
@@ -547,28 +1066,34 @@ pub fn submit_combat_action(
         }
         execute_enemy_auto_turn(adventure, combat, idx as usize)?;
         if matches!(combat.pending_resolution, CombatResolutionState::Active) {
+            // A summon may have shifted the initiative order; the cursor still marks this actor
+            let slot_idx = combat.turn_cursor as usize;
             advance_turn_pointer(combat, slot_idx);
         } else {
             break;
         }
     }
 
+    compact_ranks(combat);
+
     Ok(())
 }
 
-// XP rewards per enemy type (11 enemy types)
-const ENEMY_XP_TABLE: [u32; 11] = [
-    50, // Armored Orc
-    45, // Armored Skeleton
-    60, // Elite Orc
-    55, // Greatsword Skeleton
-    35, // Orc
-    50, // Orc Rider
-    30, // Skeleton (Sword)
-    28, // Skeleton (Bow)
-    25, // Slime
-    70, // Werebear
-    65, // Werewolf
+// XP rewards per enemy type (11 regular enemy types, then bosses)
+const ENEMY_XP_TABLE: [u32; 13] = [
+    50,  // Armored Orc
+    45,  // Armored Skeleton
+    60,  // Elite Orc
+    55,  // Greatsword Skeleton
+    35,  // Orc
+    50,  // Orc Rider
+    30,  // Skeleton (Sword)
+    28,  // Skeleton (Bow)
+    25,  // Slime
+    70,  // Werebear
+    65,  // Werewolf
+    220, // Orc Warchief
+    200, // Bone Colossus
 ];
 
 // Loot table: (item_key, base_chance_bps, quantity)
@@ -582,6 +1107,13 @@ const LOOT_TABLE: [(u8, u16, u16); 7] = [
     (6, 100, 1),   // PhoenixFeather - 1% chance
 ];
 
+// Guaranteed boss drop: (item_key, quantity)
+const BOSS_LOOT: [(u8, u16); 3] = [
+    (6, 1),   // PhoenixFeather
+    (4, 1),   // MysteryRelic
+    (0, 150), // PouchGold
+];
+
 fn calculate_enemy_xp(enemy_kind: u8) -> u32 {
     ENEMY_XP_TABLE
         .get(enemy_kind as usize)
@@ -688,11 +1220,16 @@ pub fn conclude_combat(ctx: Context<ConcludeCombat>) -> Result<()> {
     // Update hero snapshots with HP, stress, XP, and traits
     for idx in 0..hero_count {
         let combatant = combat.heroes[idx];
+        adventure.hero_resolve[idx] = combatant.resolve;
         let snapshot = &mut adventure.hero_snapshots[idx];
 
-        // Update HP
+        // Update HP; heroes who end the fight on Death's Door walk away with 1 HP
         let clamped_hp = combatant.hp.min(combatant.max_hp).min(u8::MAX as u16) as u8;
-        snapshot.current_hp = if combatant.alive { clamped_hp } else { 0 };
+        snapshot.current_hp = if combatant.alive {
+            clamped_hp.max(1)
+        } else {
+            0
+        };
 
         // Update stress
         snapshot.stress = combatant.stress.min(snapshot.stress_max);
@@ -709,8 +1246,14 @@ pub fn conclude_combat(ctx: Context<ConcludeCombat>) -> Result<()> {
             apply_trait_to_hero(snapshot, 1, true);
         }
 
+        // Surviving death blows may leave a scar
+        let scarred = combatant.alive
+            && combatant.death_blows_survived > 0
+            && rand_percent(combat)
+                < DEATH_BLOW_TRAIT_CHANCE_BPS.saturating_mul(combatant.death_blows_survived as u16);
+
         // Apply pending negative traits
-        if combatant.pending_negative_traits > 0 {
+        if combatant.pending_negative_traits > 0 || scarred {
             // Fearful trait (example - trait value 1)
             apply_trait_to_hero(snapshot, 1, false);
         }
@@ -718,6 +1261,15 @@ pub fn conclude_combat(ctx: Context<ConcludeCombat>) -> Result<()> {
 
     // Generate loot on victory
     if is_victory {
+        // Boss loot goes in first so it can't be crowded out of a full loot bag
+        if combat.encounter_kind == EncounterKind::Boss {
+            for (item_key, quantity) in BOSS_LOOT.iter() {
+                add_pending_loot(adventure, combat, *item_key, *quantity);
+            }
+            adventure.boss_cleared = true;
+            msg!("Boss defeated");
+        }
+
         for (item_key, chance_bps, quantity) in LOOT_TABLE.iter() {
             let roll = rand_percent(combat);
             if roll < *chance_bps {
@@ -732,18 +1284,29 @@ pub fn conclude_combat(ctx: Context<ConcludeCombat>) -> Result<()> {
                 add_pending_loot(adventure, combat, *item_key, *quantity);
             }
         }
+
+        // Elites may carry a door key
+        for enemy_idx in 0..combat.enemy_count.min(MAX_ENEMIES as u8) as usize {
+            let enemy = combat.enemies[enemy_idx];
+            if enemy.alive || !ELITE_ENEMY_KINDS.contains(&enemy.kind) {
+                continue;
+            }
+            if rand_percent(combat) < ELITE_KEY_CHANCE_BPS && adventure.keys < MAX_KEYS {
+                adventure.keys += 1;
+                msg!("Elite enemy {} dropped a key", enemy_idx);
+            }
+        }
     }
 
     // Reset combat status on adventure
     adventure.in_combat = false;
     adventure.combat_account = Pubkey::default();
     adventure.pending_encounter_seed = 0;
+    adventure.pending_encounter_kind = EncounterKind::Standard;
 
+    // Keep the account allocated (and delegated) for the next encounter
     combat.active = false;
 
-    // Close combat account back to adventure owner
-    combat.close(ctx.accounts.owner.to_account_info())?;
-
     Ok(())
 }
 
@@ -764,6 +1327,10 @@ pub fn decline_encounter(ctx: Context<DeclineEncounter>) -> Result<()> {
         adventure.pending_encounter_seed != 0,
         AdventureError::NoPendingEncounter
     );
+    require!(
+        !adventure.pending_encounter_kind.is_forced(),
+        AdventureError::ForcedEncounterPending
+    );
 
     // Clear the pending encounter - player declined to fight
     adventure.pending_encounter_seed = 0;
@@ -777,12 +1344,12 @@ fn build_initiative_order(
     enemies: &[EnemyCombatant; MAX_ENEMIES],
     hero_count: u8,
     enemy_count: u8,
+    enemy_initiative_bonus: i16,
 ) -> u8 {
     let mut temp = [InitiativeSlot::default(); MAX_COMBATANTS];
     let mut len: usize = 0;
 
-    for idx in 0..hero_count as usize {
-        let hero = heroes[idx];
+    for (idx, hero) in heroes.iter().enumerate().take(hero_count as usize) {
         if !hero.alive {
             continue;
         }
@@ -796,15 +1363,14 @@ fn build_initiative_order(
         len += 1;
     }
 
-    for idx in 0..enemy_count as usize {
-        let enemy = enemies[idx];
+    for (idx, enemy) in enemies.iter().enumerate().take(enemy_count as usize) {
         if !enemy.alive {
             continue;
         }
         temp[len] = InitiativeSlot {
             occupant_kind: CombatantKind::Enemy,
             index: idx as u8,
-            initiative_value: enemy.speed as i16,
+            initiative_value: (enemy.speed as i16).saturating_add(enemy_initiative_bonus),
             order: len as u8,
             active: true,
         };
@@ -833,6 +1399,57 @@ fn build_initiative_order(
     len as u8
 }
 
+/// Add a combatant that joined mid-fight to the initiative order, sorted in by initiative.
+/// A stale slot left by a fallen combatant with the same index is dropped first, and the turn
+/// cursor is shifted so it keeps pointing at the current actor.
+fn insert_initiative_slot(
+    combat: &mut AdventureCombat,
+    kind: CombatantKind,
+    index: u8,
+    initiative_value: i16,
+) {
+    let mut len = combat.initiative_len as usize;
+    let mut cursor = combat.turn_cursor as usize;
+
+    if let Some(stale) = combat.initiative[..len]
+        .iter()
+        .position(|slot| slot.occupant_kind == kind && slot.index == index)
+    {
+        combat.initiative.copy_within(stale + 1..len, stale);
+        len -= 1;
+        combat.initiative[len] = InitiativeSlot::default();
+        if stale < cursor {
+            cursor -= 1;
+        }
+    }
+    if len >= MAX_COMBATANTS {
+        return;
+    }
+
+    let position = combat.initiative[..len]
+        .iter()
+        .position(|slot| slot.initiative_value < initiative_value)
+        .unwrap_or(len);
+    combat.initiative.copy_within(position..len, position + 1);
+    combat.initiative[position] = InitiativeSlot {
+        occupant_kind: kind,
+        index,
+        initiative_value,
+        order: position as u8,
+        active: true,
+    };
+    len += 1;
+    if position <= cursor && len > 1 {
+        cursor += 1;
+    }
+
+    for (order, slot) in combat.initiative.iter_mut().enumerate().take(len) {
+        slot.order = order as u8;
+    }
+    combat.initiative_len = len as u8;
+    combat.turn_cursor = cursor as u8;
+}
+
 fn current_actor(combat: &mut AdventureCombat) -> Option<(usize, CombatantKind, u8)> {
     let len = combat.initiative_len as usize;
     if len == 0 {
@@ -912,6 +1529,7 @@ fn advance_turn_pointer(combat: &mut AdventureCombat, current_slot: usize) {
 fn execute_hero_action(
     adventure: &mut AdventureSession,
     combat: &mut AdventureCombat,
+    registry: &ItemRegistry,
     instruction: CombatInstruction,
     _chill_stacks: u8,
 ) -> Result<()> {
@@ -921,6 +1539,7 @@ fn execute_hero_action(
         AdventureError::HeroIndexOutOfRange
     );
 
+    compact_ranks(combat);
     let mut hero = combat.heroes[hero_index];
     require!(hero.alive, AdventureError::HeroNotAlive);
 
@@ -941,6 +1560,14 @@ fn execute_hero_action(
                 resist_modifier: 0,
             }),
             target: Targeting::SelfOnly,
+            usable_ranks: RANKS_ALL,
+            target_ranks: RANKS_ALL,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 12,
+            stress: 0,
+            summon: None,
         },
         HeroActionKind::UseItem => AbilitySpec {
             cost: 1,
@@ -949,6 +1576,30 @@ fn execute_hero_action(
             power_percent: 0,
             status: None,
             target: Targeting::Ally,
+            usable_ranks: RANKS_ALL,
+            target_ranks: RANKS_ALL,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
+        },
+        HeroActionKind::Reposition => AbilitySpec {
+            cost: 1,
+            kind: HeroActionKind::Reposition,
+            damage_type: DamageType::Support,
+            power_percent: 0,
+            status: None,
+            target: Targeting::Ally,
+            usable_ranks: RANKS_ALL,
+            target_ranks: RANKS_ALL,
+            knockback: 0,
+            area: AreaOfEffect::Single,
+            falloff_percent: 100,
+            threat: 0,
+            stress: 0,
+            summon: None,
         },
     };
 
@@ -956,11 +1607,15 @@ fn execute_hero_action(
         hero.ap >= ability.cost,
         AdventureError::InsufficientActionPoints
     );
+    require!(
+        rank_in(hero.rank, ability.usable_ranks),
+        AdventureError::AbilityNotUsableFromRank
+    );
 
     match instruction.action {
         HeroActionKind::Attack | HeroActionKind::Skill1 | HeroActionKind::Skill2 => {
             match ability.damage_type {
-                DamageType::Heal => {
+                DamageType::Heal | DamageType::Support => {
                     let target_index = match instruction.target {
                         TargetSelector::Ally(ix) => ix as usize,
                         TargetSelector::None => hero_index,
@@ -970,14 +1625,18 @@ fn execute_hero_action(
                         target_index < combat.hero_count as usize,
                         AdventureError::HeroIndexOutOfRange
                     );
-                    let heal_variance = rand_range(combat, 8, 18);
-                    let target = combat
-                        .heroes
-                        .get_mut(target_index)
-                        .ok_or(AdventureError::InvalidTarget)?;
-                    let heal_amount = (hero.magic as u16).saturating_mul(160) / 100 + heal_variance;
-                    target.hp = target.hp.saturating_add(heal_amount).min(target.max_hp);
-                    target.alive = target.hp > 0;
+                    let entries = combat.heroes.map(|ally| (ally.alive, ally.rank));
+                    let (splash, splash_count) = area_targets(
+                        entries,
+                        combat.hero_count as usize,
+                        target_index,
+                        ability.area,
+                    );
+                    support_ally(combat, &mut hero, hero_index, target_index, ability);
+                    let splash_ability = ability.splashed();
+                    for ally_index in splash.iter().take(splash_count) {
+                        support_ally(combat, &mut hero, hero_index, *ally_index, splash_ability);
+                    }
                     hero.kill_streak = 0;
                 }
                 _ => {
@@ -993,14 +1652,29 @@ fn execute_hero_action(
                         target_idx = usize::MAX;
                     }
                     if target_idx == usize::MAX || !combat.enemies[target_idx].alive {
-                        if let Some(alive_idx) = first_alive_enemy_index(combat) {
-                            target_idx = alive_idx;
+                        if first_alive_enemy_index(combat).is_some() {
+                            target_idx = first_enemy_in_ranks(combat, ability.target_ranks)
+                                .ok_or(AdventureError::TargetOutOfReach)?;
                         } else {
                             combat.pending_resolution = CombatResolutionState::Victory;
                             combat.active = false;
                             return Ok(());
                         }
                     }
+                    require!(
+                        rank_in(combat.enemies[target_idx].rank, ability.target_ranks),
+                        AdventureError::TargetOutOfReach
+                    );
+                    if hero.resolve == AFFLICTION_FRENZIED
+                        && rand_percent(combat) < FRENZIED_LASH_OUT_BPS
+                    {
+                        let roll = rand_u32(combat);
+                        if let Some(idx) = random_enemy_in_ranks(combat, ability.target_ranks, roll)
+                        {
+                            msg!("Hero {} lashes out at enemy {}", hero_index, idx);
+                            target_idx = idx;
+                        }
+                    }
 
                     msg!(
                         "Targeting enemy: requested_idx={:?}, resolved_idx={}, enemy_count={}",
@@ -1039,73 +1713,89 @@ fn execute_hero_action(
                             return Ok(());
                         }
                     }
-
-                    let (damage, _) = compute_damage(
-                        combat,
-                        ability,
-                        hero.attack,
-                        hero.magic,
-                        hero.luck,
-                        enemy_snapshot.defense,
-                        enemy_snapshot.resistance,
-                        false,
+                    let primary = hero_strike_enemy(combat, &mut hero, ability, target_idx);
+                    let entries = combat.enemies.map(|enemy| (enemy.alive, enemy.rank));
+                    let (splash, splash_count) = area_targets(
+                        entries,
+                        combat.enemy_count as usize,
+                        target_idx,
+                        ability.area,
                     );
-
-                    if damage > 0 {
-                        enemy_snapshot.hp = enemy_snapshot.hp.saturating_sub(damage);
-                    }
-
-                    let enemy_killed = enemy_snapshot.hp == 0;
-                    if enemy_killed {
-                        enemy_snapshot.alive = false;
-                        hero.kill_streak = hero.kill_streak.saturating_add(1);
-
-                        // Chance for positive trait on kill streak of 2+
-                        if hero.kill_streak >= 2 && rand_percent(combat) < 3000 {
-                            // 30% chance
-                            hero.pending_positive_traits =
-                                hero.pending_positive_traits.saturating_add(1);
-                        }
-                    } else if damage > 0 {
-                        hero.kill_streak = 0;
-                    }
-
-                    if let Some(status) = ability.status {
-                        let mut guard = false;
-                        apply_status_application(
-                            combat,
-                            &mut enemy_snapshot.statuses,
-                            &mut guard,
-                            status,
-                            enemy_snapshot.resistance,
-                            hero.luck,
-                        );
+                    let splash_ability = ability.splashed();
+                    for enemy_index in splash.iter().take(splash_count) {
+                        hero_strike_enemy(combat, &mut hero, splash_ability, *enemy_index);
                     }
 
-                    combat.enemies[target_idx] = enemy_snapshot;
-                    if enemy_killed {
-                        mark_enemy_dead(combat, target_idx as u8);
+                    if primary == StrikeOutcome::Hit && ability.knockback > 0 {
+                        knock_back_enemy(combat, target_idx, ability.knockback);
                     }
                 }
             }
         }
         HeroActionKind::Defend => {
+            let mut entry = log_entry(
+                CombatantKind::Hero,
+                hero_index as u8,
+                CombatLogAction::Defend,
+                CombatantKind::Hero,
+                hero_index as u8,
+            );
             if let Some(status) = ability.status {
-                apply_status_application(
+                if apply_status_application(
                     combat,
                     &mut hero.statuses,
                     &mut hero.guard,
                     status,
                     0,
                     hero.luck,
-                );
+                ) {
+                    entry.status = status.effect;
+                }
             }
+            log_event(combat, entry);
         }
         HeroActionKind::UseItem => {
             let item_key = instruction.item_key.ok_or(AdventureError::InvalidItemKey)?;
-            apply_item_to_ally(adventure, combat, &mut hero, item_key, instruction.target)?;
+            apply_item_to_ally(
+                adventure,
+                combat,
+                registry,
+                &mut hero,
+                item_key,
+                instruction.target,
+            )?;
             hero.kill_streak = 0;
         }
+        HeroActionKind::Reposition => {
+            // Swap places with a living ally
+            let TargetSelector::Ally(ix) = instruction.target else {
+                return Err(error!(AdventureError::InvalidTarget));
+            };
+            let ally_index = ix as usize;
+            require!(
+                ally_index < combat.hero_count as usize
+                    && ally_index != hero_index
+                    && combat.heroes[ally_index].alive,
+                AdventureError::InvalidTarget
+            );
+            std::mem::swap(&mut combat.heroes[ally_index].rank, &mut hero.rank);
+            let entry = log_entry(
+                CombatantKind::Hero,
+                hero_index as u8,
+                CombatLogAction::Reposition,
+                CombatantKind::Hero,
+                ally_index as u8,
+            );
+            log_event(combat, entry);
+        }
+    }
+
+    if ability.threat > 0 {
+        for enemy in combat.enemies.iter_mut().take(combat.enemy_count as usize) {
+            if enemy.alive {
+                add_threat(enemy, hero.hero_index, ability.threat as u16);
+            }
+        }
     }
 
     hero.ap = hero.ap.saturating_sub(ability.cost);
@@ -1115,15 +1805,196 @@ fn execute_hero_action(
     Ok(())
 }
 
-fn execute_enemy_auto_turn(
-    _adventure: &mut AdventureSession,
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StrikeOutcome {
+    Missed,
+    Hit,
+    Killed,
+}
+
+/// Resolve one hit of a hero ability on an enemy.
+fn hero_strike_enemy(
     combat: &mut AdventureCombat,
-    enemy_index: usize,
-) -> Result<()> {
-    if enemy_index >= combat.enemy_count as usize {
-        return Ok(());
+    hero: &mut HeroCombatant,
+    ability: AbilitySpec,
+    target_idx: usize,
+) -> StrikeOutcome {
+    let mut enemy_snapshot = combat.enemies[target_idx];
+    let (damage, crit) = compute_damage(
+        combat,
+        ability,
+        hero.attack,
+        hero.magic,
+        hero.luck,
+        total_stacks(&hero.statuses, StatusEffect::Blind),
+        Defender::new(
+            enemy_snapshot.defense,
+            enemy_snapshot.resistance,
+            total_stacks(&enemy_snapshot.statuses, StatusEffect::Guard) > 0,
+            &enemy_snapshot.statuses,
+        ),
+    );
+    let mut entry = log_entry(
+        CombatantKind::Hero,
+        hero.hero_index,
+        log_action(ability.kind),
+        CombatantKind::Enemy,
+        target_idx as u8,
+    );
+    entry.amount = damage;
+    if damage == 0 {
+        msg!("Hero {} misses", hero.hero_index);
+        entry.flags |= COMBAT_LOG_MISSED;
+        log_event(combat, entry);
+        return StrikeOutcome::Missed;
+    }
+    if crit {
+        entry.flags |= COMBAT_LOG_CRIT;
+    }
+
+    if damage > 0 {
+        enemy_snapshot.hp = enemy_snapshot.hp.saturating_sub(damage);
+        add_threat(
+            &mut enemy_snapshot,
+            hero.hero_index,
+            damage / DAMAGE_PER_THREAT,
+        );
+    }
+
+    let enemy_killed = enemy_snapshot.hp == 0;
+    if enemy_killed {
+        enemy_snapshot.alive = false;
+        hero.kill_streak = hero.kill_streak.saturating_add(1);
+
+        // Chance for positive trait on kill streak of 2+
+        if hero.kill_streak >= 2 && rand_percent(combat) < 3000 {
+            // 30% chance
+            hero.pending_positive_traits = hero.pending_positive_traits.saturating_add(1);
+        }
+    } else if damage > 0 && ability.area == AreaOfEffect::Single {
+        hero.kill_streak = 0;
+    }
+
+    if let Some(status) = ability.status {
+        let mut guard = false;
+        if apply_status_application(
+            combat,
+            &mut enemy_snapshot.statuses,
+            &mut guard,
+            status,
+            enemy_snapshot.resistance,
+            hero.luck,
+        ) {
+            entry.status = status.effect;
+        }
+    }
+
+    if enemy_killed {
+        entry.flags |= COMBAT_LOG_KILLED;
+    }
+    log_event(combat, entry);
+
+    combat.enemies[target_idx] = enemy_snapshot;
+    if enemy_killed {
+        mark_enemy_dead(combat, target_idx as u8);
+        StrikeOutcome::Killed
+    } else {
+        StrikeOutcome::Hit
+    }
+}
+
+/// Heal or buff one ally. The acting hero is held outside `combat` while it acts, so a
+/// self-target goes through `hero`.
+fn support_ally(
+    combat: &mut AdventureCombat,
+    hero: &mut HeroCombatant,
+    hero_index: usize,
+    target_index: usize,
+    ability: AbilitySpec,
+) {
+    let mut target = if target_index == hero_index {
+        *hero
+    } else {
+        combat.heroes[target_index]
+    };
+    // Fallen heroes only come back through a revive
+    if !target.alive {
+        return;
+    }
+
+    let mut entry = log_entry(
+        CombatantKind::Hero,
+        hero_index as u8,
+        log_action(ability.kind),
+        CombatantKind::Hero,
+        target_index as u8,
+    );
+    if ability.damage_type == DamageType::Heal {
+        let heal_variance = rand_range(combat, 8, 18);
+        let heal_amount = hero.magic.saturating_mul(ability.power_percent) / 100 + heal_variance;
+        let before = target.hp;
+        target.hp = target.hp.saturating_add(heal_amount).min(target.max_hp);
+        entry.amount = target.hp - before;
+    }
+    if let Some(status) = ability.status {
+        if apply_status_application(
+            combat,
+            &mut target.statuses,
+            &mut target.guard,
+            status,
+            0,
+            hero.luck,
+        ) {
+            entry.status = status.effect;
+        }
+    }
+    log_event(combat, entry);
+
+    if target_index == hero_index {
+        *hero = target;
+    } else {
+        combat.heroes[target_index] = target;
+    }
+}
+
+/// Secondary targets of an area ability: living combatants on the primary target's side,
+/// excluding the primary target itself.
+fn area_targets<const N: usize>(
+    entries: [(bool, u8); N],
+    count: usize,
+    primary: usize,
+    area: AreaOfEffect,
+) -> ([usize; N], usize) {
+    let mut targets = [0usize; N];
+    let mut len = 0;
+    let primary_rank = entries[primary].1;
+    for (idx, (alive, rank)) in entries.iter().enumerate().take(count) {
+        if idx == primary || !*alive {
+            continue;
+        }
+        let reached = match area {
+            AreaOfEffect::Single => false,
+            AreaOfEffect::Adjacent => rank.abs_diff(primary_rank) == 1,
+            AreaOfEffect::All => true,
+        };
+        if reached {
+            targets[len] = idx;
+            len += 1;
+        }
     }
+    (targets, len)
+}
 
+fn execute_enemy_auto_turn(
+    _adventure: &mut AdventureSession,
+    combat: &mut AdventureCombat,
+    enemy_index: usize,
+) -> Result<()> {
+    if enemy_index >= combat.enemy_count as usize {
+        return Ok(());
+    }
+
+    compact_ranks(combat);
     let start = start_enemy_turn(combat, enemy_index);
     let mut enemy_state = combat.enemies[enemy_index];
     if !enemy_state.alive || !start.alive {
@@ -1137,32 +2008,115 @@ fn execute_enemy_auto_turn(
         enemy_state.ap = enemy_state.ap.saturating_add(1).min(ENEMY_AP_MAX);
     }
 
+    if start.stunned {
+        msg!("Enemy {} is stunned and loses the turn", enemy_index);
+        log_self_event(
+            combat,
+            CombatantKind::Enemy,
+            enemy_index as u8,
+            CombatLogAction::Stunned,
+        );
+        combat.enemies[enemy_index] = enemy_state;
+        end_enemy_turn(&mut combat.enemies[enemy_index]);
+        return Ok(());
+    }
+
+    maybe_enrage_boss(&mut enemy_state, enemy_index);
+
+    let profile = enemy_ai_profile(enemy_state.kind);
     let ability_roll = rand_u32(combat);
-    let mut ability = enemy_skill(enemy_state.kind, ability_roll);
-    if ability.cost > enemy_state.ap {
-        ability = ENEMY_ABILITY_SETS[enemy_state.kind as usize % ENEMY_ABILITY_SETS.len()].basic;
+    let ability = choose_enemy_ability(combat, enemy_index, &enemy_state, &profile, ability_roll);
+
+    if ability.target != Targeting::Enemy {
+        match ability.summon {
+            Some(kind) => summon_enemy(combat, enemy_index, ability, kind),
+            None => enemy_support_ally(combat, &mut enemy_state, enemy_index, ability),
+        }
+        enemy_state.ap = enemy_state.ap.saturating_sub(ability.cost);
+        combat.enemies[enemy_index] = enemy_state;
+        end_enemy_turn(&mut combat.enemies[enemy_index]);
+        return Ok(());
     }
 
-    let target_index = select_hero_target(combat, ability_roll)?;
-    let mut hero_state = combat.heroes[target_index];
-    if !hero_state.alive {
+    let target_roll = rand_u32(combat);
+    let target_index = select_hero_target(combat, &enemy_state, &profile, ability, target_roll)?;
+    if !combat.heroes[target_index].alive {
         mark_hero_dead(combat, target_index as u8);
         combat.enemies[enemy_index] = enemy_state;
         end_enemy_turn(&mut combat.enemies[enemy_index]);
         return Ok(());
     }
 
-    let hero_guard = hero_state.guard;
-    let (damage, _) = compute_damage(
+    let primary = enemy_strike_hero(combat, &enemy_state, enemy_index, ability, target_index);
+    let entries = combat.heroes.map(|hero| (hero.alive, hero.rank));
+    let (splash, splash_count) = area_targets(
+        entries,
+        combat.hero_count as usize,
+        target_index,
+        ability.area,
+    );
+    let splash_ability = ability.splashed();
+    for hero_index in splash.iter().take(splash_count) {
+        enemy_strike_hero(
+            combat,
+            &enemy_state,
+            enemy_index,
+            splash_ability,
+            *hero_index,
+        );
+    }
+    if primary == StrikeOutcome::Hit && ability.knockback > 0 {
+        knock_back_hero(combat, target_index, ability.knockback);
+    }
+    enemy_state.ap = enemy_state.ap.saturating_sub(ability.cost);
+    combat.enemies[enemy_index] = enemy_state;
+    end_enemy_turn(&mut combat.enemies[enemy_index]);
+
+    Ok(())
+}
+
+/// Resolve one hit of an enemy ability on a hero.
+fn enemy_strike_hero(
+    combat: &mut AdventureCombat,
+    enemy_state: &EnemyCombatant,
+    enemy_index: usize,
+    ability: AbilitySpec,
+    target_index: usize,
+) -> StrikeOutcome {
+    let mut hero_state = combat.heroes[target_index];
+    let (damage, crit) = compute_damage(
         combat,
         ability,
         enemy_state.attack,
         enemy_state.magic,
         enemy_state.luck,
-        hero_state.defense,
-        hero_state.resistance,
-        hero_guard,
+        total_stacks(&enemy_state.statuses, StatusEffect::Blind),
+        Defender::new(
+            hero_state.defense,
+            hero_state.resistance,
+            hero_state.guard,
+            &hero_state.statuses,
+        ),
     );
+    // Logged before the damage lands so a Death's Door entry follows the hit that caused it
+    let mut entry = log_entry(
+        CombatantKind::Enemy,
+        enemy_index as u8,
+        log_action(ability.kind),
+        CombatantKind::Hero,
+        target_index as u8,
+    );
+    entry.amount = damage;
+    if damage == 0 {
+        msg!("Enemy {} misses", enemy_state.kind);
+        entry.flags |= COMBAT_LOG_MISSED;
+        log_event(combat, entry);
+        return StrikeOutcome::Missed;
+    }
+    if crit {
+        entry.flags |= COMBAT_LOG_CRIT;
+    }
+    let log_slot = log_event(combat, entry);
 
     if damage > 0 {
         // Check for heavy damage (>40% max HP) before applying
@@ -1173,9 +2127,7 @@ fn execute_enemy_auto_turn(
                 hero_state.pending_negative_traits.saturating_add(1);
         }
 
-        hero_state.hp = hero_state.hp.saturating_sub(damage);
-        if hero_state.hp == 0 {
-            hero_state.alive = false;
+        if !take_hero_damage(combat, &mut hero_state, damage) {
             mark_hero_dead(combat, target_index as u8);
 
             // Other heroes witness ally death - chance for negative trait
@@ -1191,23 +2143,220 @@ fn execute_enemy_auto_turn(
         }
     }
 
+    if !hero_state.alive {
+        combat.log[log_slot].flags |= COMBAT_LOG_KILLED;
+    }
+
     if let Some(status) = ability.status {
-        apply_status_application(
+        if apply_status_application(
             combat,
             &mut hero_state.statuses,
             &mut hero_state.guard,
             status,
             hero_state.resistance,
             enemy_state.luck,
-        );
+        ) {
+            combat.log[log_slot].status = status.effect;
+        }
     }
 
+    let stress = ability.stress + if crit { CRIT_STRESS } else { 0 };
+    add_stress(combat, &mut hero_state, stress);
+
     combat.heroes[target_index] = hero_state;
-    enemy_state.ap = enemy_state.ap.saturating_sub(ability.cost);
-    combat.enemies[enemy_index] = enemy_state;
-    end_enemy_turn(&mut combat.enemies[enemy_index]);
+    if hero_state.alive {
+        StrikeOutcome::Hit
+    } else {
+        StrikeOutcome::Killed
+    }
+}
 
-    Ok(())
+/// Apply damage to a hero. A hero brought to 0 HP lands on Death's Door and keeps fighting;
+/// any further damage is a death blow that the hero may resist. Returns false once the hero
+/// has died.
+fn take_hero_damage(combat: &mut AdventureCombat, hero: &mut HeroCombatant, damage: u16) -> bool {
+    if damage == 0 || !hero.alive {
+        return hero.alive;
+    }
+
+    if hero.hp > 0 {
+        hero.hp = hero.hp.saturating_sub(damage);
+        if hero.hp == 0 {
+            msg!("Hero {} is at Death's Door", hero.hero_index);
+            log_self_event(
+                combat,
+                CombatantKind::Hero,
+                hero.hero_index,
+                CombatLogAction::DeathsDoor,
+            );
+            add_stress(combat, hero, DEATHS_DOOR_STRESS);
+        }
+        return true;
+    }
+
+    let resist_bps = (DEATH_BLOW_BASE_RESIST_BPS
+        + hero.resistance as u32 * DEATH_BLOW_RESIST_BPS_PER_POINT
+        + hero.positive_traits as u32 * DEATH_BLOW_TRAIT_BPS)
+        .min(DEATH_BLOW_MAX_RESIST_BPS);
+    if (rand_percent(combat) as u32) < resist_bps {
+        hero.death_blows_survived = hero.death_blows_survived.saturating_add(1);
+        msg!("Hero {} resists the death blow", hero.hero_index);
+        log_self_event(
+            combat,
+            CombatantKind::Hero,
+            hero.hero_index,
+            CombatLogAction::DeathBlowResisted,
+        );
+        add_stress(combat, hero, DEATH_BLOW_STRESS);
+        return true;
+    }
+
+    msg!("Hero {} succumbs to a death blow", hero.hero_index);
+    hero.alive = false;
+    false
+}
+
+/// Raise a hero's stress. Reaching the cap for the first time this run forces a resolve check.
+fn add_stress(combat: &mut AdventureCombat, hero: &mut HeroCombatant, amount: u16) {
+    if amount == 0 || !hero.alive {
+        return;
+    }
+    hero.stress = hero.stress.saturating_add(amount).min(hero.stress_max);
+    if hero.resolve == RESOLVE_NONE && hero.stress_max > 0 && hero.stress >= hero.stress_max {
+        resolve_check(combat, hero);
+    }
+}
+
+/// Roll a virtue or an affliction. Luck and positive traits improve the odds of a virtue,
+/// which also sheds half of the hero's stress.
+fn resolve_check(combat: &mut AdventureCombat, hero: &mut HeroCombatant) {
+    let virtue_bps = (VIRTUE_BASE_BPS
+        + hero.luck as u32 * VIRTUE_LUCK_BPS
+        + hero.positive_traits as u32 * VIRTUE_TRAIT_BPS)
+        .min(VIRTUE_MAX_BPS);
+    let roll = rand_u32(combat);
+    let pick = (roll >> 16) as u8;
+
+    if roll % 10_000 < virtue_bps {
+        hero.resolve = if pick & 1 == 0 {
+            VIRTUE_STALWART
+        } else {
+            VIRTUE_INSPIRING
+        };
+        hero.stress = hero.stress_max / 2;
+        if hero.resolve == VIRTUE_STALWART {
+            apply_stalwart(hero);
+        }
+        msg!("Hero {} is virtuous: {}", hero.hero_index, hero.resolve);
+    } else {
+        hero.resolve = AFFLICTION_STUBBORN + pick % AFFLICTION_COUNT;
+        msg!("Hero {} is afflicted: {}", hero.hero_index, hero.resolve);
+    }
+}
+
+fn apply_stalwart(hero: &mut HeroCombatant) {
+    hero.attack = hero
+        .attack
+        .saturating_add(hero.attack * STALWART_STAT_PERCENT / 100);
+    hero.defense = hero
+        .defense
+        .saturating_add(hero.defense * STALWART_STAT_PERCENT / 100);
+}
+
+/// Start-of-turn effects of afflictions and virtues. Returns false when the hero refuses to act.
+fn apply_resolve_turn(combat: &mut AdventureCombat, hero_index: usize) -> bool {
+    let hero_count = combat.hero_count as usize;
+    match combat.heroes[hero_index].resolve {
+        AFFLICTION_STUBBORN => {
+            let refuses = rand_percent(combat) < STUBBORN_REFUSE_BPS;
+            if refuses {
+                msg!("Hero {} refuses orders", hero_index);
+                log_self_event(
+                    combat,
+                    CombatantKind::Hero,
+                    hero_index as u8,
+                    CombatLogAction::RefusedOrders,
+                );
+            }
+            return !refuses;
+        }
+        AFFLICTION_DESPAIRING => {
+            for idx in (0..hero_count).filter(|idx| *idx != hero_index) {
+                let mut ally = combat.heroes[idx];
+                add_stress(combat, &mut ally, DESPAIR_ALLY_STRESS);
+                combat.heroes[idx] = ally;
+            }
+        }
+        VIRTUE_INSPIRING => {
+            for ally in combat.heroes.iter_mut().take(hero_count) {
+                if ally.alive {
+                    ally.stress = ally.stress.saturating_sub(INSPIRING_PARTY_RELIEF);
+                }
+            }
+        }
+        _ => {}
+    }
+    true
+}
+
+fn random_enemy_in_ranks(combat: &AdventureCombat, target_ranks: u8, roll: u32) -> Option<usize> {
+    let mut candidates = [0usize; MAX_ENEMIES];
+    let mut count = 0;
+    for idx in 0..combat.enemy_count as usize {
+        let enemy = combat.enemies[idx];
+        if enemy.alive && rank_in(enemy.rank, target_ranks) {
+            candidates[count] = idx;
+            count += 1;
+        }
+    }
+    (count > 0).then(|| candidates[roll as usize % count])
+}
+
+/// Write an entry to the combat log ring buffer and return the slot it went to.
+fn log_event(combat: &mut AdventureCombat, entry: CombatLogEntry) -> usize {
+    let slot = combat.log_cursor as usize % COMBAT_LOG_CAPACITY;
+    combat.log[slot] = entry;
+    combat.log_cursor = combat.log_cursor.wrapping_add(1);
+    slot
+}
+
+fn log_entry(
+    actor_kind: CombatantKind,
+    actor_index: u8,
+    action: CombatLogAction,
+    target_kind: CombatantKind,
+    target_index: u8,
+) -> CombatLogEntry {
+    CombatLogEntry {
+        actor_kind,
+        actor_index,
+        action,
+        target_kind,
+        target_index,
+        ..CombatLogEntry::default()
+    }
+}
+
+/// Log something that happens to a combatant on its own, such as losing a turn to a stun.
+fn log_self_event(
+    combat: &mut AdventureCombat,
+    kind: CombatantKind,
+    index: u8,
+    action: CombatLogAction,
+) {
+    let entry = log_entry(kind, index, action, kind, index);
+    log_event(combat, entry);
+}
+
+fn log_action(kind: HeroActionKind) -> CombatLogAction {
+    match kind {
+        HeroActionKind::Attack => CombatLogAction::Attack,
+        HeroActionKind::Skill1 => CombatLogAction::Skill1,
+        HeroActionKind::Skill2 => CombatLogAction::Skill2,
+        HeroActionKind::Defend => CombatLogAction::Defend,
+        HeroActionKind::UseItem => CombatLogAction::UseItem,
+        HeroActionKind::Reposition => CombatLogAction::Reposition,
+    }
 }
 
 fn check_victory(combat: &mut AdventureCombat) -> bool {
@@ -1248,20 +2397,365 @@ fn first_alive_hero_index(combat: &AdventureCombat) -> Option<usize> {
     (0..combat.hero_count as usize).find(|idx| combat.heroes[*idx].alive)
 }
 
-fn select_hero_target(combat: &AdventureCombat, roll: u32) -> Result<usize> {
-    let mut alive: [u8; MAX_PARTY] = [0; MAX_PARTY];
-    let mut count = 0usize;
-    for idx in 0..combat.hero_count as usize {
-        if combat.heroes[idx].alive {
-            alive[count] = idx as u8;
-            count += 1;
+/// Score every living hero within reach (falling back to any living hero) by the threat they
+/// hold on this enemy, how wounded they are and how open they are to the ability, and pick
+/// the best. A small roll breaks ties and keeps the enemy from being fully predictable.
+fn select_hero_target(
+    combat: &AdventureCombat,
+    enemy: &EnemyCombatant,
+    profile: &AiProfile,
+    ability: AbilitySpec,
+    roll: u32,
+) -> Result<usize> {
+    let hero_count = combat.hero_count as usize;
+    let any_in_reach = (0..hero_count).any(|idx| {
+        let hero = combat.heroes[idx];
+        hero.alive && rank_in(hero.rank, ability.target_ranks)
+    });
+
+    let mut best: Option<(usize, u32)> = None;
+    for idx in 0..hero_count {
+        let hero = combat.heroes[idx];
+        if !hero.alive || (any_in_reach && !rank_in(hero.rank, ability.target_ranks)) {
+            continue;
+        }
+        let jitter = roll.rotate_left(idx as u32 * 8) % AI_JITTER;
+        let score = target_score(enemy, profile, ability, &hero) + jitter;
+        let is_better = match best {
+            Some((_, best_score)) => score > best_score,
+            None => true,
+        };
+        if is_better {
+            best = Some((idx, score));
+        }
+    }
+
+    best.map(|(idx, _)| idx)
+        .ok_or(error!(AdventureError::HeroIndexOutOfRange))
+}
+
+fn target_score(
+    enemy: &EnemyCombatant,
+    profile: &AiProfile,
+    ability: AbilitySpec,
+    hero: &HeroCombatant,
+) -> u32 {
+    let threat = enemy
+        .threat
+        .get(hero.hero_index as usize)
+        .copied()
+        .unwrap_or(0) as u32;
+    let missing_hp_percent = missing_hp_percent(hero.hp, hero.max_hp);
+    let mut vulnerability = if hero.guard { 0 } else { 50 };
+    if total_stacks(&hero.statuses, StatusEffect::Mark) > 0 {
+        vulnerability += 50;
+    }
+    if let Some(status) = ability.status {
+        if total_stacks(&hero.statuses, status.effect) == 0 {
+            vulnerability += 50;
+        }
+    }
+
+    threat * profile.threat_weight
+        + missing_hp_percent * profile.low_hp_weight
+        + vulnerability * profile.vulnerability_weight
+}
+
+/// Weigh the enemy's affordable skills: stronger skills score higher, status skills are
+/// favoured while a reachable hero lacks the status and avoided once everyone has it, and area
+/// skills gain value with the number of heroes they can catch. Heals, buffs and summons are
+/// only weighed when they would do something.
+fn choose_enemy_ability(
+    combat: &AdventureCombat,
+    enemy_index: usize,
+    enemy: &EnemyCombatant,
+    profile: &AiProfile,
+    roll: u32,
+) -> AbilitySpec {
+    let set = enemy_ability_set(enemy.kind, enemy.phase);
+    let living_heroes = (0..combat.hero_count as usize)
+        .filter(|idx| combat.heroes[*idx].alive)
+        .count() as u32;
+
+    let mut best = set.basic;
+    let mut best_score = 0u32;
+    let options = [
+        (HeroActionKind::Attack, set.basic),
+        (HeroActionKind::Skill1, set.skill1),
+        (HeroActionKind::Skill2, set.skill2),
+    ];
+    for (slot, (kind, ability)) in options.into_iter().enumerate() {
+        let ability = AbilitySpec { kind, ..ability };
+        if ability.cost > enemy.ap || !rank_in(enemy.rank, ability.usable_ranks) {
+            continue;
+        }
+
+        let mut score = ability.power_percent as u32;
+        if ability.target != Targeting::Enemy {
+            let Some(support_score) = enemy_support_score(combat, enemy_index, ability) else {
+                continue;
+            };
+            score = support_score;
+        } else if let Some(status) = ability.status {
+            let open_target = (0..combat.hero_count as usize).any(|idx| {
+                let hero = combat.heroes[idx];
+                hero.alive
+                    && rank_in(hero.rank, ability.target_ranks)
+                    && total_stacks(&hero.statuses, status.effect) == 0
+            });
+            score = if open_target {
+                score + profile.status_skill_bias
+            } else {
+                score.saturating_sub(profile.status_skill_bias)
+            };
+        }
+        let extra_targets = match ability.area {
+            AreaOfEffect::Single => 0,
+            AreaOfEffect::Adjacent => living_heroes.saturating_sub(1).min(2),
+            AreaOfEffect::All => living_heroes.saturating_sub(1),
+        };
+        score +=
+            extra_targets * ability.power_percent as u32 * ability.falloff_percent as u32 / 10_000;
+        score += roll.rotate_left(slot as u32 * 11) % AI_JITTER;
+
+        if score > best_score {
+            best = ability;
+            best_score = score;
+        }
+    }
+
+    best
+}
+
+/// What an ally-side enemy ability is worth this turn, or `None` when it would do nothing.
+fn enemy_support_score(
+    combat: &AdventureCombat,
+    enemy_index: usize,
+    ability: AbilitySpec,
+) -> Option<u32> {
+    if ability.summon.is_some() {
+        let slot_free = (combat.enemy_count as usize) < MAX_ENEMIES;
+        return slot_free.then_some(ability.power_percent as u32);
+    }
+    let ally = combat.enemies[select_enemy_ally(combat, enemy_index, ability)?];
+    if ability.damage_type == DamageType::Heal {
+        let missing = missing_hp_percent(ally.hp, ally.max_hp);
+        return (missing >= ENEMY_HEAL_THRESHOLD_PERCENT)
+            .then_some(ability.power_percent as u32 * missing / 50);
+    }
+    Some(ability.power_percent as u32)
+}
+
+/// The ally an enemy support ability lands on: the most wounded for heals, otherwise the
+/// front-most ally still missing the ability's status, with the user itself as a last resort.
+fn select_enemy_ally(
+    combat: &AdventureCombat,
+    enemy_index: usize,
+    ability: AbilitySpec,
+) -> Option<usize> {
+    let living = (0..combat.enemy_count as usize).filter(|idx| combat.enemies[*idx].alive);
+    if ability.damage_type == DamageType::Heal {
+        return living
+            .filter(|idx| combat.enemies[*idx].hp < combat.enemies[*idx].max_hp)
+            .max_by_key(|idx| {
+                let ally = combat.enemies[*idx];
+                missing_hp_percent(ally.hp, ally.max_hp)
+            });
+    }
+    let effect = ability.status?.effect;
+    living
+        .filter(|idx| total_stacks(&combat.enemies[*idx].statuses, effect) == 0)
+        .min_by_key(|idx| (*idx == enemy_index, combat.enemies[*idx].rank))
+}
+
+/// Heal or buff one of the enemy's allies. The acting enemy is held outside `combat` while it
+/// acts, so a self-target goes through `enemy`.
+fn enemy_support_ally(
+    combat: &mut AdventureCombat,
+    enemy: &mut EnemyCombatant,
+    enemy_index: usize,
+    ability: AbilitySpec,
+) {
+    let Some(target_index) = select_enemy_ally(combat, enemy_index, ability) else {
+        msg!("Enemy {} has no ally to support", enemy_index);
+        return;
+    };
+    let mut target = if target_index == enemy_index {
+        *enemy
+    } else {
+        combat.enemies[target_index]
+    };
+
+    let mut entry = log_entry(
+        CombatantKind::Enemy,
+        enemy_index as u8,
+        log_action(ability.kind),
+        CombatantKind::Enemy,
+        target_index as u8,
+    );
+    if ability.damage_type == DamageType::Heal {
+        let heal_variance = rand_range(combat, 4, 10);
+        let heal_amount = enemy.magic.saturating_mul(ability.power_percent) / 100 + heal_variance;
+        let before = target.hp;
+        target.hp = target.hp.saturating_add(heal_amount).min(target.max_hp);
+        entry.amount = target.hp - before;
+        msg!(
+            "Enemy {} heals enemy {} for {}",
+            enemy_index,
+            target_index,
+            entry.amount
+        );
+    }
+    if let Some(status) = ability.status {
+        let mut guard = false;
+        if apply_status_application(
+            combat,
+            &mut target.statuses,
+            &mut guard,
+            status,
+            0,
+            enemy.luck,
+        ) {
+            entry.status = status.effect;
+        }
+    }
+    log_event(combat, entry);
+
+    if target_index == enemy_index {
+        *enemy = target;
+    } else {
+        combat.enemies[target_index] = target;
+    }
+}
+
+/// Call a fresh enemy into the first unused slot, at the back of the formation, and give it a
+/// place in the initiative order.
+fn summon_enemy(combat: &mut AdventureCombat, enemy_index: usize, ability: AbilitySpec, kind: u8) {
+    let slot = combat.enemy_count as usize;
+    if slot >= MAX_ENEMIES {
+        msg!("Enemy {} finds no room to summon", enemy_index);
+        return;
+    }
+
+    let living = (0..slot).filter(|idx| combat.enemies[*idx].alive).count();
+    let mut summoned = spawn_enemy(kind, combat.torch);
+    summoned.rank = living as u8 + 1;
+    combat.enemies[slot] = summoned;
+    combat.enemy_count += 1;
+    insert_initiative_slot(
+        combat,
+        CombatantKind::Enemy,
+        slot as u8,
+        summoned.speed as i16,
+    );
+
+    let mut entry = log_entry(
+        CombatantKind::Enemy,
+        enemy_index as u8,
+        log_action(ability.kind),
+        CombatantKind::Enemy,
+        slot as u8,
+    );
+    entry.action = CombatLogAction::Summon;
+    log_event(combat, entry);
+    msg!(
+        "Enemy {} summons enemy kind {} into slot {}",
+        enemy_index,
+        kind,
+        slot
+    );
+}
+
+fn add_threat(enemy: &mut EnemyCombatant, hero_index: u8, amount: u16) {
+    if let Some(threat) = enemy.threat.get_mut(hero_index as usize) {
+        *threat = (*threat as u16).saturating_add(amount).min(u8::MAX as u16) as u8;
+    }
+}
+
+fn first_enemy_in_ranks(combat: &AdventureCombat, target_ranks: u8) -> Option<usize> {
+    (0..combat.enemy_count as usize)
+        .filter(|idx| {
+            let enemy = combat.enemies[*idx];
+            enemy.alive && rank_in(enemy.rank, target_ranks)
+        })
+        .min_by_key(|idx| combat.enemies[*idx].rank)
+}
+
+fn missing_hp_percent(hp: u16, max_hp: u16) -> u32 {
+    if max_hp == 0 {
+        0
+    } else {
+        (max_hp.saturating_sub(hp) as u32) * 100 / max_hp as u32
+    }
+}
+
+fn rank_in(rank: u8, mask: u8) -> bool {
+    (1..=4).contains(&rank) && mask & (1 << (rank - 1)) != 0
+}
+
+/// Close gaps left by the fallen: living combatants keep their relative order and fill ranks
+/// from 1. The fallen drop to rank 0, and anyone revived rejoins at the back.
+fn compact_ranks(combat: &mut AdventureCombat) {
+    let hero_count = (combat.hero_count as usize).min(MAX_PARTY);
+    let hero_entries = combat.heroes.map(|hero| (hero.alive, hero.rank));
+    let hero_ranks = compacted_ranks(hero_entries, hero_count);
+    for (hero, rank) in combat.heroes.iter_mut().zip(hero_ranks) {
+        hero.rank = rank;
+    }
+
+    let enemy_count = (combat.enemy_count as usize).min(MAX_ENEMIES);
+    let enemy_entries = combat.enemies.map(|enemy| (enemy.alive, enemy.rank));
+    let enemy_ranks = compacted_ranks(enemy_entries, enemy_count);
+    for (enemy, rank) in combat.enemies.iter_mut().zip(enemy_ranks) {
+        enemy.rank = rank;
+    }
+}
+
+fn compacted_ranks<const N: usize>(entries: [(bool, u8); N], count: usize) -> [u8; N] {
+    let mut order = [0usize; N];
+    let mut len = 0;
+    for (idx, (alive, _)) in entries.iter().enumerate().take(count) {
+        if *alive {
+            order[len] = idx;
+            len += 1;
         }
     }
-    if count == 0 {
-        return Err(error!(AdventureError::HeroIndexOutOfRange));
+    order[..len].sort_by_key(|idx| {
+        let rank = entries[*idx].1;
+        (if rank == 0 { u8::MAX } else { rank }, *idx)
+    });
+
+    let mut ranks = [0u8; N];
+    for (position, idx) in order[..len].iter().enumerate() {
+        ranks[*idx] = position as u8 + 1;
+    }
+    ranks
+}
+
+fn knock_back_hero(combat: &mut AdventureCombat, hero_index: usize, ranks: u8) {
+    for _ in 0..ranks {
+        let rank = combat.heroes[hero_index].rank;
+        let Some(behind) = (0..combat.hero_count as usize)
+            .find(|idx| combat.heroes[*idx].alive && combat.heroes[*idx].rank == rank + 1)
+        else {
+            break;
+        };
+        combat.heroes[behind].rank = rank;
+        combat.heroes[hero_index].rank = rank + 1;
+    }
+}
+
+fn knock_back_enemy(combat: &mut AdventureCombat, enemy_index: usize, ranks: u8) {
+    for _ in 0..ranks {
+        let rank = combat.enemies[enemy_index].rank;
+        let Some(behind) = (0..combat.enemy_count as usize)
+            .find(|idx| combat.enemies[*idx].alive && combat.enemies[*idx].rank == rank + 1)
+        else {
+            break;
+        };
+        combat.enemies[behind].rank = rank;
+        combat.enemies[enemy_index].rank = rank + 1;
     }
-    let pick = (roll as usize) % count;
-    Ok(alive[pick] as usize)
 }
 
 fn mark_enemy_dead(combat: &mut AdventureCombat, enemy_index: u8) {
@@ -1301,6 +2795,7 @@ fn mark_hero_dead(combat: &mut AdventureCombat, hero_index: u8) {
 fn apply_item_to_ally(
     adventure: &mut AdventureSession,
     combat: &mut AdventureCombat,
+    registry: &ItemRegistry,
     hero: &mut HeroCombatant,
     item_key: u8,
     target: TargetSelector,
@@ -1323,49 +2818,21 @@ fn apply_item_to_ally(
         combat.heroes[target_index]
     };
 
-    match item_key {
-        1 => {
-            // StressTonic - reduce stress by 20
-            target_hero.stress = target_hero.stress.saturating_sub(20);
-        }
-        3 => {
-            // HealingSalve - restore 30 HP
-            let heal = 30u16;
-            target_hero.hp = target_hero.hp.saturating_add(heal).min(target_hero.max_hp);
-            target_hero.alive = target_hero.hp > 0;
-        }
-        5 => {
-            // CalmingIncense - reduce stress by 30 and clear one negative status
-            target_hero.stress = target_hero.stress.saturating_sub(30);
-            // Remove first negative status (Poison, Bleed, Burn, or Chill)
-            for status in target_hero.statuses.iter_mut() {
-                if !status.is_empty()
-                    && matches!(
-                        status.effect,
-                        StatusEffect::Poison
-                            | StatusEffect::Bleed
-                            | StatusEffect::Burn
-                            | StatusEffect::Chill
-                    )
-                {
-                    status.clear();
-                    break;
-                }
-            }
-        }
-        6 => {
-            // PhoenixFeather - revive dead hero at 50% HP
-            if !target_hero.alive {
-                let restored = target_hero.max_hp / 2;
-                target_hero.hp = restored.max(1);
-                target_hero.alive = true;
-                mark_hero_alive(combat, target_index as u8);
-            }
-        }
-        _ => {
-            // Unknown item - no effect but still consumed
-        }
+    // Unknown or torch items have no effect in combat but are still consumed
+    let effect = registry.get(item_key).map_or(ItemEffect::None, item_effect);
+    let hp_before = target_hero.hp;
+    if apply_item_effect(&mut target_hero, effect) {
+        mark_hero_alive(combat, target_index as u8);
     }
+    let mut entry = log_entry(
+        CombatantKind::Hero,
+        hero.hero_index,
+        CombatLogAction::UseItem,
+        CombatantKind::Hero,
+        target_index as u8,
+    );
+    entry.amount = target_hero.hp.saturating_sub(hp_before);
+    log_event(combat, entry);
 
     if target_index == hero.hero_index as usize {
         *hero = target_hero;
@@ -1414,9 +2881,25 @@ fn hero_skill(hero_type: u8, action: HeroActionKind) -> AbilitySpec {
         power_percent: 135,
         status: None,
         target: Targeting::Enemy,
+        usable_ranks: RANKS_ALL,
+        target_ranks: RANKS_FRONT,
+        knockback: 0,
+        area: AreaOfEffect::Single,
+        falloff_percent: 100,
+        threat: 0,
+        stress: 0,
+        summon: None,
+    };
+    // Archers, priests and wizards strike from range with their basic attack
+    let base_attack = match hero_type {
+        HERO_ARCHER | HERO_PRIEST | HERO_WIZARD => base_attack.ranged(),
+        _ => base_attack,
     };
     match action {
-        HeroActionKind::Attack | HeroActionKind::Defend | HeroActionKind::UseItem => base_attack,
+        HeroActionKind::Attack
+        | HeroActionKind::Defend
+        | HeroActionKind::UseItem
+        | HeroActionKind::Reposition => base_attack,
         HeroActionKind::Skill1 => HERO_ABILITY_SETS
             .get(hero_type as usize)
             .map(|set| set.skill1)
@@ -1428,18 +2911,34 @@ fn hero_skill(hero_type: u8, action: HeroActionKind) -> AbilitySpec {
     }
 }
 
-fn enemy_skill(enemy_kind: u8, roll: u32) -> AbilitySpec {
-    let set = ENEMY_ABILITY_SETS
-        .get(enemy_kind as usize)
-        .unwrap_or(&ENEMY_ABILITY_SETS[0]);
-    let choice = roll % 100;
-    if choice < 50 {
-        set.basic
-    } else if choice < 80 {
-        set.skill1
-    } else {
-        set.skill2
+/// Bosses shift into their second phase once, the first time they act at or below half health.
+fn maybe_enrage_boss(enemy: &mut EnemyCombatant, enemy_index: usize) {
+    if !is_boss_kind(enemy.kind) || enemy.phase > 0 {
+        return;
+    }
+    if enemy.hp as u32 * 100 > enemy.max_hp as u32 * BOSS_ENRAGE_HP_PERCENT as u32 {
+        return;
     }
+    enemy.phase = 1;
+    enemy.ap = ENEMY_AP_MAX;
+    enemy.attack = enemy
+        .attack
+        .saturating_add(enemy.attack * BOSS_ENRAGE_ATTACK_PERCENT / 100);
+    enemy.magic = enemy
+        .magic
+        .saturating_add(enemy.magic * BOSS_ENRAGE_ATTACK_PERCENT / 100);
+    msg!("Boss {} enters phase 2", enemy_index);
+}
+
+fn enemy_ability_set(enemy_kind: u8, phase: u8) -> &'static EnemyAbilitySet {
+    if phase > 0 && is_boss_kind(enemy_kind) {
+        if let Some(set) = BOSS_ENRAGED_ABILITY_SETS.get((enemy_kind - FIRST_BOSS_KIND) as usize) {
+            return set;
+        }
+    }
+    ENEMY_ABILITY_SETS
+        .get(enemy_kind as usize)
+        .unwrap_or(&ENEMY_ABILITY_SETS[0])
 }
 
 fn status_max_stacks(effect: StatusEffect) -> u8 {
@@ -1448,11 +2947,14 @@ fn status_max_stacks(effect: StatusEffect) -> u8 {
         StatusEffect::Bleed => 3,
         StatusEffect::Burn => 2,
         StatusEffect::Chill => 3,
-        StatusEffect::Guard | StatusEffect::None => 1,
+        StatusEffect::Mark => 2,
+        StatusEffect::Regen => 3,
+        StatusEffect::Blind => 2,
+        StatusEffect::Vulnerable => 3,
+        StatusEffect::Stun | StatusEffect::Guard | StatusEffect::None => 1,
     }
 }
 
-#[allow(dead_code)]
 fn total_stacks(statuses: &[StatusInstance; MAX_STATUS_PER_COMBATANT], effect: StatusEffect) -> u8 {
     statuses
         .iter()
@@ -1500,12 +3002,20 @@ fn apply_status_application(
         return set_guard_status(statuses, guard_flag, application.base_duration);
     }
 
-    let mut chance_bps: i32 =
-        6500 + application.resist_modifier as i32 * 100 + attacker_luck as i32 * 50;
-    chance_bps -= resist as i32 * 45;
-    chance_bps = chance_bps.clamp(1200, 9800);
-    if rand_percent(combat) as i32 >= chance_bps {
-        return false;
+    // Regen is a blessing and is never resisted
+    if application.effect != StatusEffect::Regen {
+        let resist_per_point = if application.effect == StatusEffect::Stun {
+            STUN_RESIST_BPS_PER_POINT
+        } else {
+            STATUS_RESIST_BPS_PER_POINT
+        };
+        let mut chance_bps: i32 =
+            6500 + application.resist_modifier as i32 * 100 + attacker_luck as i32 * 50;
+        chance_bps -= resist as i32 * resist_per_point;
+        chance_bps = chance_bps.clamp(1200, 9800);
+        if rand_percent(combat) as i32 >= chance_bps {
+            return false;
+        }
     }
 
     let max_stack = status_max_stacks(application.effect);
@@ -1583,6 +3093,15 @@ fn remove_guard_status(
 struct StatusTurnResult {
     alive: bool,
     chill_stacks: u8,
+    stunned: bool,
+}
+
+/// Start-of-turn status effects. Healing is applied to `hp` directly; damage is returned so
+/// heroes can route it through Death's Door.
+struct StatusTick {
+    damage: u16,
+    chill_stacks: u8,
+    stunned: bool,
 }
 
 fn process_statuses_for_actor(
@@ -1590,9 +3109,12 @@ fn process_statuses_for_actor(
     statuses: &mut [StatusInstance; MAX_STATUS_PER_COMBATANT],
     guard_flag: &mut bool,
     hp: &mut u16,
-) -> StatusTurnResult {
+    max_hp: u16,
+) -> StatusTick {
     let mut total_damage: u16 = 0;
+    let mut total_heal: u16 = 0;
     let mut chill_total: u8 = 0;
+    let mut stunned = false;
 
     let mut strip_guard = false;
 
@@ -1616,21 +3138,40 @@ fn process_statuses_for_actor(
             StatusEffect::Chill => {
                 chill_total = chill_total.max(status.stacks);
             }
-            StatusEffect::Guard | StatusEffect::None => {}
+            StatusEffect::Regen => {
+                total_heal = total_heal.saturating_add(status.stacks as u16 * REGEN_HEAL_PER_STACK);
+            }
+            StatusEffect::Stun => {
+                stunned = true;
+            }
+            StatusEffect::Mark
+            | StatusEffect::Blind
+            | StatusEffect::Vulnerable
+            | StatusEffect::Guard
+            | StatusEffect::None => {}
         }
     }
 
-    if total_damage > 0 {
-        *hp = hp.saturating_sub(total_damage);
+    // Regen also pulls a hero back from Death's Door
+    if total_heal > 0 {
+        *hp = hp.saturating_add(total_heal).min(max_hp);
+    }
+    // A stun costs exactly one turn
+    if stunned {
+        statuses
+            .iter_mut()
+            .filter(|slot| slot.effect == StatusEffect::Stun)
+            .for_each(StatusInstance::clear);
     }
 
     if strip_guard {
         remove_guard_status(statuses, guard_flag);
     }
 
-    StatusTurnResult {
-        alive: *hp > 0,
+    StatusTick {
+        damage: total_damage,
         chill_stacks: chill_total,
+        stunned,
     }
 }
 
@@ -1656,19 +3197,52 @@ fn decay_statuses(
     }
 }
 
+/// What a hit lands on: the target's mitigation and the statuses that change it.
+#[derive(Clone, Copy)]
+struct Defender {
+    defense: u16,
+    resistance: u16,
+    guarded: bool,
+    mark_stacks: u8,
+    vulnerable_stacks: u8,
+}
+
+impl Defender {
+    fn new(
+        defense: u16,
+        resistance: u16,
+        guarded: bool,
+        statuses: &[StatusInstance; MAX_STATUS_PER_COMBATANT],
+    ) -> Self {
+        Self {
+            defense,
+            resistance,
+            guarded,
+            mark_stacks: total_stacks(statuses, StatusEffect::Mark),
+            vulnerable_stacks: total_stacks(statuses, StatusEffect::Vulnerable),
+        }
+    }
+}
+
+/// Damage dealt by one hit, or 0 when a blinded attacker misses.
 fn compute_damage(
     combat: &mut AdventureCombat,
     ability: AbilitySpec,
     atk: u16,
     mag: u16,
     luck: u16,
-    target_def: u16,
-    target_res: u16,
-    target_guarded: bool,
+    blind_stacks: u8,
+    target: Defender,
 ) -> (u16, bool) {
     match ability.damage_type {
         DamageType::Heal | DamageType::Support => (0, false),
         DamageType::Physical | DamageType::Magical => {
+            if blind_stacks > 0
+                && rand_percent(combat) < BLIND_MISS_BPS.saturating_mul(blind_stacks as u16)
+            {
+                return (0, false);
+            }
+
             let offensive = if matches!(ability.damage_type, DamageType::Physical) {
                 atk
             } else {
@@ -1679,49 +3253,39 @@ fn compute_damage(
             let roll = rand_range(combat, 0, variance);
             let mut damage = base.saturating_add(roll);
 
-            if rand_percent(combat) < crit_chance_bps(luck) {
+            let is_physical = matches!(ability.damage_type, DamageType::Physical);
+            let crit = rand_percent(combat) < crit_chance_bps(luck);
+            if crit {
                 damage = damage.saturating_mul(CRIT_MULTIPLIER_PERCENT) / 100;
-                if matches!(ability.damage_type, DamageType::Physical) {
+                if is_physical {
                     damage = damage.saturating_add(offensive / 4);
                 }
                 damage = damage.max(6);
-                damage = apply_defense(
-                    damage,
-                    target_def,
-                    target_res,
-                    target_guarded,
-                    matches!(ability.damage_type, DamageType::Physical),
-                );
-                return (damage.max(1), true);
             }
 
-            damage = apply_defense(
-                damage,
-                target_def,
-                target_res,
-                target_guarded,
-                matches!(ability.damage_type, DamageType::Physical),
-            );
+            damage = apply_defense(damage, target, is_physical);
+            if target.mark_stacks > 0 {
+                let bonus = 100 + MARK_DAMAGE_PERCENT * target.mark_stacks as u16;
+                damage = (damage as u32 * bonus as u32 / 100) as u16;
+            }
 
-            (damage.max(1), false)
+            (damage.max(1), crit)
         }
     }
 }
 
-fn apply_defense(
-    mut damage: u16,
-    target_def: u16,
-    target_res: u16,
-    target_guarded: bool,
-    is_physical: bool,
-) -> u16 {
-    let mitigation = if is_physical {
-        target_def / 2
+fn apply_defense(mut damage: u16, target: Defender, is_physical: bool) -> u16 {
+    let mut mitigation = if is_physical {
+        target.defense / 2
     } else {
-        target_res / 2
+        target.resistance / 2
     };
+    if target.vulnerable_stacks > 0 {
+        let reduction = (VULNERABLE_MITIGATION_PERCENT * target.vulnerable_stacks as u16).min(100);
+        mitigation -= mitigation * reduction / 100;
+    }
     damage = damage.saturating_sub(mitigation);
-    if target_guarded {
+    if target.guarded {
         damage = (damage as u32 * 60 / 100) as u16;
         damage = damage.max(1);
     }
@@ -1733,12 +3297,34 @@ fn start_hero_turn(combat: &mut AdventureCombat, hero_index: usize) -> StatusTur
     let mut statuses = hero_snapshot.statuses;
     let mut guard = hero_snapshot.guard;
     let mut hp = hero_snapshot.hp;
-    let result = process_statuses_for_actor(combat, &mut statuses, &mut guard, &mut hp);
+    let tick = process_statuses_for_actor(
+        combat,
+        &mut statuses,
+        &mut guard,
+        &mut hp,
+        hero_snapshot.max_hp,
+    );
     hero_snapshot.statuses = statuses;
     hero_snapshot.guard = guard;
     hero_snapshot.hp = hp;
+    if tick.damage > 0 {
+        let mut entry = log_entry(
+            CombatantKind::Hero,
+            hero_index as u8,
+            CombatLogAction::StatusTick,
+            CombatantKind::Hero,
+            hero_index as u8,
+        );
+        entry.amount = tick.damage;
+        log_event(combat, entry);
+    }
+    let alive = take_hero_damage(combat, &mut hero_snapshot, tick.damage);
     combat.heroes[hero_index] = hero_snapshot;
-    result
+    StatusTurnResult {
+        alive,
+        chill_stacks: tick.chill_stacks,
+        stunned: tick.stunned,
+    }
 }
 
 fn end_hero_turn(hero: &mut HeroCombatant) {
@@ -1747,19 +3333,48 @@ fn end_hero_turn(hero: &mut HeroCombatant) {
 
 fn start_enemy_turn(combat: &mut AdventureCombat, enemy_index: usize) -> StatusTurnResult {
     let mut enemy_snapshot = combat.enemies[enemy_index];
-    let mut guard = false;
+    let mut guard = total_stacks(&enemy_snapshot.statuses, StatusEffect::Guard) > 0;
     let mut statuses = enemy_snapshot.statuses;
     let mut hp = enemy_snapshot.hp;
-    let result = process_statuses_for_actor(combat, &mut statuses, &mut guard, &mut hp);
+    let tick = process_statuses_for_actor(
+        combat,
+        &mut statuses,
+        &mut guard,
+        &mut hp,
+        enemy_snapshot.max_hp,
+    );
+    hp = hp.saturating_sub(tick.damage);
+    if tick.damage > 0 {
+        let mut entry = log_entry(
+            CombatantKind::Enemy,
+            enemy_index as u8,
+            CombatLogAction::StatusTick,
+            CombatantKind::Enemy,
+            enemy_index as u8,
+        );
+        entry.amount = tick.damage;
+        if hp == 0 {
+            entry.flags |= COMBAT_LOG_KILLED;
+        }
+        log_event(combat, entry);
+    }
     enemy_snapshot.statuses = statuses;
     enemy_snapshot.hp = hp;
     combat.enemies[enemy_index] = enemy_snapshot;
-    result
+    StatusTurnResult {
+        alive: hp > 0,
+        chill_stacks: tick.chill_stacks,
+        stunned: tick.stunned,
+    }
 }
 
 fn end_enemy_turn(enemy: &mut EnemyCombatant) {
     let mut guard = false;
     decay_statuses(&mut enemy.statuses, &mut guard);
+    // Old grudges fade so the enemy keeps reacting to what the party does now
+    for threat in enemy.threat.iter_mut() {
+        *threat -= *threat / THREAT_DECAY_DIVISOR;
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -1777,6 +3392,7 @@ pub enum HeroActionKind {
     Skill2,
     Defend,
     UseItem,
+    Reposition,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::AdventureError;
+use crate::logic::Mulberry32;
+use crate::state::{AdventureSession, ItemSlot};
+use crate::Interact;
+
+// Corpse loot: (item_key, min_qty, max_qty)
+const CORPSE_LOOT: [(u8, u16, u16); 4] = [
+    (0, 1, 3), // PouchGold
+    (1, 1, 1), // StressTonic
+    (2, 1, 1), // MinorTorch
+    (3, 1, 1), // HealingSalve
+];
+
+/// Use the curio the party is standing on. Outcomes are seeded from the dungeon seed and the
+/// curio index, so the same curio always behaves the same way for a given floor.
+pub fn interact(ctx: Context<Interact>, curio_index: u8) -> Result<()> {
+    let adventure = &mut ctx.accounts.adventure;
+    let owner = ctx.accounts.owner.key();
+    let authority = ctx.accounts.authority.key();
+
+    let is_authorized = authority == owner || adventure.delegate == Some(authority);
+    require!(is_authorized, AdventureError::Unauthorized);
+
+    require!(adventure.is_active, AdventureError::AdventureNotActive);
+    require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
+    require!(!adventure.in_combat, AdventureError::BlockedInCombat);
+
+    let idx = curio_index as usize;
+    let curio_point = adventure
+        .curios
+        .get(idx)
+        .copied()
+        .ok_or(AdventureError::NoCurioAtPosition)?;
+    require!(
+        adventure.party_position == curio_point,
+        AdventureError::NoCurioAtPosition
+    );
+
+    let used = adventure
+        .used_curios
+        .get(idx)
+        .copied()
+        .ok_or(AdventureError::NoCurioAtPosition)?;
+    require!(used == 0, AdventureError::CurioAlreadyUsed);
+
+    let kind = adventure
+        .curio_kinds
+        .get(idx)
+        .copied()
+        .unwrap_or(CURIO_CORPSE);
+
+    let mut seed = adventure.seed ^ ((curio_index as u32 + 1) << 12) ^ 0x00c0_ffee;
+    if seed == 0 {
+        seed = 1;
+    }
+    let mut rng = Mulberry32::new(seed);
+
+    match kind {
+        CURIO_SHRINE => pray_at_shrine(adventure, &mut rng),
+        CURIO_CAMPFIRE => rest_at_campfire(adventure),
+        _ => search_corpse(adventure, &mut rng, curio_index),
+    }
+
+    adventure.used_curios[idx] = 1;
+
+    Ok(())
+}
+
+/// Every living hero takes stress; in exchange the party is blessed for the next few fights.
+fn pray_at_shrine(adventure: &mut AdventureSession, rng: &mut Mulberry32) {
+    let stress = rng.next_range(SHRINE_STRESS_MIN, SHRINE_STRESS_MAX);
+    let combats = rng.next_range(SHRINE_BLESSING_MIN_COMBATS, SHRINE_BLESSING_MAX_COMBATS) as u8;
+
+    let hero_count = (adventure.hero_count as usize).min(MAX_PARTY);
+    for hero in adventure.hero_snapshots.iter_mut().take(hero_count) {
+        if hero.current_hp == 0 {
+            continue;
+        }
+        hero.stress = hero.stress.saturating_add(stress).min(hero.stress_max);
+    }
+    adventure.blessing_combats = adventure.blessing_combats.max(combats);
+
+    msg!(
+        "Shrine blessing for {} combats at {} stress",
+        combats,
+        stress
+    );
+}
+
+/// Living heroes recover health and a little stress while the torch burns down.
+fn rest_at_campfire(adventure: &mut AdventureSession) {
+    let hero_count = (adventure.hero_count as usize).min(MAX_PARTY);
+    for hero in adventure.hero_snapshots.iter_mut().take(hero_count) {
+        if hero.current_hp == 0 {
+            continue;
+        }
+        let heal = (hero.max_hp as u16 * CAMPFIRE_HEAL_PERCENT / 100).max(1);
+        hero.current_hp = (hero.current_hp as u16 + heal).min(hero.max_hp as u16) as u8;
+        hero.stress = hero.stress.saturating_sub(CAMPFIRE_STRESS_RELIEF);
+    }
+    adventure.torch = adventure.torch.saturating_sub(CAMPFIRE_TORCH_COST);
+
+    msg!("The party rests at the campfire");
+}
+
+/// Either something useful turns up, or the leader finds only a grim sight.
+fn search_corpse(adventure: &mut AdventureSession, rng: &mut Mulberry32, curio_index: u8) {
+    if rng.next_f32() >= CORPSE_LOOT_CHANCE {
+        let leader = &mut adventure.hero_snapshots[0];
+        leader.stress = leader
+            .stress
+            .saturating_add(CORPSE_STRESS)
+            .min(leader.stress_max);
+        msg!("Corpse {} held nothing but dread", curio_index);
+        return;
+    }
+
+    let (item_key, min_qty, max_qty) = CORPSE_LOOT[(rng.next_u32() as usize) % CORPSE_LOOT.len()];
+    let quantity = rng.next_range(min_qty, max_qty);
+
+    if let Some(slot) = adventure
+        .pending_loot
+        .iter_mut()
+        .find(|slot| slot.item_key == item_key && slot.quantity > 0)
+    {
+        slot.quantity = slot.quantity.saturating_add(quantity);
+    } else if let Some(slot) = adventure
+        .pending_loot
+        .iter_mut()
+        .find(|slot| slot.is_empty())
+    {
+        *slot = ItemSlot { item_key, quantity };
+    }
+
+    adventure.pending_loot_count = adventure
+        .pending_loot
+        .iter()
+        .filter(|slot| !slot.is_empty())
+        .count() as u8;
+
+    msg!(
+        "Corpse {} yielded item {} x{}",
+        curio_index,
+        item_key,
+        quantity
+    );
+}
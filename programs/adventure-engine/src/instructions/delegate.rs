@@ -11,18 +11,29 @@ pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Option<Pubkey>) -> Resu
     Ok(())
 }
 
-/// Delegate-only step: calls the SDK to delegate the PDA. No account data is mutated here.
+/// Delegate-only step: calls the SDK to delegate the adventure PDA and its combat PDA.
+/// No account data is mutated here.
 pub fn delegate_adventure(ctx: Context<DelegateAdventure>) -> Result<()> {
     let owner = ctx.accounts.owner.key();
     let dungeon_mint = ctx.accounts.dungeon_mint.key();
     let seeds: &[&[u8]] = &[ADVENTURE_SEED, owner.as_ref(), dungeon_mint.as_ref()];
 
-    let mut config = DelegateConfig::default();
-    config.commit_frequency_ms = DEFAULT_COMMIT_FREQUENCY_MS;
-    if let Some(validator) = ctx.remaining_accounts.first() {
-        config.validator = Some(validator.key());
-    }
+    let validator = ctx.remaining_accounts.first().map(|account| account.key());
 
-    ctx.accounts.delegate_pda(&ctx.accounts.payer, seeds, config)?;
+    ctx.accounts
+        .delegate_pda(&ctx.accounts.payer, seeds, delegate_config(validator))?;
+
+    // Combat lives next to the adventure so encounters never allocate on the rollup
+    let adventure_key = ctx.accounts.pda.key();
+    let combat_seeds: &[&[u8]] = &[COMBAT_SEED, adventure_key.as_ref()];
+    ctx.accounts
+        .delegate_combat(&ctx.accounts.payer, combat_seeds, delegate_config(validator))?;
     Ok(())
 }
+
+fn delegate_config(validator: Option<Pubkey>) -> DelegateConfig {
+    let mut config = DelegateConfig::default();
+    config.commit_frequency_ms = DEFAULT_COMMIT_FREQUENCY_MS;
+    config.validator = validator;
+    config
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::AdventureError;
+use crate::logic::{get_torch_stat_buff, tile_index, Mulberry32};
+use crate::state::{AdventureSession, DungeonPoint};
+use crate::{DoorOpenMethod, OpenDoor};
+
+/// Whether the party may step onto `point`. Door tiles block until the door has been opened.
+pub fn is_passable(adventure: &AdventureSession, point: DungeonPoint) -> bool {
+    let tile_idx = tile_index(adventure.width, point.x, point.y);
+    if adventure.grid.get(tile_idx).copied() != Some(TILE_DOOR) {
+        return true;
+    }
+    adventure
+        .doors
+        .iter()
+        .position(|door| *door == point)
+        .and_then(|idx| adventure.opened_doors.get(idx))
+        .map(|flag| *flag != 0)
+        .unwrap_or(false)
+}
+
+/// Open a door next to the party. Closed doors open by hand; locked doors need a key or a
+/// successful lockpick; sealed doors only yield to a key.
+pub fn open_door(ctx: Context<OpenDoor>, door_index: u8, method: DoorOpenMethod) -> Result<()> {
+    let adventure = &mut ctx.accounts.adventure;
+    let owner = ctx.accounts.owner.key();
+    let authority = ctx.accounts.authority.key();
+
+    let is_authorized = authority == owner || adventure.delegate == Some(authority);
+    require!(is_authorized, AdventureError::Unauthorized);
+
+    require!(adventure.is_active, AdventureError::AdventureNotActive);
+    require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
+    require!(!adventure.in_combat, AdventureError::BlockedInCombat);
+
+    let idx = door_index as usize;
+    let door_point = adventure
+        .doors
+        .get(idx)
+        .copied()
+        .ok_or(AdventureError::DoorNotFound)?;
+    let opened = adventure
+        .opened_doors
+        .get(idx)
+        .copied()
+        .ok_or(AdventureError::DoorNotFound)?;
+    require!(opened == 0, AdventureError::DoorAlreadyOpen);
+
+    let position = adventure.party_position;
+    let dx = (position.x as i32 - door_point.x as i32).abs();
+    let dy = (position.y as i32 - door_point.y as i32).abs();
+    require!(dx <= 1 && dy <= 1, AdventureError::DoorOutOfReach);
+
+    let lock = adventure
+        .door_locks
+        .get(idx)
+        .copied()
+        .unwrap_or(DOOR_CLOSED);
+
+    match (lock, method) {
+        (DOOR_CLOSED, _) => {}
+        (_, DoorOpenMethod::Key) => {
+            require!(adventure.keys > 0, AdventureError::NoKeys);
+            adventure.keys -= 1;
+        }
+        (DOOR_LOCKED, DoorOpenMethod::Lockpick) => {
+            if !roll_lockpick(adventure, door_index)? {
+                adventure.torch = adventure.torch.saturating_sub(LOCKPICK_FAIL_TORCH_BURN);
+                let leader = &mut adventure.hero_snapshots[0];
+                leader.stress = leader
+                    .stress
+                    .saturating_add(LOCKPICK_FAIL_STRESS)
+                    .min(leader.stress_max);
+                msg!("Lockpick failed on door {}", door_index);
+                return Ok(());
+            }
+        }
+        (DOOR_SEALED, DoorOpenMethod::Lockpick) => return err!(AdventureError::DoorSealed),
+        _ => return err!(AdventureError::DoorLocked),
+    }
+
+    adventure.opened_doors[idx] = 1;
+    msg!(
+        "Door {} opened at ({}, {})",
+        door_index,
+        door_point.x,
+        door_point.y
+    );
+
+    Ok(())
+}
+
+fn roll_lockpick(adventure: &AdventureSession, door_index: u8) -> Result<bool> {
+    let leader = adventure.hero_snapshots[0];
+    let luck = (leader.luck as u32 + get_torch_stat_buff(adventure.torch) as u32).min(100);
+    let chance = (LOCKPICK_BASE_BPS + luck * LOCKPICK_LUCK_BPS).min(LOCKPICK_MAX_BPS);
+
+    let clock = Clock::get()?;
+    let mut seed = adventure.seed
+        ^ ((door_index as u32) << 18)
+        ^ (clock.slot as u32)
+        ^ (clock.unix_timestamp as u32).rotate_left(7)
+        ^ ((adventure.party_position.x as u32) << 8)
+        ^ (adventure.party_position.y as u32);
+    if seed == 0 {
+        seed = adventure.seed.wrapping_add(1);
+    }
+    let mut rng = Mulberry32::new(seed);
+
+    Ok(rng.next_u32() % 10_000 < chance)
+}
@@ -129,11 +129,12 @@ pub fn exit_adventure<'info>(ctx: Context<'_, '_, '_, 'info, ExitAdventure<'info
 
     let payer_info = ctx.accounts.authority.to_account_info();
     let adventure_info = adventure_account_info.clone();
+    let combat_info = ctx.accounts.combat.to_account_info();
     let magic_program_info = ctx.accounts.magic_program.to_account_info();
 
     commit_and_undelegate_accounts(
         &payer_info,
-        vec![&adventure_info],
+        vec![&adventure_info, &combat_info],
         &ctx.accounts.magic_context,
         &magic_program_info,
     )
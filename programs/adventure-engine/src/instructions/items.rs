@@ -1,15 +1,19 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_PARTY;
 use crate::errors::AdventureError;
+use crate::item_effects::{
+    apply_item_effect, check_hero_item_use, item_effect, ExploringHero, ItemEffect,
+};
 use crate::state::{AdventureSession, ItemSlot};
 use crate::ManageItems;
 
-use player_economy::state::{ItemDefinition, ItemKey};
+use player_economy::state::{ItemDefinition, ItemRegistry};
 
 /// Pick up an item found in the dungeon (from chest or combat)
 /// Validates pending loot availability and inventory capacity before applying changes.
 pub fn pickup_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Result<()> {
-    require!(item_key < 7, AdventureError::InvalidItemKey);
+    let definition = resolve_definition(&ctx.accounts.item_registry, item_key)?;
     require!(quantity > 0, AdventureError::InvalidItemQuantity);
 
     let adventure = &mut ctx.accounts.adventure;
@@ -21,13 +25,10 @@ pub fn pickup_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Re
     require!(adventure.is_active, AdventureError::AdventureNotActive);
     require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
 
-    let item_enum = resolve_item_enum(item_key)?;
-    let definition = item_enum.definition();
-
     ensure_pending_available(adventure, item_key, quantity)?;
-    ensure_inventory_capacity(adventure, item_key, quantity, definition)?;
+    ensure_inventory_capacity(adventure, item_key, quantity, &definition)?;
 
-    apply_inventory_add(adventure, item_key, quantity, definition)?;
+    apply_inventory_add(adventure, item_key, quantity, &definition)?;
     consume_pending_loot(adventure, item_key, quantity)?;
 
     Ok(())
@@ -35,7 +36,7 @@ pub fn pickup_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Re
 
 /// Drop an item from inventory.
 pub fn drop_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Result<()> {
-    require!(item_key < 7, AdventureError::InvalidItemKey);
+    resolve_definition(&ctx.accounts.item_registry, item_key)?;
     require!(quantity > 0, AdventureError::InvalidItemQuantity);
 
     let adventure = &mut ctx.accounts.adventure;
@@ -75,8 +76,8 @@ pub fn swap_item(
     pickup_item_key: u8,
     pickup_quantity: u16,
 ) -> Result<()> {
-    require!(drop_item_key < 7, AdventureError::InvalidItemKey);
-    require!(pickup_item_key < 7, AdventureError::InvalidItemKey);
+    resolve_definition(&ctx.accounts.item_registry, drop_item_key)?;
+    let pickup_definition = resolve_definition(&ctx.accounts.item_registry, pickup_item_key)?;
     require!(drop_quantity > 0, AdventureError::InvalidItemQuantity);
     require!(pickup_quantity > 0, AdventureError::InvalidItemQuantity);
 
@@ -89,9 +90,6 @@ pub fn swap_item(
     require!(adventure.is_active, AdventureError::AdventureNotActive);
     require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
 
-    let pickup_enum = resolve_item_enum(pickup_item_key)?;
-    let pickup_definition = pickup_enum.definition();
-
     ensure_pending_available(adventure, pickup_item_key, pickup_quantity)?;
 
     // First drop the requested amount from inventory.
@@ -118,13 +116,13 @@ pub fn swap_item(
         adventure,
         pickup_item_key,
         pickup_quantity,
-        pickup_definition,
+        &pickup_definition,
     )?;
     apply_inventory_add(
         adventure,
         pickup_item_key,
         pickup_quantity,
-        pickup_definition,
+        &pickup_definition,
     )?;
     consume_pending_loot(adventure, pickup_item_key, pickup_quantity)?;
 
@@ -146,18 +144,11 @@ fn enforce_authority(
     Ok(())
 }
 
-fn resolve_item_enum(item_key: u8) -> Result<ItemKey> {
-    let item = match item_key {
-        0 => ItemKey::PouchGold,
-        1 => ItemKey::StressTonic,
-        2 => ItemKey::MinorTorch,
-        3 => ItemKey::HealingSalve,
-        4 => ItemKey::MysteryRelic,
-        5 => ItemKey::CalmingIncense,
-        6 => ItemKey::PhoenixFeather,
-        _ => return Err(error!(AdventureError::InvalidItemKey)),
-    };
-    Ok(item)
+fn resolve_definition(registry: &ItemRegistry, item_key: u8) -> Result<ItemDefinition> {
+    registry
+        .get(item_key)
+        .copied()
+        .ok_or_else(|| error!(AdventureError::InvalidItemKey))
 }
 
 fn ensure_pending_available(
@@ -355,8 +346,15 @@ fn apply_inventory_add(
     Ok(())
 }
 
-pub fn use_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Result<()> {
-    require!(item_key < 7, AdventureError::InvalidItemKey);
+/// Use an item between fights. Hero-targeted items (salves, tonics, incense, feathers) apply to
+/// `hero_index`; the torch ignores it.
+pub fn use_item(
+    ctx: Context<ManageItems>,
+    item_key: u8,
+    quantity: u16,
+    hero_index: u8,
+) -> Result<()> {
+    let definition = resolve_definition(&ctx.accounts.item_registry, item_key)?;
     require!(quantity > 0, AdventureError::InvalidItemQuantity);
 
     let adventure = &mut ctx.accounts.adventure;
@@ -368,10 +366,21 @@ pub fn use_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Resul
     require!(adventure.is_active, AdventureError::AdventureNotActive);
     require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
 
-    let item_enum = resolve_item_enum(item_key)?;
-    let definition = item_enum.definition();
+    require!(!adventure.in_combat, AdventureError::BlockedInCombat);
+
     require!(definition.usable, AdventureError::ItemNotUsable);
 
+    let effect = item_effect(&definition);
+    let target_index = hero_index as usize;
+    if effect.targets_hero() {
+        require!(
+            target_index < (adventure.hero_count as usize).min(MAX_PARTY),
+            AdventureError::HeroIndexOutOfRange
+        );
+        let alive = adventure.hero_snapshots[target_index].current_hp > 0;
+        check_hero_item_use(effect, quantity, alive)?;
+    }
+
     // Consume the item from inventory
     let slot = adventure
         .items
@@ -390,18 +399,23 @@ pub fn use_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Resul
         adventure.item_count = adventure.item_count.saturating_sub(1);
     }
 
-    // Apply item effect
-    match item_enum {
-        ItemKey::MinorTorch => {
-            adventure.torch = adventure.torch.saturating_add(25);
-            if adventure.torch > 100 {
-                adventure.torch = 100;
+    // Apply item effect once per unit used
+    let session: &mut AdventureSession = adventure;
+    for _ in 0..quantity {
+        match effect {
+            ItemEffect::RestoreTorch(amount) => {
+                session.torch = session.torch.saturating_add(amount).min(100);
             }
-        }
-        _ => {
-            // For now, other items do nothing on-chain when "used"
+            _ if effect.targets_hero() => {
+                let mut target = ExploringHero {
+                    hero: &mut session.hero_snapshots[target_index],
+                    lingering: &mut session.lingering_statuses[target_index],
+                };
+                apply_item_effect(&mut target, effect);
+            }
+            _ => {}
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
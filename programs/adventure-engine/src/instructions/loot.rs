@@ -1,55 +1,59 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_ITEMS;
+use crate::constants::{CHEST_KEY_CHANCE, MAX_ITEMS, MAX_KEYS};
 use crate::errors::AdventureError;
 use crate::logic::{get_torch_stat_buff, Mulberry32};
 use crate::state::{AdventureSession, ItemSlot};
 use crate::OpenChest;
 
-use player_economy::state::{ItemDefinition, ItemKey};
+use player_economy::state::ItemDefinition;
+use player_economy::{
+    ITEM_CALMING_INCENSE, ITEM_HEALING_SALVE, ITEM_MINOR_TORCH, ITEM_MYSTERY_RELIC,
+    ITEM_PHOENIX_FEATHER, ITEM_POUCH_GOLD, ITEM_STRESS_TONIC,
+};
 
 const MAX_CHEST_ITEMS: u8 = 5;
 
 #[derive(Clone, Copy)]
 struct LootEntry {
-    item: ItemKey,
+    item: u8,
     min_qty: u16,
     max_qty: u16,
 }
 
 const LOOT_ENTRIES: [LootEntry; 7] = [
     LootEntry {
-        item: ItemKey::PouchGold,
+        item: ITEM_POUCH_GOLD,
         min_qty: 1,
         max_qty: 3,
     },
     LootEntry {
-        item: ItemKey::StressTonic,
+        item: ITEM_STRESS_TONIC,
         min_qty: 1,
         max_qty: 2,
     },
     LootEntry {
-        item: ItemKey::MinorTorch,
+        item: ITEM_MINOR_TORCH,
         min_qty: 1,
         max_qty: 2,
     },
     LootEntry {
-        item: ItemKey::HealingSalve,
+        item: ITEM_HEALING_SALVE,
         min_qty: 1,
         max_qty: 1,
     },
     LootEntry {
-        item: ItemKey::MysteryRelic,
+        item: ITEM_MYSTERY_RELIC,
         min_qty: 1,
         max_qty: 1,
     },
     LootEntry {
-        item: ItemKey::CalmingIncense,
+        item: ITEM_CALMING_INCENSE,
         min_qty: 1,
         max_qty: 2,
     },
     LootEntry {
-        item: ItemKey::PhoenixFeather,
+        item: ITEM_PHOENIX_FEATHER,
         min_qty: 1,
         max_qty: 1,
     },
@@ -118,7 +122,10 @@ pub fn open_chest(ctx: Context<OpenChest>, chest_index: u8) -> Result<()> {
 
         let item_index = sample_loot_item(&mut rng, effective_luck);
         let entry = &LOOT_ENTRIES[item_index];
-        let definition = entry.item.definition();
+        // Items retired from the registry simply stop dropping.
+        let Some(definition) = ctx.accounts.item_registry.get(entry.item).copied() else {
+            continue;
+        };
 
         let quantity = if entry.min_qty == entry.max_qty {
             entry.min_qty
@@ -129,9 +136,9 @@ pub fn open_chest(ctx: Context<OpenChest>, chest_index: u8) -> Result<()> {
         push_loot_slot(
             &mut loot_slots,
             &mut slot_count,
-            entry.item as u8,
+            entry.item,
             quantity,
-            definition,
+            &definition,
         );
     }
 
@@ -155,6 +162,11 @@ pub fn open_chest(ctx: Context<OpenChest>, chest_index: u8) -> Result<()> {
         chest_index
     };
 
+    if rng.next_f32() < CHEST_KEY_CHANCE && adventure.keys < MAX_KEYS {
+        adventure.keys += 1;
+        msg!("Found a key in chest {}", chest_index);
+    }
+
     if let Some(flag) = adventure.opened_chests.get_mut(idx) {
         *flag = 1;
     }
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::AdventureError;
+use crate::state::{
+    AdventureCombat, AdventureSession, DungeonPoint, DungeonRoom, EncounterKind, HeroSnapshot,
+    ItemSlot, StatusInstance,
+};
+use crate::MigrateAdventure;
+
+/// Grow a session written before traps, doors, curios and the later run fields were appended,
+/// at the caller's expense. The appended fields start empty, exactly as after a fresh reset.
+/// The session's combat account is created, or rebuilt from the old layout, at the same time.
+pub fn migrate_adventure(ctx: Context<MigrateAdventure>) -> Result<()> {
+    let info = ctx.accounts.adventure.to_account_info();
+    let payer = ctx.accounts.payer.key();
+
+    require_keys_eq!(*info.owner, crate::ID, AdventureError::Unauthorized);
+
+    let (player, dungeon_mint, bump, width, height, delegate, legacy_len) = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *AdventureSession::DISCRIMINATOR,
+            AdventureError::NotLegacyAdventure
+        );
+        let mut cursor = &data[8..];
+        let player = Pubkey::deserialize(&mut cursor)?;
+        let dungeon_mint = Pubkey::deserialize(&mut cursor)?;
+        let bump = u8::deserialize(&mut cursor)?;
+        let _seed = u32::deserialize(&mut cursor)?;
+        let width = u16::deserialize(&mut cursor)?;
+        let height = u16::deserialize(&mut cursor)?;
+        require!(
+            data.len() < AdventureSession::space(width, height),
+            AdventureError::NotLegacyAdventure
+        );
+        let (delegate, legacy_len) = legacy_tail(&data)?;
+        (
+            player,
+            dungeon_mint,
+            bump,
+            width,
+            height,
+            delegate,
+            legacy_len,
+        )
+    };
+
+    let expected = Pubkey::create_program_address(
+        &[
+            ADVENTURE_SEED,
+            player.as_ref(),
+            dungeon_mint.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(AdventureError::InvalidAdventureAccount))?;
+    require_keys_eq!(
+        expected,
+        info.key(),
+        AdventureError::InvalidAdventureAccount
+    );
+    let is_authorized = payer == player || delegate == Some(payer);
+    require!(is_authorized, AdventureError::Unauthorized);
+
+    let space = AdventureSession::space(width, height);
+    let rent = Rent::get()?.minimum_balance(space);
+    let shortfall = rent.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: info.clone(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, shortfall)?;
+    }
+
+    // Anything past the legacy fields is leftover from earlier, longer writes
+    info.try_borrow_mut_data()?[legacy_len..].fill(0);
+    info.resize(space)?;
+
+    let mut adventure = AdventureSession::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    adventure.traps = Vec::new();
+    adventure.curios = Vec::new();
+    adventure.trap_states = Vec::new();
+    adventure.door_locks = Vec::new();
+    adventure.opened_doors = Vec::new();
+    adventure.curio_kinds = Vec::new();
+    adventure.used_curios = Vec::new();
+    adventure.lingering_statuses = [StatusInstance::default(); MAX_PARTY];
+    adventure.keys = 0;
+    adventure.boss_room = BOSS_ROOM_NONE;
+    adventure.boss_cleared = false;
+    adventure.pending_encounter_kind = EncounterKind::Standard;
+    adventure.blessing_combats = 0;
+    adventure.camps_used = 0;
+    adventure.hero_resolve = [RESOLVE_NONE; MAX_PARTY];
+
+    let legacy_seed = migrate_combat(&ctx)?;
+
+    // A fight in the old layout can't be read back, so it starts over from its encounter seed
+    if adventure.in_combat {
+        let clock = Clock::get()?;
+        let fallback = (adventure.seed as u64) ^ (clock.slot << 16) ^ 1;
+        adventure.in_combat = false;
+        adventure.combat_account = Pubkey::default();
+        adventure.pending_encounter_seed =
+            legacy_seed.filter(|seed| *seed != 0).unwrap_or(fallback);
+    }
+    adventure.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    msg!("Adventure {} migrated", info.key());
+
+    Ok(())
+}
+
+/// Give the session a current-layout combat account, returning the encounter seed of a fight
+/// left in an old-layout one.
+fn migrate_combat(ctx: &Context<MigrateAdventure>) -> Result<Option<u64>> {
+    let combat = ctx.accounts.combat.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system = ctx.accounts.system_program.to_account_info();
+    let adventure_key = ctx.accounts.adventure.key();
+    let bump = ctx.bumps.combat;
+    let signer_seeds: &[&[&[u8]]] = &[&[COMBAT_SEED, adventure_key.as_ref(), &[bump]]];
+
+    let rent = Rent::get()?.minimum_balance(AdventureCombat::LEN);
+    let mut legacy_seed = None;
+    if *combat.owner == crate::ID {
+        if combat.data_len() >= AdventureCombat::LEN {
+            return Ok(None);
+        }
+        // `loot_seed` and `last_updated` closed out the old layout
+        {
+            let data = combat.try_borrow_data()?;
+            let tail = data
+                .len()
+                .checked_sub(16)
+                .ok_or(AdventureError::InvalidCombatAccount)?;
+            legacy_seed = Some(u64::deserialize(&mut &data[tail..])?);
+        }
+        let shortfall = rent.saturating_sub(combat.lamports());
+        if shortfall > 0 {
+            let cpi_accounts = Transfer {
+                from: payer.clone(),
+                to: combat.clone(),
+            };
+            system_program::transfer(CpiContext::new(system.clone(), cpi_accounts), shortfall)?;
+        }
+        combat.resize(AdventureCombat::LEN)?;
+    } else {
+        require_keys_eq!(
+            *combat.owner,
+            system_program::ID,
+            AdventureError::InvalidCombatAccount
+        );
+        if combat.lamports() == 0 {
+            let cpi_accounts = CreateAccount {
+                from: payer,
+                to: combat.clone(),
+            };
+            system_program::create_account(
+                CpiContext::new_with_signer(system, cpi_accounts, signer_seeds),
+                rent,
+                AdventureCombat::LEN as u64,
+                &crate::ID,
+            )?;
+        } else {
+            // Someone pre-funded the address, so top it up and claim it in place
+            let shortfall = rent.saturating_sub(combat.lamports());
+            if shortfall > 0 {
+                let cpi_accounts = Transfer {
+                    from: payer,
+                    to: combat.clone(),
+                };
+                system_program::transfer(CpiContext::new(system.clone(), cpi_accounts), shortfall)?;
+            }
+            let cpi_accounts = Allocate {
+                account_to_allocate: combat.clone(),
+            };
+            system_program::allocate(
+                CpiContext::new_with_signer(system.clone(), cpi_accounts, signer_seeds),
+                AdventureCombat::LEN as u64,
+            )?;
+            let cpi_accounts = Assign {
+                account_to_assign: combat.clone(),
+            };
+            system_program::assign(
+                CpiContext::new_with_signer(system, cpi_accounts, signer_seeds),
+                &crate::ID,
+            )?;
+        }
+    }
+
+    // An all-zero body is an idle combat; only the header needs filling in
+    let mut data = combat.try_borrow_mut_data()?;
+    data.fill(0);
+    data[..8].copy_from_slice(AdventureCombat::DISCRIMINATOR);
+    let mut fresh = AdventureCombat::try_deserialize(&mut &data[..])?;
+    fresh.adventure = adventure_key;
+    fresh.bump = bump;
+    fresh.try_serialize(&mut &mut data[..])?;
+
+    Ok(legacy_seed)
+}
+
+/// Walk the legacy layout, returning the stored delegate and where the legacy fields end.
+fn legacy_tail(data: &[u8]) -> Result<(Option<Pubkey>, usize)> {
+    let head = 8
+        + 32
+        + 32
+        + 1
+        + 4
+        + 2
+        + 2
+        + 1
+        + 1
+        + 1
+        + (32 * MAX_PARTY)
+        + (HeroSnapshot::SIZE * MAX_PARTY)
+        + DungeonPoint::SIZE
+        + 1
+        + (ItemSlot::SIZE * MAX_ITEMS)
+        + 1
+        + 1
+        + (ItemSlot::SIZE * MAX_ITEMS);
+    let mut cursor = data.get(head..).ok_or(AdventureError::NotLegacyAdventure)?;
+    let delegate = Option::<Pubkey>::deserialize(&mut cursor)?;
+
+    // grid, rooms, doors, chests, portals, opened_chests, used_portals
+    let element_sizes = [
+        1,
+        DungeonRoom::SIZE,
+        DungeonPoint::SIZE,
+        DungeonPoint::SIZE,
+        DungeonPoint::SIZE,
+        1,
+        1,
+    ];
+    for size in element_sizes {
+        let len = u32::deserialize(&mut cursor)? as usize;
+        cursor = cursor
+            .get(len * size..)
+            .ok_or(AdventureError::NotLegacyAdventure)?;
+    }
+
+    // last_exit_portal through pending_encounter_seed
+    let tail = 1 + DungeonPoint::SIZE + (8 * 4) + 1 + (32 * MAX_PARTY) + 1 + 1 + 32 + 8;
+    let end = data.len() - cursor.len() + tail;
+    require!(end <= data.len(), AdventureError::NotLegacyAdventure);
+
+    Ok((delegate, end))
+}
@@ -1,15 +1,25 @@
+pub mod camp;
 pub mod combat;
+pub mod curios;
 pub mod delegate;
+pub mod doors;
 pub mod exit;
 pub mod items;
 pub mod loot;
+pub mod migrate;
 pub mod movement;
 pub mod start;
 pub mod support;
+pub mod traps;
+pub use camp::camp;
 pub use combat::{begin_encounter, conclude_combat, submit_combat_action, CombatInstruction};
+pub use curios::interact;
 pub use delegate::delegate_adventure;
+pub use doors::open_door;
 pub use exit::exit_adventure;
 pub use items::{drop_item, pickup_item, swap_item, use_item};
 pub use loot::open_chest;
+pub use migrate::migrate_adventure;
 pub use movement::move_hero;
 pub use start::start_adventure;
+pub use traps::disarm_trap;
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::{
-    ENCOUNTER_BASE_BPS, ENCOUNTER_MAX_TORCH, ENCOUNTER_MIN_TORCH, ENCOUNTER_TORCH_SLOPE_BPS,
+    BOSS_ROOM_NONE, ENCOUNTER_BASE_BPS, ENCOUNTER_MAX_TORCH, ENCOUNTER_MIN_TORCH,
+    ENCOUNTER_TORCH_SLOPE_BPS,
 };
 use crate::errors::AdventureError;
-use crate::logic::{is_floor, Mulberry32};
-use crate::state::{AdventureSession, DungeonPoint};
+use crate::instructions::doors::is_passable;
+use crate::instructions::traps::{step_onto_tile, TrapStep};
+use crate::logic::{is_walkable, Mulberry32};
+use crate::state::{AdventureSession, DungeonPoint, EncounterKind};
 use crate::{Direction, MoveHero};
 
 impl Direction {
@@ -38,6 +41,10 @@ pub fn move_hero(ctx: Context<MoveHero>, direction: Direction) -> Result<()> {
         !adventure.in_combat,
         AdventureError::MovementBlockedInCombat
     );
+    require!(
+        !adventure.pending_encounter_kind.is_forced(),
+        AdventureError::ForcedEncounterPending
+    );
 
     let (dx, dy) = direction.delta();
     let current = adventure.party_position;
@@ -57,23 +64,64 @@ pub fn move_hero(ctx: Context<MoveHero>, direction: Direction) -> Result<()> {
     let next_y_u16 = next_y as u16;
 
     require!(
-        is_floor(&adventure.grid, adventure.width, next_x_u16, next_y_u16),
+        is_walkable(&adventure.grid, adventure.width, next_x_u16, next_y_u16),
         AdventureError::MovementIntoWall
     );
 
-    adventure.party_position = DungeonPoint {
+    let next = DungeonPoint {
         x: next_x_u16,
         y: next_y_u16,
     };
 
+    require!(is_passable(adventure, next), AdventureError::DoorClosed);
+
     // Decrement torch by 1 on each move, but don't go below 0
     adventure.torch = adventure.torch.saturating_sub(1);
 
+    // A spotted trap stops the party in front of it
+    if let TrapStep::Spotted = step_onto_tile(adventure, next)? {
+        return Ok(());
+    }
+
+    adventure.party_position = next;
+
+    if in_uncleared_boss_room(adventure) {
+        queue_boss_encounter(adventure)?;
+        return Ok(());
+    }
+
     maybe_trigger_encounter(adventure)?;
 
     Ok(())
 }
 
+fn in_uncleared_boss_room(adventure: &AdventureSession) -> bool {
+    if adventure.boss_cleared || adventure.boss_room == BOSS_ROOM_NONE {
+        return false;
+    }
+    adventure
+        .rooms
+        .get(adventure.boss_room as usize)
+        .map(|room| room.contains(adventure.party_position))
+        .unwrap_or(false)
+}
+
+/// Stepping into the boss lair always starts a fight; the party can't walk or decline away.
+fn queue_boss_encounter(adventure: &mut AdventureSession) -> Result<()> {
+    let clock = Clock::get()?;
+    let mut encounter_seed = (adventure.seed as u64)
+        ^ (clock.slot << 16)
+        ^ ((clock.unix_timestamp as u64) << 1)
+        ^ ((adventure.boss_room as u64) << 48);
+    if encounter_seed == 0 {
+        encounter_seed = 1;
+    }
+    adventure.pending_encounter_seed = encounter_seed;
+    adventure.pending_encounter_kind = EncounterKind::Boss;
+    msg!("The party enters the boss lair");
+    Ok(())
+}
+
 fn maybe_trigger_encounter(adventure: &mut AdventureSession) -> Result<()> {
     let clock = Clock::get()?;
     let torch = adventure.torch;
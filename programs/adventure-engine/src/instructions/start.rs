@@ -8,7 +8,9 @@ use hero_core::cpi::accounts::LockCtx;
 
 use crate::errors::AdventureError;
 use crate::logic::{generate_adventure, is_floor};
-use crate::state::{DungeonPoint, HeroAdventureLock, HeroSnapshot, ItemSlot};
+use crate::state::{
+    DungeonPoint, EncounterKind, HeroAdventureLock, HeroSnapshot, ItemSlot, StatusInstance,
+};
 use crate::{constants::*, ItemInput, StartAdventure};
 
 pub fn start_adventure<'info>(
@@ -195,14 +197,15 @@ pub fn start_adventure<'info>(
 
     // Build unique item map and validate quantities
     for item in items.iter() {
-        require!(item.item_key < 7, AdventureError::InvalidItemKey); // 7 item types in player-economy
+        let definition = ctx
+            .accounts
+            .item_registry
+            .get(item.item_key)
+            .ok_or(AdventureError::InvalidItemKey)?;
         require!(item.quantity > 0, AdventureError::InvalidItemQuantity);
 
         // Prevent bringing loot-only items to adventures
-        require!(
-            item.item_key != ITEM_POUCH_GOLD && item.item_key != ITEM_MYSTERY_RELIC,
-            AdventureError::InvalidItemKey
-        );
+        require!(!definition.loot_only, AdventureError::InvalidItemKey);
 
         if let Some(slot) = item_array
             .iter_mut()
@@ -229,27 +232,16 @@ pub fn start_adventure<'info>(
     if !items.is_empty() {
         let consumptions: Vec<player_economy::ItemConsumption> = items
             .iter()
-            .map(|input| {
-                let item_key = match input.item_key {
-                    0 => player_economy::ItemKey::PouchGold,
-                    1 => player_economy::ItemKey::StressTonic,
-                    2 => player_economy::ItemKey::MinorTorch,
-                    3 => player_economy::ItemKey::HealingSalve,
-                    4 => player_economy::ItemKey::MysteryRelic,
-                    5 => player_economy::ItemKey::CalmingIncense,
-                    6 => player_economy::ItemKey::PhoenixFeather,
-                    _ => unreachable!(),
-                };
-                player_economy::ItemConsumption {
-                    item: item_key,
-                    quantity: input.quantity,
-                }
+            .map(|input| player_economy::ItemConsumption {
+                item: input.item_key,
+                quantity: input.quantity,
             })
             .collect();
 
         let cpi_accounts = player_economy::cpi::accounts::ConsumeItems {
             authority: ctx.accounts.player.to_account_info(),
             player_economy: ctx.accounts.player_economy.to_account_info(),
+            economy_stats: ctx.accounts.economy_stats.to_account_info(),
         };
         let cpi_program = ctx.accounts.player_economy_program.to_account_info();
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
@@ -268,19 +260,36 @@ pub fn start_adventure<'info>(
 
         let previous_opened = mem::take(&mut adventure.opened_chests);
         let previous_used = mem::take(&mut adventure.used_portals);
+        let previous_traps = mem::take(&mut adventure.trap_states);
+        let previous_doors = mem::take(&mut adventure.opened_doors);
+        let previous_curios = mem::take(&mut adventure.used_curios);
 
         adventure.grid = generated.grid;
         adventure.rooms = generated.rooms;
         adventure.doors = generated.doors;
+        adventure.door_locks = generated.door_locks;
         adventure.chests = generated.chests;
         adventure.portals = generated.portals;
+        adventure.traps = generated.traps;
+        adventure.curios = generated.curios;
+        adventure.curio_kinds = generated.curio_kinds;
+        adventure.boss_room = generated.boss_room;
 
         let chests_len = adventure.chests.len();
         let portals_len = adventure.portals.len();
+        let traps_len = adventure.traps.len();
+        let doors_len = adventure.doors.len();
+        let curios_len = adventure.curios.len();
         adventure.opened_chests = previous_opened;
         ensure_u8_vector_length(&mut adventure.opened_chests, chests_len);
         adventure.used_portals = previous_used;
         ensure_u8_vector_length(&mut adventure.used_portals, portals_len);
+        adventure.trap_states = previous_traps;
+        ensure_u8_vector_length(&mut adventure.trap_states, traps_len);
+        adventure.opened_doors = previous_doors;
+        ensure_u8_vector_length(&mut adventure.opened_doors, doors_len);
+        adventure.used_curios = previous_curios;
+        ensure_u8_vector_length(&mut adventure.used_curios, curios_len);
 
         adventure.seed = dungeon_seed;
         adventure.width = dungeon_grid_width;
@@ -289,6 +298,10 @@ pub fn start_adventure<'info>(
         if should_reset {
             adventure.opened_chests.fill(0);
             adventure.used_portals.fill(0);
+            adventure.trap_states.fill(0);
+            adventure.opened_doors.fill(0);
+            adventure.used_curios.fill(0);
+            adventure.boss_cleared = false;
             adventure.last_exit_portal = PORTAL_NONE;
             adventure.last_exit_position = adventure
                 .rooms
@@ -353,6 +366,21 @@ pub fn start_adventure<'info>(
         adventure.in_combat = false;
         adventure.combat_account = Pubkey::default();
         adventure.pending_encounter_seed = 0;
+        adventure.pending_encounter_kind = EncounterKind::Standard;
+        adventure
+            .lingering_statuses
+            .iter_mut()
+            .for_each(StatusInstance::clear);
+        adventure.keys = 0;
+        adventure.blessing_combats = 0;
+        adventure.camps_used = 0;
+        adventure.hero_resolve = [RESOLVE_NONE; MAX_PARTY];
+    }
+
+    let combat = &mut ctx.accounts.combat;
+    if combat.adventure == Pubkey::default() {
+        combat.adventure = adventure_key;
+        combat.bump = ctx.bumps.combat;
     }
 
     Ok(())
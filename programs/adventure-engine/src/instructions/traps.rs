@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::AdventureError;
+use crate::logic::{is_trap_tile, tile_index, Mulberry32};
+use crate::state::{AdventureSession, DungeonPoint, HeroSnapshot, StatusEffect, StatusInstance};
+use crate::DisarmTrap;
+
+/// Outcome of walking onto a tile that may hold a trap.
+pub enum TrapStep {
+    Clear,
+    Spotted,
+    Triggered,
+}
+
+/// Resolve a trap on the destination tile before the party moves onto it.
+/// A hidden trap gets a detection roll: on success it is revealed and the party halts in front
+/// of it, otherwise it fires. Revealed traps always fire when stepped on.
+pub fn step_onto_tile(adventure: &mut AdventureSession, point: DungeonPoint) -> Result<TrapStep> {
+    let Some(trap_index) = armed_trap_at(adventure, point) else {
+        return Ok(TrapStep::Clear);
+    };
+
+    let mut rng = trap_rng(adventure, trap_index)?;
+    let revealed = adventure.trap_states[trap_index] & TRAP_REVEALED != 0;
+
+    if !revealed {
+        let tile = trap_tile(adventure, trap_index);
+        let leader = adventure.hero_snapshots[0];
+        let chance = detection_chance_bps(tile, &leader, adventure.torch);
+        if rng.next_u32() % 10_000 < chance {
+            adventure.trap_states[trap_index] |= TRAP_REVEALED;
+            msg!("Trap {} spotted at ({}, {})", trap_index, point.x, point.y);
+            return Ok(TrapStep::Spotted);
+        }
+    }
+
+    trigger_trap(adventure, trap_index, &mut rng);
+    Ok(TrapStep::Triggered)
+}
+
+/// Attempt to disarm a revealed trap next to the party. A failed attempt sets it off.
+pub fn disarm_trap(ctx: Context<DisarmTrap>, trap_index: u8) -> Result<()> {
+    let adventure = &mut ctx.accounts.adventure;
+    let owner = ctx.accounts.owner.key();
+    let authority = ctx.accounts.authority.key();
+
+    let is_authorized = authority == owner || adventure.delegate == Some(authority);
+    require!(is_authorized, AdventureError::Unauthorized);
+
+    require!(adventure.is_active, AdventureError::AdventureNotActive);
+    require!(adventure.heroes_inside, AdventureError::AdventureNotActive);
+    require!(!adventure.in_combat, AdventureError::BlockedInCombat);
+
+    let idx = trap_index as usize;
+    let trap_point = adventure
+        .traps
+        .get(idx)
+        .copied()
+        .ok_or(AdventureError::TrapNotFound)?;
+    let state = adventure
+        .trap_states
+        .get(idx)
+        .copied()
+        .ok_or(AdventureError::TrapNotFound)?;
+
+    require!(
+        state & (TRAP_DISARMED | TRAP_TRIGGERED) == 0,
+        AdventureError::TrapAlreadyResolved
+    );
+    require!(state & TRAP_REVEALED != 0, AdventureError::TrapNotRevealed);
+
+    let position = adventure.party_position;
+    let dx = (position.x as i32 - trap_point.x as i32).abs();
+    let dy = (position.y as i32 - trap_point.y as i32).abs();
+    require!(dx <= 1 && dy <= 1, AdventureError::TrapOutOfReach);
+
+    let mut rng = trap_rng(adventure, idx)?;
+    let tile = trap_tile(adventure, idx);
+    let leader = adventure.hero_snapshots[0];
+    let chance = disarm_chance_bps(tile, &leader);
+
+    if rng.next_u32() % 10_000 < chance {
+        adventure.trap_states[idx] |= TRAP_DISARMED;
+        msg!("Trap {} disarmed", trap_index);
+    } else {
+        msg!("Disarm failed, trap {} triggered", trap_index);
+        trigger_trap(adventure, idx, &mut rng);
+    }
+
+    Ok(())
+}
+
+fn armed_trap_at(adventure: &AdventureSession, point: DungeonPoint) -> Option<usize> {
+    let idx = adventure.traps.iter().position(|trap| *trap == point)?;
+    let state = adventure.trap_states.get(idx).copied().unwrap_or(0);
+    if state & (TRAP_DISARMED | TRAP_TRIGGERED) != 0 {
+        return None;
+    }
+    if !is_trap_tile(trap_tile(adventure, idx)) {
+        return None;
+    }
+    Some(idx)
+}
+
+fn trap_tile(adventure: &AdventureSession, trap_index: usize) -> u8 {
+    let point = adventure.traps[trap_index];
+    let idx = tile_index(adventure.width, point.x, point.y);
+    adventure.grid.get(idx).copied().unwrap_or(TILE_FLOOR)
+}
+
+fn trap_rng(adventure: &AdventureSession, trap_index: usize) -> Result<Mulberry32> {
+    let clock = Clock::get()?;
+    let mut seed = adventure.seed
+        ^ ((trap_index as u32) << 20)
+        ^ (clock.slot as u32)
+        ^ (clock.unix_timestamp as u32).rotate_left(11)
+        ^ ((adventure.party_position.x as u32) << 8)
+        ^ (adventure.party_position.y as u32);
+    if seed == 0 {
+        seed = adventure.seed.wrapping_add(1);
+    }
+    Ok(Mulberry32::new(seed))
+}
+
+fn detection_chance_bps(tile: u8, leader: &HeroSnapshot, torch: u8) -> u32 {
+    let base: u32 = match tile {
+        TILE_SPIKE_TRAP => 2_500,
+        TILE_POISON_GAS => 2_000,
+        TILE_COLLAPSING_FLOOR => 1_500,
+        TILE_PRESSURE_PLATE => 800,
+        _ => 0,
+    };
+    let chance = base + leader.luck as u32 * 30 + leader.speed as u32 * 15 + torch as u32 * 10;
+    chance.min(9_000)
+}
+
+fn disarm_chance_bps(tile: u8, leader: &HeroSnapshot) -> u32 {
+    let base: u32 = match tile {
+        TILE_SPIKE_TRAP => 5_000,
+        TILE_POISON_GAS => 4_000,
+        TILE_COLLAPSING_FLOOR => 3_500,
+        TILE_PRESSURE_PLATE => 3_000,
+        _ => 0,
+    };
+    let chance = base + leader.luck as u32 * 35 + leader.speed as u32 * 10;
+    chance.clamp(1_000, 9_500)
+}
+
+fn trigger_trap(adventure: &mut AdventureSession, trap_index: usize, rng: &mut Mulberry32) {
+    let tile = trap_tile(adventure, trap_index);
+    adventure.trap_states[trap_index] |= TRAP_REVEALED | TRAP_TRIGGERED;
+
+    let hero_count = (adventure.hero_count as usize).min(MAX_PARTY);
+    match tile {
+        TILE_SPIKE_TRAP => {
+            let mut alive = [0usize; MAX_PARTY];
+            let mut count = 0;
+            for idx in 0..hero_count {
+                if adventure.hero_snapshots[idx].current_hp > 0 {
+                    alive[count] = idx;
+                    count += 1;
+                }
+            }
+            if count > 0 {
+                let victim = alive[(rng.next_u32() as usize) % count];
+                damage_hero(&mut adventure.hero_snapshots[victim], SPIKE_TRAP_DAMAGE);
+            }
+        }
+        TILE_POISON_GAS => {
+            for idx in 0..hero_count {
+                if adventure.hero_snapshots[idx].current_hp == 0 {
+                    continue;
+                }
+                let lingering = &mut adventure.lingering_statuses[idx];
+                if lingering.effect == StatusEffect::Poison && !lingering.is_empty() {
+                    lingering.stacks = lingering.stacks.saturating_add(1);
+                    lingering.duration = lingering.duration.max(POISON_GAS_DURATION);
+                } else {
+                    *lingering = StatusInstance {
+                        effect: StatusEffect::Poison,
+                        duration: POISON_GAS_DURATION,
+                        stacks: 1,
+                    };
+                }
+            }
+        }
+        TILE_COLLAPSING_FLOOR => {
+            for hero in adventure.hero_snapshots.iter_mut().take(hero_count) {
+                damage_hero(hero, COLLAPSE_TRAP_DAMAGE);
+            }
+            adventure.torch = adventure.torch.saturating_sub(COLLAPSE_TRAP_TORCH_BURN);
+        }
+        TILE_PRESSURE_PLATE => {
+            for hero in adventure.hero_snapshots.iter_mut().take(hero_count) {
+                if hero.current_hp == 0 {
+                    continue;
+                }
+                hero.stress = hero
+                    .stress
+                    .saturating_add(PRESSURE_PLATE_STRESS)
+                    .min(hero.stress_max);
+            }
+            adventure.torch = adventure.torch.saturating_sub(PRESSURE_PLATE_TORCH_BURN);
+        }
+        _ => {}
+    }
+
+    msg!("Trap {} triggered (tile={})", trap_index, tile);
+}
+
+/// Traps wound but never finish off a hero.
+fn damage_hero(hero: &mut HeroSnapshot, amount: u8) {
+    if hero.current_hp == 0 {
+        return;
+    }
+    hero.current_hp = hero.current_hp.saturating_sub(amount).max(1);
+}
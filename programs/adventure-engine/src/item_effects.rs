@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use player_economy::constants::{
+    ITEM_EFFECT_CALM, ITEM_EFFECT_HEAL, ITEM_EFFECT_RELIEVE_STRESS, ITEM_EFFECT_RESTORE_TORCH,
+    ITEM_EFFECT_REVIVE,
+};
+use player_economy::state::ItemDefinition;
+
+use crate::errors::AdventureError;
+use crate::state::{HeroCombatant, HeroSnapshot, StatusEffect, StatusInstance};
+
+/// What using an item does. Combat and exploration both resolve items through this table so
+/// the two paths stay in step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemEffect {
+    None,
+    RestoreTorch(u8),
+    Heal(u16),
+    RelieveStress(u16),
+    /// Relieve stress and cleanse one negative status.
+    Calm(u16),
+    /// Bring a fallen hero back at half health.
+    Revive,
+}
+
+impl ItemEffect {
+    /// Effects that act on a single hero rather than on the party's torch.
+    pub fn targets_hero(self) -> bool {
+        !matches!(self, ItemEffect::None | ItemEffect::RestoreTorch(_))
+    }
+}
+
+/// Resolve the effect id and strength an item's registry definition carries.
+pub fn item_effect(definition: &ItemDefinition) -> ItemEffect {
+    let value = definition.effect_value;
+    match definition.effect_id {
+        ITEM_EFFECT_RESTORE_TORCH => ItemEffect::RestoreTorch(value.min(u8::MAX as u16) as u8),
+        ITEM_EFFECT_HEAL => ItemEffect::Heal(value),
+        ITEM_EFFECT_RELIEVE_STRESS => ItemEffect::RelieveStress(value),
+        ITEM_EFFECT_CALM => ItemEffect::Calm(value),
+        ITEM_EFFECT_REVIVE => ItemEffect::Revive,
+        _ => ItemEffect::None,
+    }
+}
+
+/// Check a hero-targeted item may be used between fights on a hero that is or isn't `alive`.
+/// Revives are the one effect meant for a fallen hero, and only their first unit takes effect.
+pub fn check_hero_item_use(effect: ItemEffect, quantity: u16, alive: bool) -> Result<()> {
+    if effect == ItemEffect::Revive {
+        require!(!alive, AdventureError::HeroAlreadyAlive);
+        require!(quantity == 1, AdventureError::InvalidItemQuantity);
+    } else {
+        require!(alive, AdventureError::HeroNotAlive);
+    }
+    Ok(())
+}
+
+/// A hero an item can be used on, either mid-fight or between fights.
+pub trait ItemTarget {
+    fn is_alive(&self) -> bool;
+    fn restore_hp(&mut self, amount: u16);
+    fn relieve_stress(&mut self, amount: u16);
+    fn cleanse_negative_status(&mut self);
+    fn revive_at_half(&mut self);
+}
+
+/// Apply a hero-targeted effect. Returns true when a fallen hero was revived so callers can
+/// restore them to turn order. Torch effects act on the session and are ignored here.
+pub fn apply_item_effect<T: ItemTarget>(target: &mut T, effect: ItemEffect) -> bool {
+    match effect {
+        ItemEffect::Heal(amount) => {
+            if target.is_alive() {
+                target.restore_hp(amount);
+            }
+            false
+        }
+        ItemEffect::RelieveStress(amount) => {
+            target.relieve_stress(amount);
+            false
+        }
+        ItemEffect::Calm(amount) => {
+            target.relieve_stress(amount);
+            target.cleanse_negative_status();
+            false
+        }
+        ItemEffect::Revive => {
+            if target.is_alive() {
+                return false;
+            }
+            target.revive_at_half();
+            true
+        }
+        ItemEffect::RestoreTorch(_) | ItemEffect::None => false,
+    }
+}
+
+pub fn is_negative_status(effect: StatusEffect) -> bool {
+    matches!(
+        effect,
+        StatusEffect::Poison
+            | StatusEffect::Bleed
+            | StatusEffect::Burn
+            | StatusEffect::Chill
+            | StatusEffect::Stun
+            | StatusEffect::Mark
+            | StatusEffect::Blind
+            | StatusEffect::Vulnerable
+    )
+}
+
+impl ItemTarget for HeroCombatant {
+    fn is_alive(&self) -> bool {
+        self.alive
+    }
+
+    fn restore_hp(&mut self, amount: u16) {
+        self.hp = self.hp.saturating_add(amount).min(self.max_hp);
+    }
+
+    fn relieve_stress(&mut self, amount: u16) {
+        self.stress = self.stress.saturating_sub(amount);
+    }
+
+    fn cleanse_negative_status(&mut self) {
+        if let Some(status) = self
+            .statuses
+            .iter_mut()
+            .find(|status| !status.is_empty() && is_negative_status(status.effect))
+        {
+            status.clear();
+        }
+    }
+
+    fn revive_at_half(&mut self) {
+        self.hp = (self.max_hp / 2).max(1);
+        self.alive = true;
+    }
+}
+
+/// A hero between fights: its snapshot plus any status it is carrying into the next combat.
+pub struct ExploringHero<'a> {
+    pub hero: &'a mut HeroSnapshot,
+    pub lingering: &'a mut StatusInstance,
+}
+
+impl ItemTarget for ExploringHero<'_> {
+    fn is_alive(&self) -> bool {
+        self.hero.current_hp > 0
+    }
+
+    fn restore_hp(&mut self, amount: u16) {
+        let hp = (self.hero.current_hp as u16).saturating_add(amount);
+        self.hero.current_hp = hp.min(self.hero.max_hp as u16) as u8;
+    }
+
+    fn relieve_stress(&mut self, amount: u16) {
+        self.hero.stress = self.hero.stress.saturating_sub(amount);
+    }
+
+    fn cleanse_negative_status(&mut self) {
+        if !self.lingering.is_empty() && is_negative_status(self.lingering.effect) {
+            self.lingering.clear();
+        }
+    }
+
+    fn revive_at_half(&mut self) {
+        self.hero.current_hp = (self.hero.max_hp / 2).max(1);
+    }
+}
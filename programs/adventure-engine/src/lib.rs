@@ -12,6 +12,7 @@ pub mod combat;
 pub mod constants;
 pub mod errors;
 pub mod instructions;
+pub mod item_effects;
 pub mod logic;
 pub mod state;
 
@@ -29,6 +30,13 @@ pub enum Direction {
     NorthWest,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DoorOpenMethod {
+    Hand,
+    Key,
+    Lockpick,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ItemInput {
     pub item_key: u8,
@@ -51,6 +59,15 @@ pub struct StartAdventure<'info> {
         bump
     )]
     pub adventure: Account<'info, AdventureSession>,
+    /// Allocated once with the session and reused by every encounter
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = AdventureCombat::LEN,
+        seeds = [COMBAT_SEED, adventure.key().as_ref()],
+        bump
+    )]
+    pub combat: Account<'info, AdventureCombat>,
     #[account(
         mut,
         seeds = [player_economy::PLAYER_ECONOMY_SEED, player.key().as_ref()],
@@ -58,6 +75,19 @@ pub struct StartAdventure<'info> {
         seeds::program = player_economy_program.key()
     )]
     pub player_economy: Account<'info, player_economy::PlayerEconomy>,
+    #[account(
+        seeds = [player_economy::ITEM_REGISTRY_SEED],
+        bump = item_registry.bump,
+        seeds::program = player_economy::ID
+    )]
+    pub item_registry: Account<'info, player_economy::ItemRegistry>,
+    #[account(
+        mut,
+        seeds = [player_economy::ECONOMY_STATS_SEED, &[economy_stats.shard]],
+        bump = economy_stats.bump,
+        seeds::program = player_economy::ID
+    )]
+    pub economy_stats: Account<'info, player_economy::EconomyStats>,
     pub system_program: Program<'info, System>,
     /// CHECK: hero-core program for CPI calls
     pub hero_program: Program<'info, hero_core::program::HeroCore>,
@@ -94,6 +124,15 @@ pub struct DelegateAdventure<'info> {
     )]
     pub pda: AccountInfo<'info>,
 
+    /// CHECK: Combat PDA of the adventure, delegated alongside it (validated by seeds/bump)
+    #[account(
+        mut,
+        del,
+        seeds = [COMBAT_SEED, pda.key().as_ref()],
+        bump
+    )]
+    pub combat: AccountInfo<'info>,
+
     /// CHECK: Only used to derive seeds
     pub owner: UncheckedAccount<'info>,
 
@@ -130,6 +169,13 @@ pub struct ExitAdventure<'info> {
         constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
     )]
     pub adventure: Account<'info, AdventureSession>,
+    /// Committed and undelegated with the adventure, so the next run can reuse it
+    #[account(
+        mut,
+        seeds = [COMBAT_SEED, adventure.key().as_ref()],
+        bump = combat.bump
+    )]
+    pub combat: Account<'info, AdventureCombat>,
     /// CHECK: hero-core program for CPI calls
     pub hero_program: Program<'info, hero_core::program::HeroCore>,
     #[account(
@@ -164,6 +210,12 @@ pub struct ManageItems<'info> {
         constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
     )]
     pub adventure: Account<'info, AdventureSession>,
+    #[account(
+        seeds = [player_economy::ITEM_REGISTRY_SEED],
+        bump = item_registry.bump,
+        seeds::program = player_economy::ID
+    )]
+    pub item_registry: Account<'info, player_economy::ItemRegistry>,
 }
 
 #[derive(Accounts)]
@@ -183,6 +235,86 @@ pub struct OpenChest<'info> {
         constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
     )]
     pub adventure: Account<'info, AdventureSession>,
+    #[account(
+        seeds = [player_economy::ITEM_REGISTRY_SEED],
+        bump = item_registry.bump,
+        seeds::program = player_economy::ID
+    )]
+    pub item_registry: Account<'info, player_economy::ItemRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct DisarmTrap<'info> {
+    /// CHECK: The owner of the adventure session (used for PDA derivation)
+    pub owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ADVENTURE_SEED, owner.key().as_ref(), adventure.dungeon_mint.as_ref()],
+        bump = adventure.bump,
+        constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
+    )]
+    pub adventure: Account<'info, AdventureSession>,
+}
+
+#[derive(Accounts)]
+pub struct OpenDoor<'info> {
+    /// CHECK: The owner of the adventure session (used for PDA derivation)
+    pub owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ADVENTURE_SEED, owner.key().as_ref(), adventure.dungeon_mint.as_ref()],
+        bump = adventure.bump,
+        constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
+    )]
+    pub adventure: Account<'info, AdventureSession>,
+}
+
+#[derive(Accounts)]
+pub struct Interact<'info> {
+    /// CHECK: The owner of the adventure session (used for PDA derivation)
+    pub owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ADVENTURE_SEED, owner.key().as_ref(), adventure.dungeon_mint.as_ref()],
+        bump = adventure.bump,
+        constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
+    )]
+    pub adventure: Account<'info, AdventureSession>,
+}
+
+#[derive(Accounts)]
+pub struct Camp<'info> {
+    /// CHECK: The owner of the adventure session (used for PDA derivation)
+    pub owner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ADVENTURE_SEED, owner.key().as_ref(), adventure.dungeon_mint.as_ref()],
+        bump = adventure.bump,
+        constraint = adventure.player == owner.key() @ AdventureError::AdventureOwnerMismatch
+    )]
+    pub adventure: Account<'info, AdventureSession>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAdventure<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Legacy sessions don't deserialize as `AdventureSession`; owner, discriminator and
+    /// PDA are checked in the handler
+    #[account(mut)]
+    pub adventure: UncheckedAccount<'info>,
+    /// CHECK: Combat PDA of the session; created, or rebuilt from the old layout, in the handler
+    #[account(mut, seeds = [COMBAT_SEED, adventure.key().as_ref()], bump)]
+    pub combat: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -199,14 +331,12 @@ pub struct BeginEncounter<'info> {
     )]
     pub adventure: Account<'info, AdventureSession>,
     #[account(
-        init,
-        payer = authority,
-        space = AdventureCombat::LEN,
+        mut,
         seeds = [COMBAT_SEED, adventure.key().as_ref()],
-        bump
+        bump = combat.bump,
+        constraint = combat.adventure == adventure.key() @ AdventureError::InvalidCombatAccount
     )]
     pub combat: Account<'info, AdventureCombat>,
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -230,12 +360,17 @@ pub struct SubmitCombatAction<'info> {
         constraint = combat.active @ AdventureError::CombatNotActive
     )]
     pub combat: Account<'info, AdventureCombat>,
+    #[account(
+        seeds = [player_economy::ITEM_REGISTRY_SEED],
+        bump = item_registry.bump,
+        seeds::program = player_economy::ID
+    )]
+    pub item_registry: Account<'info, player_economy::ItemRegistry>,
 }
 
 #[derive(Accounts)]
 pub struct ConcludeCombat<'info> {
     /// CHECK: Adventure owner; used for PDA derivation
-    #[account(mut)]
     pub owner: UncheckedAccount<'info>,
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -325,12 +460,32 @@ pub mod adventure_engine {
         crate::instructions::loot::open_chest(ctx, chest_index)
     }
 
+    pub fn disarm_trap(ctx: Context<DisarmTrap>, trap_index: u8) -> Result<()> {
+        crate::instructions::traps::disarm_trap(ctx, trap_index)
+    }
+
+    pub fn open_door(ctx: Context<OpenDoor>, door_index: u8, method: DoorOpenMethod) -> Result<()> {
+        crate::instructions::doors::open_door(ctx, door_index, method)
+    }
+
+    pub fn interact(ctx: Context<Interact>, curio_index: u8) -> Result<()> {
+        crate::instructions::curios::interact(ctx, curio_index)
+    }
+
+    pub fn camp(ctx: Context<Camp>) -> Result<()> {
+        crate::instructions::camp::camp(ctx)
+    }
+
     pub fn exit_adventure<'info>(
         ctx: Context<'_, '_, '_, 'info, ExitAdventure<'info>>,
     ) -> Result<()> {
         crate::instructions::exit::exit_adventure(ctx)
     }
 
+    pub fn migrate_adventure(ctx: Context<MigrateAdventure>) -> Result<()> {
+        crate::instructions::migrate::migrate_adventure(ctx)
+    }
+
     pub fn begin_encounter(ctx: Context<BeginEncounter>) -> Result<()> {
         crate::instructions::combat::begin_encounter(ctx)
     }
@@ -350,7 +505,12 @@ pub mod adventure_engine {
         crate::instructions::combat::decline_encounter(ctx)
     }
 
-    pub fn use_item(ctx: Context<ManageItems>, item_key: u8, quantity: u16) -> Result<()> {
-        crate::instructions::items::use_item(ctx, item_key, quantity)
+    pub fn use_item(
+        ctx: Context<ManageItems>,
+        item_key: u8,
+        quantity: u16,
+        hero_index: u8,
+    ) -> Result<()> {
+        crate::instructions::items::use_item(ctx, item_key, quantity, hero_index)
     }
 }
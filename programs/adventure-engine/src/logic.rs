@@ -5,8 +5,13 @@ pub struct GeneratedAdventure {
     pub grid: Vec<u8>,
     pub rooms: Vec<DungeonRoom>,
     pub doors: Vec<DungeonPoint>,
+    pub door_locks: Vec<u8>,
     pub chests: Vec<DungeonPoint>,
     pub portals: Vec<DungeonPoint>,
+    pub traps: Vec<DungeonPoint>,
+    pub curios: Vec<DungeonPoint>,
+    pub curio_kinds: Vec<u8>,
+    pub boss_room: u8,
 }
 
 #[derive(Clone, Copy)]
@@ -117,14 +122,38 @@ pub fn generate_adventure(seed: u32, width: u16, height: u16) -> GeneratedAdvent
             next_center.x as usize,
         );
 
-        if doors.len() < MAX_DOORS {
-            push_unique_point(&mut doors, prev_center);
-        }
-        if doors.len() < MAX_DOORS {
-            push_unique_point(&mut doors, next_center);
+        // Doors sit on the first corridor tile outside each room the corridor connects.
+        let path = corridor_path(prev_center, next_center);
+        let exit = path.iter().copied().find(|point| !prev.contains(*point));
+        let entry = path
+            .iter()
+            .rev()
+            .copied()
+            .find(|point| !next.contains(*point));
+        for door in [exit, entry].into_iter().flatten() {
+            if doors.len() >= MAX_DOORS {
+                break;
+            }
+            if rooms.iter().any(|room| room.contains(door)) {
+                continue;
+            }
+            push_unique_point(&mut doors, door);
         }
     }
 
+    // The boss lairs in the room farthest from spawn.
+    let spawn = rooms[0].center();
+    let boss_room = rooms
+        .iter()
+        .enumerate()
+        .skip(1)
+        .max_by_key(|(_, room)| {
+            let center = room.center();
+            center.x.abs_diff(spawn.x) as u32 + center.y.abs_diff(spawn.y) as u32
+        })
+        .map(|(idx, _)| idx as u8)
+        .unwrap_or(BOSS_ROOM_NONE);
+
     let mut chests: Vec<DungeonPoint> = Vec::with_capacity(MAX_CHESTS);
     for room in &rooms {
         if chests.len() >= MAX_CHESTS {
@@ -158,20 +187,123 @@ pub fn generate_adventure(seed: u32, width: u16, height: u16) -> GeneratedAdvent
 
     ensure_floor_tiles(&mut chests, &rooms);
 
+    // Traps are placed last so they never overlap chests, portals or room centers.
+    let mut traps: Vec<DungeonPoint> = Vec::with_capacity(MAX_TRAPS);
+    for room in rooms.iter().skip(1) {
+        if traps.len() >= MAX_TRAPS {
+            break;
+        }
+        if rng.next_f32() >= TRAP_SPAWN_CHANCE {
+            continue;
+        }
+        let Some(point) = sample_room_point(&mut rng, room) else {
+            continue;
+        };
+        let kind = TRAP_TILES[(rng.next_u32() as usize) % TRAP_TILES.len()];
+        let blocked = point == room.center()
+            || chests.contains(&point)
+            || portals.contains(&point)
+            || traps.contains(&point);
+        if blocked {
+            continue;
+        }
+        grid[point.y as usize * w + point.x as usize] = kind;
+        traps.push(point);
+    }
+
+    // Doors are stamped after every corridor is carved so later corridors can't overwrite them.
+    // Keys aren't guaranteed, so nothing between spawn and the nearest portal is ever sealed;
+    // corridors chain consecutive rooms, so walking them in order always reaches that portal.
+    let portal_route = rooms
+        .iter()
+        .position(|room| portals.contains(&room.center()))
+        .map(|last| &rooms[..=last])
+        .unwrap_or(&rooms[..1]);
+    let mut door_locks: Vec<u8> = Vec::with_capacity(doors.len());
+    for door in &doors {
+        let roll = rng.next_f32();
+        let mut lock = if roll < DOOR_SEALED_CHANCE {
+            DOOR_SEALED
+        } else if roll < DOOR_SEALED_CHANCE + DOOR_LOCKED_CHANCE {
+            DOOR_LOCKED
+        } else {
+            DOOR_CLOSED
+        };
+        let on_route = portal_route
+            .windows(2)
+            .any(|pair| on_corridor(pair[0].center(), pair[1].center(), *door));
+        if lock == DOOR_SEALED && (on_route || borders_room(&rooms[0], *door)) {
+            lock = DOOR_LOCKED;
+        }
+        grid[door.y as usize * w + door.x as usize] = TILE_DOOR;
+        door_locks.push(lock);
+    }
+
+    // Curios go in the same free interior tiles as traps. Only one campfire per floor.
+    let mut curios: Vec<DungeonPoint> = Vec::with_capacity(MAX_CURIOS);
+    let mut curio_kinds: Vec<u8> = Vec::with_capacity(MAX_CURIOS);
+    for room in rooms.iter().skip(1) {
+        if curios.len() >= MAX_CURIOS {
+            break;
+        }
+        if rng.next_f32() >= CURIO_SPAWN_CHANCE {
+            continue;
+        }
+        let Some(point) = sample_room_point(&mut rng, room) else {
+            continue;
+        };
+        let mut kind = (rng.next_u32() % 3) as u8;
+        if kind == CURIO_CAMPFIRE && curio_kinds.contains(&CURIO_CAMPFIRE) {
+            kind = CURIO_CORPSE;
+        }
+        let blocked = point == room.center()
+            || chests.contains(&point)
+            || portals.contains(&point)
+            || traps.contains(&point)
+            || curios.contains(&point);
+        if blocked {
+            continue;
+        }
+        curios.push(point);
+        curio_kinds.push(kind);
+    }
+
     GeneratedAdventure {
         grid,
         rooms,
         doors,
+        door_locks,
         chests,
         portals,
+        traps,
+        curios,
+        curio_kinds,
+        boss_room,
     }
 }
 
+const TRAP_TILES: [u8; 4] = [
+    TILE_SPIKE_TRAP,
+    TILE_POISON_GAS,
+    TILE_COLLAPSING_FLOOR,
+    TILE_PRESSURE_PLATE,
+];
+
 pub fn is_floor(grid: &[u8], width: u16, x: u16, y: u16) -> bool {
     let idx = tile_index(width, x, y);
     grid.get(idx).copied().unwrap_or(TILE_WALL) == TILE_FLOOR
 }
 
+/// Any tile the party can stand on, including trap and door tiles.
+pub fn is_walkable(grid: &[u8], width: u16, x: u16, y: u16) -> bool {
+    let idx = tile_index(width, x, y);
+    grid.get(idx).copied().unwrap_or(TILE_WALL) != TILE_WALL
+}
+
+pub fn is_trap_tile(tile: u8) -> bool {
+    TRAP_TILES.contains(&tile)
+}
+
 pub fn tile_index(width: u16, x: u16, y: u16) -> usize {
     let w = width as usize;
     (y as usize).saturating_mul(w) + (x as usize)
@@ -199,6 +331,36 @@ fn carve_vertical(grid: &mut [u8], width: usize, y0: usize, y1: usize, x: usize)
     }
 }
 
+/// Tiles visited by an L-shaped corridor: horizontal along `from.y`, then vertical along `to.x`.
+fn corridor_path(from: DungeonPoint, to: DungeonPoint) -> Vec<DungeonPoint> {
+    let mut path = vec![from];
+    let mut x = from.x;
+    while x != to.x {
+        x = if x < to.x { x + 1 } else { x - 1 };
+        path.push(DungeonPoint { x, y: from.y });
+    }
+    let mut y = from.y;
+    while y != to.y {
+        y = if y < to.y { y + 1 } else { y - 1 };
+        path.push(DungeonPoint { x: to.x, y });
+    }
+    path
+}
+
+/// Whether `point` lies on the corridor `corridor_path(from, to)` would produce.
+fn on_corridor(from: DungeonPoint, to: DungeonPoint, point: DungeonPoint) -> bool {
+    let along_x = point.y == from.y && point.x >= from.x.min(to.x) && point.x <= from.x.max(to.x);
+    let along_y = point.x == to.x && point.y >= from.y.min(to.y) && point.y <= from.y.max(to.y);
+    along_x || along_y
+}
+
+fn borders_room(room: &DungeonRoom, point: DungeonPoint) -> bool {
+    point.x + 1 >= room.x
+        && point.x <= room.x + room.w
+        && point.y + 1 >= room.y
+        && point.y <= room.y + room.h
+}
+
 fn sample_room_point(rng: &mut Mulberry32, room: &DungeonRoom) -> Option<DungeonPoint> {
     if room.w <= 2 || room.h <= 2 {
         return None;
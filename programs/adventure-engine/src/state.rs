@@ -43,6 +43,27 @@ pub struct AdventureSession {
     pub in_combat: bool,
     pub combat_account: Pubkey,
     pub pending_encounter_seed: u64,
+    // Fields below were appended after launch; `migrate_adventure` grows older sessions to fit
+    pub traps: Vec<DungeonPoint>,
+    pub curios: Vec<DungeonPoint>,
+    pub trap_states: Vec<u8>,
+    pub door_locks: Vec<u8>,
+    pub opened_doors: Vec<u8>,
+    pub curio_kinds: Vec<u8>,
+    pub used_curios: Vec<u8>,
+    /// Statuses picked up outside combat (e.g. from traps), applied when the next fight begins.
+    pub lingering_statuses: [StatusInstance; MAX_PARTY],
+    /// Keys found this run; spent to open locked or sealed doors.
+    pub keys: u8,
+    /// Index into `rooms` of the boss lair, or `BOSS_ROOM_NONE` for single-room dungeons.
+    pub boss_room: u8,
+    pub boss_cleared: bool,
+    pub pending_encounter_kind: EncounterKind,
+    /// Combats left on a shrine blessing; each one boosts hero attack and magic.
+    pub blessing_combats: u8,
+    pub camps_used: u8,
+    /// Affliction or virtue per hero (`RESOLVE_*`), kept until the run ends.
+    pub hero_resolve: [u8; MAX_PARTY],
 }
 
 impl AdventureSession {
@@ -54,8 +75,13 @@ impl AdventureSession {
         let doors_space = 4 + MAX_DOORS * DungeonPoint::SIZE;
         let chests_space = 4 + MAX_CHESTS * DungeonPoint::SIZE;
         let portals_space = 4 + MAX_PORTALS * DungeonPoint::SIZE;
+        let traps_space = 4 + MAX_TRAPS * DungeonPoint::SIZE;
         let chest_state_space = 4 + MAX_CHESTS;
         let portal_state_space = 4 + MAX_PORTALS;
+        let trap_state_space = 4 + MAX_TRAPS;
+        let door_state_space = (4 + MAX_DOORS) * 2;
+        let curios_space = 4 + MAX_CURIOS * DungeonPoint::SIZE;
+        let curio_state_space = (4 + MAX_CURIOS) * 2;
 
         let fixed = 8
             + 32
@@ -84,7 +110,15 @@ impl AdventureSession {
             + 1
             + 1
             + 32
-            + 8;
+            + 8
+            + (StatusInstance::SIZE * MAX_PARTY)
+            + 1
+            + 1
+            + 1
+            + 1
+            + 1
+            + 1
+            + MAX_PARTY;
 
         fixed
             + grid_space
@@ -92,14 +126,19 @@ impl AdventureSession {
             + doors_space
             + chests_space
             + portals_space
+            + traps_space
             + chest_state_space
             + portal_state_space
+            + trap_state_space
+            + door_state_space
+            + curios_space
+            + curio_state_space
     }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ItemSlot {
-    pub item_key: u8, // ItemKey from player-economy, 255 = empty
+    pub item_key: u8, // item id from the player-economy registry, 255 = empty
     pub quantity: u16,
 }
 
@@ -258,6 +297,13 @@ impl DungeonRoom {
             y: self.y + (self.h.saturating_sub(1) >> 1),
         }
     }
+
+    pub fn contains(&self, point: DungeonPoint) -> bool {
+        point.x >= self.x
+            && point.x < self.x + self.w
+            && point.y >= self.y
+            && point.y < self.y + self.h
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -278,6 +324,16 @@ pub enum StatusEffect {
     Burn = 3,
     Chill = 4,
     Guard = 5,
+    /// Skips the bearer's next turn.
+    Stun = 6,
+    /// Bearer takes extra damage from every hit.
+    Mark = 7,
+    /// Heals the bearer at the start of each turn.
+    Regen = 8,
+    /// Bearer's attacks may miss.
+    Blind = 9,
+    /// Lowers the bearer's defense and resistance.
+    Vulnerable = 10,
 }
 
 impl Default for StatusEffect {
@@ -361,11 +417,34 @@ pub struct HeroCombatant {
     pub pending_xp: u32,
     pub pending_positive_traits: u8,
     pub pending_negative_traits: u8,
+    /// Formation rank, 1 (front) to 4 (back); 0 once fallen.
+    pub rank: u8,
+    pub stress_max: u16,
+    /// Number of positive traits; each improves the odds of a virtue.
+    pub positive_traits: u8,
+    /// Affliction or virtue (`RESOLVE_*`).
+    pub resolve: u8,
+    /// Death blows resisted on Death's Door this fight. A hero at 0 HP who is still `alive`
+    /// is on Death's Door.
+    pub death_blows_survived: u8,
 }
 
 impl HeroCombatant {
-    pub const SIZE: usize =
-        1 + 1 + 1 + (2 * 9) + 1 + 1 + (StatusInstance::SIZE * MAX_STATUS_PER_COMBATANT) + 4 + 1 + 1;
+    pub const SIZE: usize = 1
+        + 1
+        + 1
+        + (2 * 9)
+        + 1
+        + 1
+        + (StatusInstance::SIZE * MAX_STATUS_PER_COMBATANT)
+        + 4
+        + 1
+        + 1
+        + 1
+        + 2
+        + 1
+        + 1
+        + 1;
 
     pub fn reset(&mut self) {
         self.ap = HERO_AP_MAX;
@@ -377,6 +456,7 @@ impl HeroCombatant {
         self.pending_xp = 0;
         self.pending_positive_traits = 0;
         self.pending_negative_traits = 0;
+        self.death_blows_survived = 0;
     }
 }
 
@@ -394,19 +474,24 @@ pub struct EnemyCombatant {
     pub speed: u16,
     pub luck: u16,
     pub statuses: [StatusInstance; MAX_STATUS_PER_COMBATANT],
-    pub threat: u8,
+    /// Threat each hero has built against this enemy, indexed by hero index.
+    pub threat: [u8; MAX_PARTY],
+    /// Boss phase; bosses move to phase 1 once they drop to half health.
+    pub phase: u8,
+    /// Formation rank, 1 (front) to 4 (back); 0 once fallen.
+    pub rank: u8,
 }
 
 impl EnemyCombatant {
     pub const SIZE: usize =
-        1 + 1 + 1 + (2 * 8) + (StatusInstance::SIZE * MAX_STATUS_PER_COMBATANT) + 1;
+        1 + 1 + 1 + (2 * 8) + (StatusInstance::SIZE * MAX_STATUS_PER_COMBATANT) + MAX_PARTY + 1 + 1;
 
     pub fn reset(&mut self) {
         self.ap = ENEMY_AP_MAX;
         for status in self.statuses.iter_mut() {
             status.clear();
         }
-        self.threat = 0;
+        self.threat = [0; MAX_PARTY];
     }
 }
 
@@ -424,6 +509,62 @@ impl Default for CombatResolutionState {
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EncounterKind {
+    #[default]
+    Standard,
+    Boss,
+    Ambush,
+}
+
+impl EncounterKind {
+    /// Forced encounters can't be declined or walked away from.
+    pub fn is_forced(self) -> bool {
+        matches!(self, EncounterKind::Boss | EncounterKind::Ambush)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CombatLogAction {
+    #[default]
+    None,
+    Attack,
+    Skill1,
+    Skill2,
+    Defend,
+    UseItem,
+    Reposition,
+    /// Start-of-turn damage from poison, bleed or burn.
+    StatusTick,
+    Stunned,
+    RefusedOrders,
+    DeathsDoor,
+    DeathBlowResisted,
+    /// An enemy called a new enemy into the fight; the target is the newcomer.
+    Summon,
+}
+
+/// One thing that happened in a fight, so clients can replay every turn resolved inside a
+/// single transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CombatLogEntry {
+    pub actor_kind: CombatantKind,
+    pub actor_index: u8,
+    pub action: CombatLogAction,
+    pub target_kind: CombatantKind,
+    pub target_index: u8,
+    /// Damage dealt, or HP restored for heals.
+    pub amount: u16,
+    /// `COMBAT_LOG_*` bits.
+    pub flags: u8,
+    /// Status that landed on the target, if any.
+    pub status: StatusEffect,
+}
+
+impl CombatLogEntry {
+    pub const SIZE: usize = 1 + 1 + 1 + 1 + 1 + 2 + 1 + 1;
+}
+
 #[account]
 pub struct AdventureCombat {
     pub adventure: Pubkey,
@@ -442,6 +583,12 @@ pub struct AdventureCombat {
     pub pending_resolution: CombatResolutionState,
     pub loot_seed: u64,
     pub last_updated: i64,
+    pub encounter_kind: EncounterKind,
+    /// Ring buffer of the most recent events; `log_cursor % COMBAT_LOG_CAPACITY` is the next
+    /// slot to write.
+    pub log: [CombatLogEntry; COMBAT_LOG_CAPACITY],
+    /// Total events logged this fight.
+    pub log_cursor: u32,
 }
 
 impl AdventureCombat {
@@ -461,5 +608,8 @@ impl AdventureCombat {
         + (EnemyCombatant::SIZE * MAX_ENEMIES)
         + 1
         + 8
-        + 8;
+        + 8
+        + 1
+        + (CombatLogEntry::SIZE * COMBAT_LOG_CAPACITY)
+        + 4;
 }
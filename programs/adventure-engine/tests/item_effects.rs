@@ -0,0 +1,62 @@
+use adventure_engine::item_effects::{
+    apply_item_effect, check_hero_item_use, item_effect, ExploringHero, ItemEffect,
+};
+use adventure_engine::state::{HeroSnapshot, StatusInstance};
+use player_economy::constants::ITEM_PHOENIX_FEATHER;
+use player_economy::state::DEFAULT_ITEM_DEFINITIONS;
+
+fn fallen_hero() -> HeroSnapshot {
+    HeroSnapshot {
+        hero_id: 1,
+        hero_type: 0,
+        level: 1,
+        experience: 0,
+        max_hp: 40,
+        current_hp: 0,
+        attack: 10,
+        defense: 5,
+        magic: 5,
+        resistance: 5,
+        speed: 5,
+        luck: 5,
+        status_effects: 0,
+        stress: 0,
+        stress_max: 200,
+        positive_traits: [0; 3],
+        negative_traits: [0; 3],
+    }
+}
+
+#[test]
+fn feather_revives_a_fallen_hero_between_fights() {
+    let feather = DEFAULT_ITEM_DEFINITIONS
+        .iter()
+        .find(|definition| definition.key == ITEM_PHOENIX_FEATHER)
+        .unwrap();
+    assert!(feather.usable);
+
+    let effect = item_effect(feather);
+    assert_eq!(effect, ItemEffect::Revive);
+    assert!(effect.targets_hero());
+
+    let mut hero = fallen_hero();
+    let mut lingering = StatusInstance::default();
+    check_hero_item_use(effect, 1, hero.current_hp > 0).unwrap();
+
+    let mut target = ExploringHero {
+        hero: &mut hero,
+        lingering: &mut lingering,
+    };
+    assert!(apply_item_effect(&mut target, effect));
+    assert_eq!(hero.current_hp, 20);
+
+    // A standing hero can't be revived again
+    assert!(check_hero_item_use(effect, 1, hero.current_hp > 0).is_err());
+}
+
+#[test]
+fn revive_spends_a_single_feather() {
+    assert!(check_hero_item_use(ItemEffect::Revive, 2, false).is_err());
+    assert!(check_hero_item_use(ItemEffect::Heal(10), 2, true).is_ok());
+    assert!(check_hero_item_use(ItemEffect::Heal(10), 1, false).is_err());
+}
@@ -55,6 +55,7 @@ fn pay_abbey_tithe(ctx: &Context<AbbeyService>) -> Result<()> {
     let cpi_accounts = player_economy::cpi::accounts::SpendGold {
         owner: ctx.accounts.owner.to_account_info(),
         player_economy: ctx.accounts.player_economy.to_account_info(),
+        economy_stats: ctx.accounts.economy_stats.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     player_economy::cpi::spend_gold(cpi_ctx, STRESS_RELIEF_COST)
@@ -75,6 +76,9 @@ pub struct AbbeyService<'info> {
     /// CHECK: player_economy PDA verified by player-economy program
     #[account(mut)]
     pub player_economy: AccountInfo<'info>,
+    /// CHECK: economy stats PDA verified by player-economy program
+    #[account(mut)]
+    pub economy_stats: AccountInfo<'info>,
     pub player_economy_program: Program<'info, player_economy::program::PlayerEconomy>,
     pub system_program: Program<'info, System>,
 }
@@ -35,6 +35,7 @@ pub fn reroll_stats(ctx: Context<BlacksmithService>, hero_id: u64) -> Result<()>
     let cpi_accounts = player_economy::cpi::accounts::SpendGold {
         owner: ctx.accounts.payer.to_account_info(),
         player_economy: ctx.accounts.player_economy.to_account_info(),
+        economy_stats: ctx.accounts.economy_stats.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     player_economy::cpi::spend_gold(cpi_ctx, STAT_REROLL_COST)?;
@@ -127,6 +128,9 @@ pub struct BlacksmithService<'info> {
     /// CHECK: player_economy PDA verified by player-economy program
     #[account(mut)]
     pub player_economy: AccountInfo<'info>,
+    /// CHECK: economy stats PDA verified by player-economy program
+    #[account(mut)]
+    pub economy_stats: AccountInfo<'info>,
     pub player_economy_program: Program<'info, player_economy::program::PlayerEconomy>,
     /// CHECK: VRF oracle queue; queue authority enforced off-chain and via VRF program.
     #[account(mut, address = DEFAULT_QUEUE)]
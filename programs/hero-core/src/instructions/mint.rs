@@ -14,7 +14,7 @@ use crate::state::{
     GameVault, HeroMint, HeroMinted, PendingRequestType, PlayerProfile, RandomnessRequested,
     RequestType,
 };
-use player_economy::{self, PLAYER_ECONOMY_SEED};
+use player_economy::{self, ECONOMY_STATS_SEED, PLAYER_ECONOMY_SEED};
 
 pub fn mint_hero_free(ctx: Context<MintHeroFree>) -> Result<()> {
     let profile = &mut ctx.accounts.player_profile;
@@ -144,6 +144,7 @@ pub fn mint_hero_paid(ctx: Context<MintHeroPaid>) -> Result<()> {
         player_economy::cpi::accounts::SpendGold {
             owner: ctx.accounts.payer.to_account_info(),
             player_economy: ctx.accounts.player_economy_account.to_account_info(),
+            economy_stats: ctx.accounts.economy_stats.to_account_info(),
         },
     );
     player_economy::cpi::spend_gold(spend_ctx, HERO_PRICE)?;
@@ -426,6 +427,13 @@ pub struct MintHeroPaid<'info> {
         seeds::program = player_economy::ID
     )]
     pub player_economy_account: Account<'info, player_economy::PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]],
+        bump = economy_stats.bump,
+        seeds::program = player_economy::ID
+    )]
+    pub economy_stats: Account<'info, player_economy::EconomyStats>,
     pub player_economy_program: Program<'info, player_economy::program::PlayerEconomy>,
     /// CHECK: VRF oracle queue; queue authority enforced off-chain and via VRF program.
     #[account(mut, address = DEFAULT_QUEUE)]
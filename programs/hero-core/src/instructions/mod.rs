@@ -6,6 +6,7 @@ pub mod devtools;
 pub mod initialize;
 pub mod level_up;
 pub mod mint;
+pub mod relic;
 pub mod sanitarium;
 pub mod status;
 pub mod tavern;
@@ -20,6 +21,7 @@ pub use level_up::{CallbackLevelUpHero, LevelUpHero};
 pub use mint::{
     CallbackMintHeroFree, CallbackMintHeroPaid, MintHeroFree, MintHeroPaid, MintHeroWithSeed,
 };
+pub use relic::RelicCurse;
 pub use sanitarium::SanitariumTreatment;
 pub use status::ModifyStatusEffect;
 pub use tavern::TavernService;
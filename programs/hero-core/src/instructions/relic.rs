@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{HERO_SEED, NEGATIVE_TRAIT_COUNT};
+use crate::errors::HeroError;
+use crate::state::HeroMint;
+
+/// Curse from a player-economy relic identification. Runs inside that program's VRF callback,
+/// so a hero that cannot take the curse is skipped rather than failing the callback.
+pub fn apply_relic_curse(ctx: Context<RelicCurse>, trait_roll: u8) -> Result<()> {
+    let hero = &mut ctx.accounts.hero_mint;
+
+    if hero.is_burned || hero.locked {
+        msg!("Hero {} cannot be cursed right now", hero.id);
+        return Ok(());
+    }
+
+    // Start from the rolled trait and take the first one the hero does not already have
+    let trait_id = (0..NEGATIVE_TRAIT_COUNT)
+        .map(|offset| (trait_roll.wrapping_add(offset)) % NEGATIVE_TRAIT_COUNT)
+        .find(|candidate| !hero.negative_traits.contains(&Some(*candidate)));
+    let slot = hero.negative_traits.iter_mut().find(|slot| slot.is_none());
+
+    let (Some(trait_id), Some(slot)) = (trait_id, slot) else {
+        msg!("Hero {} has no room for another negative trait", hero.id);
+        return Ok(());
+    };
+    *slot = Some(trait_id);
+
+    emit!(RelicCurseApplied {
+        player: hero.owner,
+        hero_id: hero.id,
+        trait_id,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RelicCurse<'info> {
+    #[account(
+        seeds = [player_economy::RELIC_CURSE_SIGNER_SEED],
+        bump,
+        seeds::program = player_economy::ID
+    )]
+    pub curse_signer: Signer<'info>,
+    /// CHECK: Hero owner, only used to match the hero PDA.
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [HERO_SEED, player.key().as_ref(), &hero_mint.id.to_le_bytes()],
+        bump = hero_mint.bump,
+        constraint = hero_mint.owner == player.key() @ HeroError::UnauthorizedOwner
+    )]
+    pub hero_mint: Account<'info, HeroMint>,
+}
+
+#[event]
+pub struct RelicCurseApplied {
+    pub player: Pubkey,
+    pub hero_id: u64,
+    pub trait_id: u8,
+}
@@ -30,6 +30,7 @@ pub fn cure_status_effect(
     let cpi_accounts = player_economy::cpi::accounts::SpendGold {
         owner: ctx.accounts.owner.to_account_info(),
         player_economy: ctx.accounts.player_economy.to_account_info(),
+        economy_stats: ctx.accounts.economy_stats.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     player_economy::cpi::spend_gold(cpi_ctx, STATUS_EFFECT_CURE_COST)?;
@@ -74,6 +75,7 @@ pub fn cure_negative_trait(
     let cpi_accounts = player_economy::cpi::accounts::SpendGold {
         owner: ctx.accounts.owner.to_account_info(),
         player_economy: ctx.accounts.player_economy.to_account_info(),
+        economy_stats: ctx.accounts.economy_stats.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     player_economy::cpi::spend_gold(cpi_ctx, NEGATIVE_TRAIT_CURE_COST)?;
@@ -99,6 +101,9 @@ pub struct SanitariumTreatment<'info> {
     /// CHECK: player_economy PDA verified by player-economy program
     #[account(mut)]
     pub player_economy: AccountInfo<'info>,
+    /// CHECK: economy stats PDA verified by player-economy program
+    #[account(mut)]
+    pub economy_stats: AccountInfo<'info>,
     pub player_economy_program: Program<'info, player_economy::program::PlayerEconomy>,
     pub system_program: Program<'info, System>,
 }
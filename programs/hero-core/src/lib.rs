@@ -54,6 +54,9 @@ pub(crate) use instructions::mint::{
     __cpi_client_accounts_mint_hero_free, __cpi_client_accounts_mint_hero_paid,
     __cpi_client_accounts_mint_hero_with_seed,
 };
+pub(crate) use instructions::relic::__client_accounts_relic_curse;
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::relic::__cpi_client_accounts_relic_curse;
 pub(crate) use instructions::sanitarium::__client_accounts_sanitarium_treatment;
 #[cfg(feature = "cpi")]
 pub(crate) use instructions::sanitarium::__cpi_client_accounts_sanitarium_treatment;
@@ -73,6 +76,7 @@ pub use instructions::{
     level_up::{CallbackLevelUpHero, LevelUpHero},
     mint::MintHeroWithSeed,
     mint::{CallbackMintHeroFree, CallbackMintHeroPaid, MintHeroFree, MintHeroPaid},
+    relic::RelicCurse,
     sanitarium::SanitariumTreatment,
     status::ModifyStatusEffect,
     tavern::TavernService,
@@ -165,6 +169,10 @@ pub mod hero_core {
         instructions::adventure::sync_stats_from_adventure(ctx, hero_state)
     }
 
+    pub fn apply_relic_curse(ctx: Context<RelicCurse>, trait_roll: u8) -> Result<()> {
+        instructions::relic::apply_relic_curse(ctx, trait_roll)
+    }
+
     pub fn cure_status_effect(
         ctx: Context<SanitariumTreatment>,
         hero_id: u64,
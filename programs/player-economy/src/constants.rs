@@ -1,9 +1,125 @@
 use anchor_lang::prelude::{pubkey, Pubkey};
 
 pub const PLAYER_ECONOMY_SEED: &[u8] = b"player_economy";
-pub const ITEM_COUNT: usize = 7;
-pub const HOURLY_GRANT_AMOUNT: u64 = 200;
-pub const HOURLY_GRANT_COOLDOWN: i64 = 60 * 60;
+pub const ITEM_REGISTRY_SEED: &[u8] = b"item_registry";
+pub const SHOP_STATE_SEED: &[u8] = b"shop_state";
+pub const TRANSFER_CONFIG_SEED: &[u8] = b"transfer_config";
+pub const TRANSFER_LEDGER_SEED: &[u8] = b"transfer_ledger";
+pub const TRADE_OFFER_SEED: &[u8] = b"trade_offer";
+pub const CRAFT_REQUEST_SEED: &[u8] = b"craft_request";
+pub const IDENTIFY_REQUEST_SEED: &[u8] = b"identify_request";
+/// PDA that signs hero-core's `apply_relic_curse`.
+pub const RELIC_CURSE_SIGNER_SEED: &[u8] = b"relic_curse";
+pub const FAUCET_CONFIG_SEED: &[u8] = b"faucet_config";
+pub const FAUCET_LEDGER_SEED: &[u8] = b"faucet_ledger";
+pub const GOLD_BRIDGE_SEED: &[u8] = b"gold_bridge";
+pub const GOLD_MINT_SEED: &[u8] = b"gold_mint";
+pub const BRIDGE_LEDGER_SEED: &[u8] = b"bridge_ledger";
+pub const ECONOMY_STATS_SEED: &[u8] = b"economy_stats";
+/// `EconomyStats` is split across this many shards so players don't contend for one
+/// account; readers sum every shard.
+pub const ECONOMY_STATS_SHARDS: u8 = 16;
+/// Length of a faucet day; one claim per day keeps a streak alive.
+pub const FAUCET_DAY_SECONDS: i64 = 24 * 60 * 60;
+
+/// Window the per-player bridge limits are counted over.
+pub const BRIDGE_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Daily telemetry buckets kept in `EconomyStats`, oldest overwritten first.
+pub const ECONOMY_STATS_DAYS: usize = 7;
+pub const ECONOMY_STATS_DAY_SECONDS: i64 = 24 * 60 * 60;
+
+/// Units of one item a single stash slot holds.
+pub const STASH_SLOT_SIZE: u16 = 20;
+/// Stash slots per tier; accounts start at tier 0.
+pub const STASH_TIER_SLOTS: [u16; 5] = [12, 18, 24, 32, 40];
+/// Gold burned to move from tier `i` to tier `i + 1`.
+pub const STASH_UPGRADE_COSTS: [u64; 4] = [200, 500, 1_000, 2_000];
+
+/// Most item types the registry can define; player inventories are sized to hold a stack of
+/// each.
+pub const MAX_ITEM_TYPES: usize = 32;
+/// Marks an empty item slot in adventure-engine, so no definition may use it.
+pub const RESERVED_ITEM_KEY: u8 = u8::MAX;
+
+/// Lengths of the fixed `[u16; N]` item array a `PlayerEconomy` had before the registry: seven
+/// slots at launch, eight once firewood was added.
+pub const LEGACY_ITEM_COUNTS: [usize; 2] = [7, 8];
+
+pub const fn legacy_player_economy_len(item_count: usize) -> usize {
+    8 + 32 + 8 + 8 + (2 * item_count) + 1 + 5
+}
+
+pub const BPS_DENOMINATOR: i64 = 10_000;
+
+/// Shop stock restocks and sell pressure resets once per epoch.
+pub const SHOP_EPOCH_SECONDS: i64 = 24 * 60 * 60;
+/// Daily stock for items the shop has not been configured for yet. Stock is shared by every
+/// buyer, so this stays high enough that one wallet can't sell an item out for everyone.
+pub const SHOP_DEFAULT_DAILY_STOCK: u16 = 5_000;
+/// Price movement per unit of net demand (purchases minus sales).
+pub const SHOP_DEMAND_STEP_BPS: i64 = 250;
+/// Net demand saturates here; the price curve is already clamped well before it.
+pub const SHOP_MAX_DEMAND: i32 = 100;
+pub const SHOP_MIN_PRICE_BPS: i64 = 5_000;
+pub const SHOP_MAX_PRICE_BPS: i64 = 20_000;
+/// Sell-back falls by this much for each unit of the same item sold in the current epoch.
+pub const SHOP_SELL_DECAY_BPS: i64 = 500;
+pub const SHOP_MIN_SELL_BPS: i64 = 2_000;
+
+/// Window the daily transfer limits are counted over.
+pub const TRANSFER_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Share of every trading post sale burned from the seller's proceeds.
+pub const TRADE_FEE_BPS: u64 = 250;
+
+// Item ids of the definitions the registry starts with
+pub const ITEM_POUCH_GOLD: u8 = 0;
+pub const ITEM_STRESS_TONIC: u8 = 1;
+pub const ITEM_MINOR_TORCH: u8 = 2;
+pub const ITEM_HEALING_SALVE: u8 = 3;
+pub const ITEM_MYSTERY_RELIC: u8 = 4;
+pub const ITEM_CALMING_INCENSE: u8 = 5;
+pub const ITEM_PHOENIX_FEATHER: u8 = 6;
+pub const ITEM_FIREWOOD: u8 = 7;
+pub const ITEM_GREATER_SALVE: u8 = 8;
+pub const ITEM_LUCKY_CHARM: u8 = 9;
+pub const ITEM_EMBER_CHARM: u8 = 10;
+pub const ITEM_WARDING_CHARM: u8 = 11;
+
+pub const RECIPE_GREATER_SALVE: u8 = 0;
+pub const RECIPE_RELIC_TRINKET: u8 = 1;
+/// Upper bound on `times` for a single craft.
+pub const MAX_CRAFT_TIMES: u16 = 10;
+
+/// How long a craft or identify waits on its VRF callback before the player may cancel it.
+pub const VRF_REQUEST_TIMEOUT_SECONDS: i64 = 10 * 60;
+
+/// Gold charged on top of the relic to identify it.
+pub const IDENTIFY_RELIC_FEE: u64 = 20;
+/// Paid instead of an item reward the player has no room for and that has no sell price.
+pub const RELIC_FALLBACK_GOLD: u64 = 70;
+pub const RELIC_REWARD_GOLD: u8 = 0;
+pub const RELIC_REWARD_ITEM: u8 = 1;
+pub const RELIC_REWARD_CURSE: u8 = 2;
+
+// Effect ids stored on item definitions and resolved by adventure-engine
+pub const ITEM_EFFECT_NONE: u8 = 0;
+pub const ITEM_EFFECT_RESTORE_TORCH: u8 = 1;
+pub const ITEM_EFFECT_HEAL: u8 = 2;
+pub const ITEM_EFFECT_RELIEVE_STRESS: u8 = 3;
+pub const ITEM_EFFECT_CALM: u8 = 4;
+pub const ITEM_EFFECT_REVIVE: u8 = 5;
+
+/// Only signer allowed to create the program's global config accounts.
+pub const ECONOMY_ADMIN: Pubkey = pubkey!("AXwYStYVryJuZjNJjHHLPp6eVRc2TuESnW1pCMiUYrwV");
 
 pub const ADVENTURE_ENGINE_PROGRAM_ID: Pubkey =
     pubkey!("Hnjoe3f7cZuc47RMytSyBrdpxj6x8SoHQBRfqdwKvxVC");
+pub const HERO_CORE_PROGRAM_ID: Pubkey = pubkey!("B8KfNvRUoNbF7FPeuDdZ7nfjPXz6kAex4Pye6GcpLD1E");
+/// Anchor discriminator of hero-core's `apply_relic_curse`; hero-core depends on this crate, so
+/// the call is built by hand.
+pub const APPLY_RELIC_CURSE_DISCRIMINATOR: [u8; 8] = [122, 77, 59, 8, 9, 98, 241, 240];
+/// Anchor discriminator and PDA seed of hero-core's `HeroMint`, read by hand for the same reason.
+pub const HERO_MINT_DISCRIMINATOR: [u8; 8] = [202, 83, 95, 67, 120, 234, 56, 187];
+pub const HERO_SEED: &[u8] = b"hero";
@@ -22,10 +22,60 @@ pub enum PlayerEconomyError {
     InsufficientGold,
     #[msg("Value overflow detected")]
     MathOverflow,
-    #[msg("Hourly grant still on cooldown")]
+    #[msg("Daily grant already claimed")]
     GrantOnCooldown,
     #[msg("Inventory quantity too large")]
     InventoryOverflow,
     #[msg("Player economy account is not initialized")]
     AccountNotInitialized,
+    #[msg("Item is not defined in the registry")]
+    UnknownItem,
+    #[msg("Inventory has no room for another item type")]
+    InventoryFull,
+    #[msg("Item registry is full")]
+    RegistryFull,
+    #[msg("Player economy account does not use the legacy layout")]
+    NotLegacyAccount,
+    #[msg("Shop does not have enough stock left today")]
+    ShopSoldOut,
+    #[msg("Cannot transfer to your own economy account")]
+    SelfTransfer,
+    #[msg("Daily transfer limit exceeded")]
+    TransferLimitExceeded,
+    #[msg("Offer price must be greater than zero")]
+    InvalidPrice,
+    #[msg("Offer does not have that many items left")]
+    OfferQuantityExceeded,
+    #[msg("Cannot fill your own offer")]
+    SelfTrade,
+    #[msg("Unknown crafting recipe")]
+    UnknownRecipe,
+    #[msg("A randomized craft is already waiting for its result")]
+    CraftInProgress,
+    #[msg("Craft callback does not match a pending request")]
+    UnexpectedCallback,
+    #[msg("A relic is already being identified")]
+    IdentifyInProgress,
+    #[msg("Not enough free stash slots")]
+    StashFull,
+    #[msg("Stash is already at the highest tier")]
+    StashAtMaxTier,
+    #[msg("Lifetime faucet cap reached")]
+    FaucetCapReached,
+    #[msg("Gold bridge is paused")]
+    BridgePaused,
+    #[msg("Daily bridge limit exceeded")]
+    BridgeLimitExceeded,
+    #[msg("Item key is reserved")]
+    ReservedItemKey,
+    #[msg("Recipient has reached their daily transfer limit")]
+    RecipientLimitExceeded,
+    #[msg("Hero must be an unlocked, unburned hero owned by the player")]
+    InvalidHero,
+    #[msg("No randomness request is pending")]
+    NoPendingRequest,
+    #[msg("Randomness request has not timed out yet")]
+    RequestNotExpired,
+    #[msg("Economy stats shard is out of range")]
+    InvalidStatsShard,
 }
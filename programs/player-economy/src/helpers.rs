@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+use ephemeral_vrf_sdk::types::SerializableAccountMeta;
+
+/// Non-signer account meta for a VRF callback.
+pub fn meta(pubkey: Pubkey, is_writable: bool) -> SerializableAccountMeta {
+    SerializableAccountMeta {
+        pubkey,
+        is_signer: false,
+        is_writable,
+    }
+}
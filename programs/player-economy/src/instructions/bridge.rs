@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeGoldBridge<'info> {
+    #[account(mut, address = ECONOMY_ADMIN @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = GoldBridge::LEN,
+        seeds = [GOLD_BRIDGE_SEED],
+        bump
+    )]
+    pub gold_bridge: Account<'info, GoldBridge>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [GOLD_MINT_SEED],
+        bump,
+        mint::decimals = 0,
+        mint::authority = gold_bridge
+    )]
+    pub gold_mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGoldBridge<'info> {
+    #[account(constraint = authority.key() == gold_bridge.authority @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [GOLD_BRIDGE_SEED], bump = gold_bridge.bump)]
+    pub gold_bridge: Account<'info, GoldBridge>,
+}
+
+#[derive(Accounts)]
+pub struct BridgeGold<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [GOLD_BRIDGE_SEED], bump = gold_bridge.bump)]
+    pub gold_bridge: Account<'info, GoldBridge>,
+    #[account(mut, address = gold_bridge.mint)]
+    pub gold_mint: Account<'info, Mint>,
+    /// Owner's token account that receives withdrawn gold or has deposited gold burned from it.
+    #[account(
+        mut,
+        token::mint = gold_mint,
+        token::authority = owner
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = BridgeLedger::LEN,
+        seeds = [BRIDGE_LEDGER_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub bridge_ledger: Account<'info, BridgeLedger>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_gold_bridge(
+    ctx: Context<InitializeGoldBridge>,
+    daily_withdraw_limit: u64,
+    daily_deposit_limit: u64,
+) -> Result<()> {
+    let bridge = &mut ctx.accounts.gold_bridge;
+    let authority = ctx.accounts.authority.key();
+
+    bridge.authority = authority;
+    bridge.bump = ctx.bumps.gold_bridge;
+    bridge.mint = ctx.accounts.gold_mint.key();
+    bridge.paused = false;
+    bridge.daily_withdraw_limit = daily_withdraw_limit;
+    bridge.daily_deposit_limit = daily_deposit_limit;
+
+    emit!(GoldBridgeUpdated {
+        authority,
+        paused: false,
+        daily_withdraw_limit,
+        daily_deposit_limit,
+    });
+
+    Ok(())
+}
+
+pub fn update_gold_bridge(
+    ctx: Context<UpdateGoldBridge>,
+    paused: bool,
+    daily_withdraw_limit: u64,
+    daily_deposit_limit: u64,
+) -> Result<()> {
+    let bridge = &mut ctx.accounts.gold_bridge;
+    bridge.paused = paused;
+    bridge.daily_withdraw_limit = daily_withdraw_limit;
+    bridge.daily_deposit_limit = daily_deposit_limit;
+
+    emit!(GoldBridgeUpdated {
+        authority: ctx.accounts.authority.key(),
+        paused,
+        daily_withdraw_limit,
+        daily_deposit_limit,
+    });
+
+    Ok(())
+}
+
+/// Burn internal gold and mint the same amount of SPL gold to the owner's token account.
+pub fn withdraw_gold(ctx: Context<BridgeGold>, amount: u64) -> Result<()> {
+    require!(amount > 0, PlayerEconomyError::InvalidQuantity);
+    let bridge = &ctx.accounts.gold_bridge;
+    require!(!bridge.paused, PlayerEconomyError::BridgePaused);
+
+    let owner = ctx.accounts.owner.key();
+    let ledger = &mut ctx.accounts.bridge_ledger;
+    prepare_ledger(ledger, owner, ctx.bumps.bridge_ledger)?;
+    let withdrawn = ledger
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    if bridge.daily_withdraw_limit > 0 {
+        require!(
+            withdrawn <= bridge.daily_withdraw_limit,
+            PlayerEconomyError::BridgeLimitExceeded
+        );
+    }
+
+    let account = &mut ctx.accounts.player_economy;
+    require!(account.gold >= amount, PlayerEconomyError::InsufficientGold);
+    account.gold -= amount;
+    ledger.withdrawn = withdrawn;
+    // Bridged gold leaves the internal ledger, so it counts against internal supply
+    ctx.accounts
+        .economy_stats
+        .record_gold_burned(Clock::get()?.unix_timestamp, amount);
+
+    let bump = [bridge.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[GOLD_BRIDGE_SEED, &bump]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.gold_mint.to_account_info(),
+        to: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.gold_bridge.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::mint_to(cpi_ctx, amount)?;
+
+    emit!(GoldWithdrawn {
+        owner,
+        amount,
+        remaining: account.gold,
+    });
+
+    Ok(())
+}
+
+/// Burn SPL gold from the owner's token account and credit it back to the internal ledger.
+pub fn deposit_gold(ctx: Context<BridgeGold>, amount: u64) -> Result<()> {
+    require!(amount > 0, PlayerEconomyError::InvalidQuantity);
+    let bridge = &ctx.accounts.gold_bridge;
+    require!(!bridge.paused, PlayerEconomyError::BridgePaused);
+
+    let owner = ctx.accounts.owner.key();
+    let ledger = &mut ctx.accounts.bridge_ledger;
+    prepare_ledger(ledger, owner, ctx.bumps.bridge_ledger)?;
+    let deposited = ledger
+        .deposited
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    if bridge.daily_deposit_limit > 0 {
+        require!(
+            deposited <= bridge.daily_deposit_limit,
+            PlayerEconomyError::BridgeLimitExceeded
+        );
+    }
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.gold_mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::burn(cpi_ctx, amount)?;
+
+    let account = &mut ctx.accounts.player_economy;
+    account.gold = account
+        .gold
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    ledger.deposited = deposited;
+    ctx.accounts
+        .economy_stats
+        .record_gold_minted(Clock::get()?.unix_timestamp, amount);
+
+    emit!(GoldDeposited {
+        owner,
+        amount,
+        balance: account.gold,
+    });
+
+    Ok(())
+}
+
+fn prepare_ledger(ledger: &mut BridgeLedger, owner: Pubkey, bump: u8) -> Result<()> {
+    if ledger.owner == Pubkey::default() {
+        ledger.owner = owner;
+        ledger.bump = bump;
+    }
+    ledger.roll_window(Clock::get()?.unix_timestamp);
+    Ok(())
+}
@@ -0,0 +1,329 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+use ephemeral_vrf_sdk::anchor::vrf;
+use ephemeral_vrf_sdk::consts::{DEFAULT_QUEUE, VRF_PROGRAM_IDENTITY};
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+
+use crate::helpers::meta;
+use crate::recipes::{recipe, RecipeOutput};
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[vrf]
+#[derive(Accounts)]
+pub struct Craft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CraftRequest::LEN,
+        seeds = [CRAFT_REQUEST_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub craft_request: Account<'info, CraftRequest>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+    /// CHECK: VRF oracle queue; queue authority enforced off-chain and via VRF program.
+    #[account(mut, address = DEFAULT_QUEUE)]
+    pub oracle_queue: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CallbackCraft<'info> {
+    #[account(address = VRF_PROGRAM_IDENTITY)]
+    pub program_identity: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CRAFT_REQUEST_SEED, craft_request.owner.as_ref()],
+        bump = craft_request.bump
+    )]
+    pub craft_request: Account<'info, CraftRequest>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, craft_request.owner.as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+}
+
+#[derive(Accounts)]
+pub struct CancelCraft<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [CRAFT_REQUEST_SEED, owner.key().as_ref()],
+        bump = craft_request.bump
+    )]
+    pub craft_request: Account<'info, CraftRequest>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimCraftedItems<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [CRAFT_REQUEST_SEED, owner.key().as_ref()],
+        bump = craft_request.bump
+    )]
+    pub craft_request: Account<'info, CraftRequest>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+}
+
+/// Consume a recipe's inputs `times` over. Fixed outputs are granted immediately; randomized
+/// outputs are rolled in `callback_craft`.
+pub fn craft(ctx: Context<Craft>, recipe_id: u8, times: u16) -> Result<()> {
+    require!(
+        times > 0 && times <= MAX_CRAFT_TIMES,
+        PlayerEconomyError::InvalidQuantity
+    );
+    let recipe = recipe(recipe_id)?;
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.craft_request.owner = owner;
+    ctx.accounts.craft_request.bump = ctx.bumps.craft_request;
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &ctx.accounts.item_registry;
+    let account = &mut ctx.accounts.player_economy;
+    let stats = &mut ctx.accounts.economy_stats;
+
+    let gold_spent = recipe
+        .gold_cost
+        .checked_mul(times as u64)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    require!(
+        account.gold >= gold_spent,
+        PlayerEconomyError::InsufficientGold
+    );
+
+    if let RecipeOutput::Random(pool) = recipe.output {
+        require!(
+            !ctx.accounts.craft_request.pending,
+            PlayerEconomyError::CraftInProgress
+        );
+        // The callback cannot refund inputs, so every possible roll must fit up front
+        for item in pool {
+            let definition = registry.definition(*item)?;
+            let worst_case = account
+                .quantity(*item)
+                .checked_add(times)
+                .ok_or(PlayerEconomyError::InventoryOverflow)?;
+            require!(
+                definition.max_stack == 0 || worst_case <= definition.max_stack,
+                PlayerEconomyError::StackLimitExceeded
+            );
+        }
+    }
+
+    for input in recipe.inputs {
+        let quantity = input
+            .quantity
+            .checked_mul(times)
+            .ok_or(PlayerEconomyError::InventoryOverflow)?;
+        account.remove_items(input.item, quantity)?;
+        stats.record_items_destroyed(now, input.item, quantity);
+    }
+    account.gold -= gold_spent;
+    stats.record_gold_burned(now, gold_spent);
+
+    match recipe.output {
+        RecipeOutput::Item { item, quantity } => {
+            let quantity = quantity
+                .checked_mul(times)
+                .ok_or(PlayerEconomyError::InventoryOverflow)?;
+            account.add_items(registry.definition(item)?, quantity)?;
+            stats.record_items_created(now, item, quantity);
+
+            emit!(ItemsCrafted {
+                owner,
+                recipe_id,
+                times,
+                gold_spent,
+                outputs: vec![ItemStack { item, quantity }],
+            });
+        }
+        RecipeOutput::Random(_) => {
+            // Each roll can take at most one new slot
+            require!(
+                account.used_slots() + times as u32 <= account.stash_capacity(),
+                PlayerEconomyError::StashFull
+            );
+            let request = &mut ctx.accounts.craft_request;
+            request.pending = true;
+            request.recipe_id = recipe_id;
+            request.times = times;
+            request.requested_at = now;
+
+            let caller_seed = hashv(&[&owner.to_bytes(), &[recipe_id], &now.to_le_bytes()]).0;
+            let ix = create_request_randomness_ix(RequestRandomnessParams {
+                payer: owner,
+                oracle_queue: ctx.accounts.oracle_queue.key(),
+                callback_program_id: crate::ID,
+                callback_discriminator: crate::instruction::CallbackCraft::DISCRIMINATOR.to_vec(),
+                accounts_metas: Some(vec![
+                    meta(ctx.accounts.craft_request.key(), true),
+                    meta(ctx.accounts.player_economy.key(), true),
+                    meta(ctx.accounts.item_registry.key(), false),
+                    meta(ctx.accounts.economy_stats.key(), true),
+                ]),
+                caller_seed,
+                callback_args: None,
+            });
+            ctx.accounts
+                .invoke_signed_vrf(&ctx.accounts.owner.to_account_info(), &ix)?;
+
+            emit!(CraftRequested {
+                owner,
+                recipe_id,
+                times,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn callback_craft(ctx: Context<CallbackCraft>, randomness: [u8; 32]) -> Result<()> {
+    let request = &mut ctx.accounts.craft_request;
+    require!(request.pending, PlayerEconomyError::UnexpectedCallback);
+
+    let recipe = recipe(request.recipe_id)?;
+    let RecipeOutput::Random(pool) = recipe.output else {
+        return Err(error!(PlayerEconomyError::UnexpectedCallback));
+    };
+
+    let mut outputs: Vec<ItemStack> = Vec::new();
+    for roll in randomness.iter().take(request.times as usize) {
+        let item = pool[*roll as usize % pool.len()];
+        match outputs.iter_mut().find(|stack| stack.item == item) {
+            Some(stack) => stack.quantity += 1,
+            None => outputs.push(ItemStack { item, quantity: 1 }),
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &ctx.accounts.item_registry;
+    let account = &mut ctx.accounts.player_economy;
+    let stats = &mut ctx.accounts.economy_stats;
+    for output in &outputs {
+        // The inventory may have filled up since the request; a failure here must not strand
+        // the request, so outputs that don't fit are held for `claim_crafted_items`
+        let granted = registry
+            .definition(output.item)
+            .and_then(|definition| account.add_items(definition, output.quantity));
+        if granted.is_err() {
+            msg!(
+                "Holding {} of crafted item {} until claimed",
+                output.quantity,
+                output.item
+            );
+            request.hold(output.item, output.quantity)?;
+        }
+        stats.record_items_created(now, output.item, output.quantity);
+    }
+    request.pending = false;
+
+    emit!(ItemsCrafted {
+        owner: request.owner,
+        recipe_id: request.recipe_id,
+        times: request.times,
+        gold_spent: recipe.gold_cost * request.times as u64,
+        outputs,
+    });
+
+    Ok(())
+}
+
+/// Refund a randomized craft whose VRF callback never arrived.
+pub fn cancel_craft(ctx: Context<CancelCraft>) -> Result<()> {
+    let request = &mut ctx.accounts.craft_request;
+    require!(request.pending, PlayerEconomyError::NoPendingRequest);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        vrf_request_expired(request.requested_at, now),
+        PlayerEconomyError::RequestNotExpired
+    );
+
+    let recipe = recipe(request.recipe_id)?;
+    let account = &mut ctx.accounts.player_economy;
+    let stats = &mut ctx.accounts.economy_stats;
+    for input in recipe.inputs {
+        let quantity = input
+            .quantity
+            .checked_mul(request.times)
+            .ok_or(PlayerEconomyError::InventoryOverflow)?;
+        account.return_items(input.item, quantity)?;
+        stats.record_items_created(now, input.item, quantity);
+    }
+    let gold_refund = recipe
+        .gold_cost
+        .checked_mul(request.times as u64)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    account.gold = account
+        .gold
+        .checked_add(gold_refund)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    stats.record_gold_minted(now, gold_refund);
+    request.pending = false;
+
+    emit!(CraftCancelled {
+        owner: request.owner,
+        recipe_id: request.recipe_id,
+        times: request.times,
+    });
+
+    Ok(())
+}
+
+/// Move held craft outputs into the inventory. Stacks that still don't fit stay held.
+pub fn claim_crafted_items(ctx: Context<ClaimCraftedItems>) -> Result<()> {
+    let registry = &ctx.accounts.item_registry;
+    let account = &mut ctx.accounts.player_economy;
+    let request = &mut ctx.accounts.craft_request;
+
+    let mut claimed: Vec<ItemStack> = Vec::new();
+    let mut held: Vec<ItemStack> = Vec::new();
+    for stack in request.unclaimed.drain(..) {
+        let granted = registry
+            .definition(stack.item)
+            .and_then(|definition| account.add_items(definition, stack.quantity));
+        match granted {
+            Ok(()) => claimed.push(stack),
+            Err(_) => held.push(stack),
+        }
+    }
+    request.unclaimed = held;
+    require!(!claimed.is_empty(), PlayerEconomyError::StashFull);
+
+    emit!(CraftedItemsClaimed {
+        owner: request.owner,
+        items: claimed,
+    });
+
+    Ok(())
+}
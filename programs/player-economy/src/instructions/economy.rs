@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
 
 use crate::{constants::*, errors::PlayerEconomyError, state::*};
 
@@ -17,6 +18,21 @@ pub struct InitializePlayerEconomy<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct MigratePlayerEconomy<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: Legacy-layout account that cannot deserialize yet; owner and size are checked in
+    /// the handler.
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub player_economy: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct SpendGold<'info> {
     /// Signer whose vault gold will be debited.
@@ -27,10 +43,12 @@ pub struct SpendGold<'info> {
         bump = player_economy.bump
     )]
     pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
 }
 
 #[derive(Accounts)]
-pub struct GrantHourlyGold<'info> {
+pub struct UpgradeStash<'info> {
     pub owner: Signer<'info>,
     #[account(
         mut,
@@ -38,6 +56,8 @@ pub struct GrantHourlyGold<'info> {
         bump = player_economy.bump
     )]
     pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
 }
 
 pub fn initialize_player_economy(ctx: Context<InitializePlayerEconomy>) -> Result<()> {
@@ -53,14 +73,93 @@ pub fn initialize_player_economy(ctx: Context<InitializePlayerEconomy>) -> Resul
     account.bump = ctx.bumps.player_economy;
     account.gold = 0;
     account.last_grant_ts = 0;
-    account.items = [0; ITEM_COUNT];
-    account.reserved = [0; 5];
+    account.items = Vec::new();
+    account.stash_tier = 0;
+    account.reserved = [0; 4];
 
     emit!(PlayerEconomyInitialized { owner });
 
     Ok(())
 }
 
+/// Move an account written with a fixed `[u16; 7]` or `[u16; 8]` item array onto the
+/// registry-backed inventory, growing it to the new size at the owner's expense.
+pub fn migrate_player_economy(ctx: Context<MigratePlayerEconomy>) -> Result<()> {
+    let info = ctx.accounts.player_economy.to_account_info();
+    let owner = ctx.accounts.owner.key();
+
+    require_keys_eq!(*info.owner, crate::ID, PlayerEconomyError::Unauthorized);
+    let item_count = LEGACY_ITEM_COUNTS
+        .into_iter()
+        .find(|count| info.data_len() == legacy_player_economy_len(*count))
+        .ok_or(PlayerEconomyError::NotLegacyAccount)?;
+
+    let (stored_owner, gold, last_grant_ts, legacy_items, bump) = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data[..8] == *PlayerEconomy::DISCRIMINATOR,
+            PlayerEconomyError::AccountNotInitialized
+        );
+        let mut cursor = &data[8..];
+        (
+            Pubkey::deserialize(&mut cursor)?,
+            u64::deserialize(&mut cursor)?,
+            i64::deserialize(&mut cursor)?,
+            (0..item_count)
+                .map(|_| u16::deserialize(&mut cursor))
+                .collect::<std::io::Result<Vec<u16>>>()?,
+            u8::deserialize(&mut cursor)?,
+        )
+    };
+    require_keys_eq!(stored_owner, owner, PlayerEconomyError::Unauthorized);
+
+    let rent = Rent::get()?.minimum_balance(PlayerEconomy::LEN);
+    let shortfall = rent.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.owner.to_account_info(),
+            to: info.clone(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, shortfall)?;
+    }
+    info.resize(PlayerEconomy::LEN)?;
+
+    // Legacy slot indices were the item ids
+    let items: Vec<ItemStack> = legacy_items
+        .iter()
+        .enumerate()
+        .filter(|(_, quantity)| **quantity > 0)
+        .map(|(item, quantity)| ItemStack {
+            item: item as u8,
+            quantity: *quantity,
+        })
+        .collect();
+    let item_types = items.len() as u8;
+    let mut migrated = PlayerEconomy {
+        owner,
+        gold,
+        last_grant_ts,
+        bump,
+        stash_tier: 0,
+        reserved: [0; 4],
+        items,
+    };
+    // Legacy stashes had no slot limit, so start at the first tier that holds what's there
+    let used = migrated.used_slots();
+    migrated.stash_tier = STASH_TIER_SLOTS
+        .iter()
+        .position(|slots| *slots as u32 >= used)
+        .unwrap_or(STASH_TIER_SLOTS.len() - 1) as u8;
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut writer)?;
+
+    emit!(PlayerEconomyMigrated { owner, item_types });
+
+    Ok(())
+}
+
 pub fn spend_gold(ctx: Context<SpendGold>, amount: u64) -> Result<()> {
     require!(amount > 0, PlayerEconomyError::InvalidSpendAmount);
     let account = &mut ctx.accounts.player_economy;
@@ -70,6 +169,9 @@ pub fn spend_gold(ctx: Context<SpendGold>, amount: u64) -> Result<()> {
     require!(account.gold >= amount, PlayerEconomyError::InsufficientGold);
 
     account.gold -= amount;
+    ctx.accounts
+        .economy_stats
+        .record_gold_burned(Clock::get()?.unix_timestamp, amount);
 
     emit!(GoldSpent {
         owner,
@@ -80,33 +182,29 @@ pub fn spend_gold(ctx: Context<SpendGold>, amount: u64) -> Result<()> {
     Ok(())
 }
 
-pub fn grant_hourly_gold(ctx: Context<GrantHourlyGold>) -> Result<()> {
+/// Burn gold to raise the stash by one tier.
+pub fn upgrade_stash(ctx: Context<UpgradeStash>) -> Result<()> {
     let account = &mut ctx.accounts.player_economy;
     let owner = ctx.accounts.owner.key();
-    let now = Clock::get()?.unix_timestamp;
 
     require_keys_eq!(account.owner, owner, PlayerEconomyError::Unauthorized);
 
-    if account.last_grant_ts != 0 {
-        let elapsed = now
-            .checked_sub(account.last_grant_ts)
-            .ok_or(PlayerEconomyError::MathOverflow)?;
-        require!(
-            elapsed >= HOURLY_GRANT_COOLDOWN,
-            PlayerEconomyError::GrantOnCooldown
-        );
-    }
+    let cost = *STASH_UPGRADE_COSTS
+        .get(account.stash_tier as usize)
+        .ok_or(PlayerEconomyError::StashAtMaxTier)?;
+    require!(account.gold >= cost, PlayerEconomyError::InsufficientGold);
 
-    account.gold = account
-        .gold
-        .checked_add(HOURLY_GRANT_AMOUNT)
-        .ok_or(PlayerEconomyError::MathOverflow)?;
-    account.last_grant_ts = now;
+    account.gold -= cost;
+    account.stash_tier += 1;
+    ctx.accounts
+        .economy_stats
+        .record_gold_burned(Clock::get()?.unix_timestamp, cost);
 
-    emit!(HourlyGrantClaimed {
+    emit!(StashUpgraded {
         owner,
-        amount: HOURLY_GRANT_AMOUNT,
-        next_available_at: now + HOURLY_GRANT_COOLDOWN,
+        tier: account.stash_tier,
+        capacity: account.stash_capacity(),
+        cost,
     });
 
     Ok(())
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeFaucetConfig<'info> {
+    #[account(mut, address = ECONOMY_ADMIN @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = FaucetConfig::LEN,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFaucetConfig<'info> {
+    #[account(constraint = authority.key() == faucet_config.authority @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [FAUCET_CONFIG_SEED], bump = faucet_config.bump)]
+    pub faucet_config: Account<'info, FaucetConfig>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDailyGold<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [FAUCET_CONFIG_SEED], bump = faucet_config.bump)]
+    pub faucet_config: Account<'info, FaucetConfig>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = FaucetLedger::LEN,
+        seeds = [FAUCET_LEDGER_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub faucet_ledger: Account<'info, FaucetLedger>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_faucet_config(
+    ctx: Context<InitializeFaucetConfig>,
+    params: FaucetParams,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let authority = ctx.accounts.authority.key();
+
+    config.authority = authority;
+    config.bump = ctx.bumps.faucet_config;
+    config.params = params;
+
+    emit!(FaucetConfigUpdated { authority, params });
+
+    Ok(())
+}
+
+pub fn update_faucet_config(ctx: Context<UpdateFaucetConfig>, params: FaucetParams) -> Result<()> {
+    ctx.accounts.faucet_config.params = params;
+
+    emit!(FaucetConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        params,
+    });
+
+    Ok(())
+}
+
+/// Once-a-day login reward. Consecutive days grow the streak; missing a day resets it.
+pub fn claim_daily_gold(ctx: Context<ClaimDailyGold>) -> Result<()> {
+    let params = ctx.accounts.faucet_config.params;
+    let account = &mut ctx.accounts.player_economy;
+    let ledger = &mut ctx.accounts.faucet_ledger;
+    let owner = ctx.accounts.owner.key();
+    let now = Clock::get()?.unix_timestamp;
+    let today = now.div_euclid(FAUCET_DAY_SECONDS);
+
+    require_keys_eq!(account.owner, owner, PlayerEconomyError::Unauthorized);
+
+    if ledger.owner == Pubkey::default() {
+        ledger.owner = owner;
+        ledger.bump = ctx.bumps.faucet_ledger;
+    } else {
+        require!(
+            ledger.last_claim_day < today,
+            PlayerEconomyError::GrantOnCooldown
+        );
+    }
+
+    let streak = if ledger.streak > 0 && ledger.last_claim_day == today - 1 {
+        ledger.streak.saturating_add(1)
+    } else {
+        1
+    };
+
+    let mut amount = params.reward(streak, account.gold);
+    if params.lifetime_cap > 0 {
+        let remaining = params.lifetime_cap.saturating_sub(ledger.lifetime_granted);
+        require!(remaining > 0, PlayerEconomyError::FaucetCapReached);
+        amount = amount.min(remaining);
+    }
+
+    account.gold = account
+        .gold
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    account.last_grant_ts = now;
+    ledger.last_claim_day = today;
+    ledger.streak = streak;
+    ledger.lifetime_granted = ledger.lifetime_granted.saturating_add(amount);
+    ctx.accounts.economy_stats.record_gold_minted(now, amount);
+
+    emit!(DailyGrantClaimed {
+        owner,
+        amount,
+        streak,
+        lifetime_granted: ledger.lifetime_granted,
+        next_available_at: (today + 1) * FAUCET_DAY_SECONDS,
+    });
+
+    Ok(())
+}
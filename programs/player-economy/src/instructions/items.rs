@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ADVENTURE_ENGINE_PROGRAM_ID, PLAYER_ECONOMY_SEED},
+    constants::{
+        ADVENTURE_ENGINE_PROGRAM_ID, ECONOMY_STATS_SEED, ITEM_REGISTRY_SEED, PLAYER_ECONOMY_SEED,
+        SHOP_STATE_SEED,
+    },
     errors::PlayerEconomyError,
     state::*,
 };
@@ -16,6 +19,12 @@ pub struct ModifyItemStock<'info> {
         bump = player_economy.bump
     )]
     pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, seeds = [SHOP_STATE_SEED], bump = shop_state.bump)]
+    pub shop_state: Account<'info, ShopState>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
 }
 
 #[derive(Accounts)]
@@ -28,6 +37,8 @@ pub struct ConsumeItems<'info> {
         bump = player_economy.bump
     )]
     pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
 }
 
 #[derive(Accounts)]
@@ -40,13 +51,17 @@ pub struct DepositLoot<'info> {
         bump = player_economy.bump
     )]
     pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
 }
 
-pub fn buy_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) -> Result<()> {
+pub fn buy_item(ctx: Context<ModifyItemStock>, item: u8, quantity: u16) -> Result<()> {
     require!(quantity > 0, PlayerEconomyError::InvalidQuantity);
 
-    let definition = item.definition();
-    let price = definition
+    let definition = *ctx.accounts.item_registry.definition(item)?;
+    let base_price = definition
         .buy_price
         .ok_or(PlayerEconomyError::ItemNotPurchasable)?;
     let account = &mut ctx.accounts.player_economy;
@@ -57,6 +72,13 @@ pub fn buy_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) ->
         PlayerEconomyError::Unauthorized
     );
 
+    let now = Clock::get()?.unix_timestamp;
+    let shop = &mut ctx.accounts.shop_state;
+    shop.roll_epoch(now);
+    let entry = shop.entry_mut(item)?;
+    require!(entry.stock >= quantity, PlayerEconomyError::ShopSoldOut);
+
+    let price = entry.buy_price(base_price, quantity)?;
     let total_price = price
         .checked_mul(quantity as u64)
         .ok_or(PlayerEconomyError::MathOverflow)?;
@@ -66,37 +88,31 @@ pub fn buy_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) ->
         PlayerEconomyError::InsufficientGold
     );
 
-    let index = item.index();
-    let current = account.items[index];
-    let new_total = current
-        .checked_add(quantity)
-        .ok_or(PlayerEconomyError::InventoryOverflow)?;
-
-    if definition.max_stack > 0 {
-        require!(
-            new_total as u64 <= definition.max_stack as u64,
-            PlayerEconomyError::StackLimitExceeded
-        );
-    }
-
+    account.add_items(&definition, quantity)?;
     account.gold -= total_price;
-    account.items[index] = new_total;
+    entry.record_purchase(quantity);
+
+    let stats = &mut ctx.accounts.economy_stats;
+    stats.record_gold_burned(now, total_price);
+    stats.record_items_created(now, item, quantity);
 
     emit!(ItemPurchased {
         owner: account.owner,
-        item: item as u8,
+        item,
         quantity,
+        base_price,
         unit_price: price,
+        remaining_stock: entry.stock,
     });
 
     Ok(())
 }
 
-pub fn sell_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) -> Result<()> {
+pub fn sell_item(ctx: Context<ModifyItemStock>, item: u8, quantity: u16) -> Result<()> {
     require!(quantity > 0, PlayerEconomyError::InvalidQuantity);
 
-    let definition = item.definition();
-    let price = definition
+    let definition = *ctx.accounts.item_registry.definition(item)?;
+    let base_price = definition
         .sell_price
         .ok_or(PlayerEconomyError::ItemNotSellable)?;
     let account = &mut ctx.accounts.player_economy;
@@ -107,24 +123,36 @@ pub fn sell_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) ->
         PlayerEconomyError::Unauthorized
     );
 
-    let index = item.index();
-    let current = account.items[index];
-    require!(current >= quantity, PlayerEconomyError::InsufficientStock);
+    let now = Clock::get()?.unix_timestamp;
+    let shop = &mut ctx.accounts.shop_state;
+    shop.roll_epoch(now);
+    let entry = shop.entry_mut(item)?;
 
+    let mut price = entry.sell_price(base_price, quantity)?;
+    // Selling back must never pay more than buying the same item right now
+    if let Some(buy_price) = definition.buy_price {
+        price = price.min(entry.buy_price(buy_price, 0)?);
+    }
     let total_value = price
         .checked_mul(quantity as u64)
         .ok_or(PlayerEconomyError::MathOverflow)?;
 
-    account.items[index] = current - quantity;
+    account.remove_items(item, quantity)?;
     account.gold = account
         .gold
         .checked_add(total_value)
         .ok_or(PlayerEconomyError::MathOverflow)?;
+    entry.record_sale(quantity);
+
+    let stats = &mut ctx.accounts.economy_stats;
+    stats.record_gold_minted(now, total_value);
+    stats.record_items_destroyed(now, item, quantity);
 
     emit!(ItemSold {
         owner: account.owner,
-        item: item as u8,
+        item,
         quantity,
+        base_price,
         unit_price: price,
     });
 
@@ -146,22 +174,22 @@ pub fn consume_items(ctx: Context<ConsumeItems>, items: Vec<ItemConsumption>) ->
             PlayerEconomyError::InvalidQuantity
         );
 
-        let index = item_consumption.item.index();
-        let current = account.items[index];
         require!(
-            current >= item_consumption.quantity,
+            account.quantity(item_consumption.item) >= item_consumption.quantity,
             PlayerEconomyError::InsufficientStock
         );
     }
 
     // Second pass: deduct items
+    let now = Clock::get()?.unix_timestamp;
+    let stats = &mut ctx.accounts.economy_stats;
     for item_consumption in &items {
-        let index = item_consumption.item.index();
-        account.items[index] -= item_consumption.quantity;
+        account.remove_items(item_consumption.item, item_consumption.quantity)?;
+        stats.record_items_destroyed(now, item_consumption.item, item_consumption.quantity);
 
         emit!(ItemConsumed {
             owner: account.owner,
-            item: item_consumption.item as u8,
+            item: item_consumption.item,
             quantity: item_consumption.quantity,
         });
     }
@@ -188,13 +216,17 @@ pub fn deposit_loot(
         PlayerEconomyError::AccountNotInitialized
     );
 
+    let registry = &ctx.accounts.item_registry;
     let account = &mut ctx.accounts.player_economy;
+    let stats = &mut ctx.accounts.economy_stats;
+    let now = Clock::get()?.unix_timestamp;
 
     if gold > 0 {
         account.gold = account
             .gold
             .checked_add(gold)
             .ok_or(PlayerEconomyError::MathOverflow)?;
+        stats.record_gold_minted(now, gold);
     }
 
     for deposit in items {
@@ -202,21 +234,9 @@ pub fn deposit_loot(
             continue;
         }
 
-        let definition = deposit.item.definition();
-        let index = deposit.item.index();
-        let current = account.items[index];
-        let new_total = current
-            .checked_add(deposit.quantity)
-            .ok_or(PlayerEconomyError::InventoryOverflow)?;
-
-        if definition.max_stack > 0 {
-            require!(
-                new_total as u64 <= definition.max_stack as u64,
-                PlayerEconomyError::StackLimitExceeded
-            );
-        }
-
-        account.items[index] = new_total;
+        let definition = registry.definition(deposit.item)?;
+        account.add_items(definition, deposit.quantity)?;
+        stats.record_items_created(now, deposit.item, deposit.quantity);
     }
 
     emit!(LootDeposited {
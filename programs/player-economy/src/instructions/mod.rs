@@ -1,5 +1,23 @@
+pub mod bridge;
+pub mod crafting;
 pub mod economy;
+pub mod faucet;
 pub mod items;
+pub mod registry;
+pub mod relic;
+pub mod shop;
+pub mod stats;
+pub mod trading;
+pub mod transfer;
 
-pub use economy::{GrantHourlyGold, InitializePlayerEconomy, SpendGold};
+pub use bridge::{BridgeGold, InitializeGoldBridge, UpdateGoldBridge};
+pub use crafting::{CallbackCraft, Craft};
+pub use economy::{InitializePlayerEconomy, MigratePlayerEconomy, SpendGold, UpgradeStash};
+pub use faucet::{ClaimDailyGold, InitializeFaucetConfig, UpdateFaucetConfig};
 pub use items::{ConsumeItems, ModifyItemStock};
+pub use registry::{InitializeItemRegistry, UpdateItemRegistry};
+pub use relic::{CallbackIdentifyRelic, IdentifyRelic};
+pub use shop::{InitializeShop, UpdateShop};
+pub use stats::InitializeEconomyStats;
+pub use trading::{CancelOffer, FillOffer, PostOffer};
+pub use transfer::{InitializeTransferConfig, TransferGold, TransferItems, UpdateTransferConfig};
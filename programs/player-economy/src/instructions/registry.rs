@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeItemRegistry<'info> {
+    #[account(mut, address = ECONOMY_ADMIN @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = ItemRegistry::LEN,
+        seeds = [ITEM_REGISTRY_SEED],
+        bump
+    )]
+    pub item_registry: Account<'info, ItemRegistry>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateItemRegistry<'info> {
+    #[account(constraint = authority.key() == item_registry.authority @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+}
+
+pub fn initialize_item_registry(ctx: Context<InitializeItemRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.item_registry;
+    let authority = ctx.accounts.authority.key();
+
+    registry.authority = authority;
+    registry.bump = ctx.bumps.item_registry;
+    registry.items = DEFAULT_ITEM_DEFINITIONS.to_vec();
+
+    emit!(ItemRegistryInitialized {
+        authority,
+        item_count: registry.items.len() as u8,
+    });
+
+    Ok(())
+}
+
+/// Add a new item type or replace the definition of an existing one.
+pub fn set_item_definition(
+    ctx: Context<UpdateItemRegistry>,
+    definition: ItemDefinition,
+) -> Result<()> {
+    require!(
+        definition.key != RESERVED_ITEM_KEY,
+        PlayerEconomyError::ReservedItemKey
+    );
+    let registry = &mut ctx.accounts.item_registry;
+
+    if let Some(existing) = registry
+        .items
+        .iter_mut()
+        .find(|existing| existing.key == definition.key)
+    {
+        *existing = definition;
+    } else {
+        require!(
+            registry.items.len() < MAX_ITEM_TYPES,
+            PlayerEconomyError::RegistryFull
+        );
+        registry.items.push(definition);
+    }
+
+    emit!(ItemDefinitionUpdated {
+        authority: ctx.accounts.authority.key(),
+        item: definition.key,
+    });
+
+    Ok(())
+}
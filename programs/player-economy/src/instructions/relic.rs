@@ -0,0 +1,323 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::keccak::hashv;
+use anchor_lang::solana_program::program::invoke_signed;
+use ephemeral_vrf_sdk::anchor::vrf;
+use ephemeral_vrf_sdk::consts::{DEFAULT_QUEUE, VRF_PROGRAM_IDENTITY};
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+
+use crate::helpers::meta;
+use crate::relics::{roll_relic_reward, RelicReward};
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[vrf]
+#[derive(Accounts)]
+pub struct IdentifyRelic<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = IdentifyRequest::LEN,
+        seeds = [IDENTIFY_REQUEST_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub identify_request: Account<'info, IdentifyRequest>,
+    /// CHECK: Hero that takes a curse; decoded and checked in the handler.
+    #[account(owner = HERO_CORE_PROGRAM_ID)]
+    pub hero_mint: UncheckedAccount<'info>,
+    /// CHECK: VRF oracle queue; queue authority enforced off-chain and via VRF program.
+    #[account(mut, address = DEFAULT_QUEUE)]
+    pub oracle_queue: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelIdentifyRelic<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, owner.key().as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [IDENTIFY_REQUEST_SEED, owner.key().as_ref()],
+        bump = identify_request.bump
+    )]
+    pub identify_request: Account<'info, IdentifyRequest>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+}
+
+#[derive(Accounts)]
+pub struct CallbackIdentifyRelic<'info> {
+    #[account(address = VRF_PROGRAM_IDENTITY)]
+    pub program_identity: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [IDENTIFY_REQUEST_SEED, identify_request.owner.as_ref()],
+        bump = identify_request.bump
+    )]
+    pub identify_request: Account<'info, IdentifyRequest>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, identify_request.owner.as_ref()],
+        bump = player_economy.bump
+    )]
+    pub player_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+    /// CHECK: Checked against the hero stored on the request.
+    #[account(mut, address = identify_request.hero)]
+    pub hero_mint: UncheckedAccount<'info>,
+    /// CHECK: Checked against the request owner.
+    #[account(address = identify_request.owner)]
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: Data-less PDA that signs the hero-core CPI.
+    #[account(seeds = [RELIC_CURSE_SIGNER_SEED], bump)]
+    pub curse_signer: UncheckedAccount<'info>,
+    /// CHECK: hero-core program.
+    #[account(address = HERO_CORE_PROGRAM_ID)]
+    pub hero_core_program: UncheckedAccount<'info>,
+}
+
+/// Leading fields of hero-core's `HeroMint`, up to `locked`. hero-core depends on this crate,
+/// so its account type can't be used here.
+#[derive(AnchorDeserialize)]
+struct HeroMintHead {
+    owner: Pubkey,
+    bump: u8,
+    id: u64,
+    /// hero_type, level, experience
+    _progress: (u8, u8, u64),
+    /// max_hp through status_effects, then both skills
+    _stats: [u8; 11],
+    _traits: [[Option<u8>; 3]; 2],
+    _is_soulbound: bool,
+    is_burned: bool,
+    /// mint_timestamp, last_level_up
+    _timestamps: [i64; 2],
+    _pending_request: u8,
+    locked: bool,
+}
+
+/// The curse is skipped for heroes hero-core won't modify, so only those it will are accepted.
+fn check_curse_target(hero_mint: &AccountInfo, owner: Pubkey) -> Result<()> {
+    let data = hero_mint.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == HERO_MINT_DISCRIMINATOR,
+        PlayerEconomyError::InvalidHero
+    );
+    let hero = HeroMintHead::deserialize(&mut &data[8..])?;
+    let expected = Pubkey::create_program_address(
+        &[
+            HERO_SEED,
+            owner.as_ref(),
+            &hero.id.to_le_bytes(),
+            &[hero.bump],
+        ],
+        &HERO_CORE_PROGRAM_ID,
+    )
+    .map_err(|_| error!(PlayerEconomyError::InvalidHero))?;
+    require_keys_eq!(expected, hero_mint.key(), PlayerEconomyError::InvalidHero);
+    require_keys_eq!(hero.owner, owner, PlayerEconomyError::InvalidHero);
+    require!(
+        !hero.is_burned && !hero.locked,
+        PlayerEconomyError::InvalidHero
+    );
+    Ok(())
+}
+
+/// Spend a Mystery Relic and the identify fee, then roll the reward through VRF. `hero_mint`
+/// is the hero that suffers a curse if one comes up.
+pub fn identify_relic(ctx: Context<IdentifyRelic>) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let hero = ctx.accounts.hero_mint.key();
+    require!(
+        !ctx.accounts.identify_request.pending,
+        PlayerEconomyError::IdentifyInProgress
+    );
+    check_curse_target(&ctx.accounts.hero_mint.to_account_info(), owner)?;
+
+    let account = &mut ctx.accounts.player_economy;
+    require!(
+        account.gold >= IDENTIFY_RELIC_FEE,
+        PlayerEconomyError::InsufficientGold
+    );
+    account.remove_items(ITEM_MYSTERY_RELIC, 1)?;
+    account.gold -= IDENTIFY_RELIC_FEE;
+
+    let now = Clock::get()?.unix_timestamp;
+    let stats = &mut ctx.accounts.economy_stats;
+    stats.record_items_destroyed(now, ITEM_MYSTERY_RELIC, 1);
+    stats.record_gold_burned(now, IDENTIFY_RELIC_FEE);
+
+    let request = &mut ctx.accounts.identify_request;
+    request.owner = owner;
+    request.bump = ctx.bumps.identify_request;
+    request.pending = true;
+    request.hero = hero;
+    request.requested_at = now;
+
+    let (curse_signer, _) = Pubkey::find_program_address(&[RELIC_CURSE_SIGNER_SEED], &crate::ID);
+    let caller_seed = hashv(&[&owner.to_bytes(), &hero.to_bytes(), &now.to_le_bytes()]).0;
+    let ix = create_request_randomness_ix(RequestRandomnessParams {
+        payer: owner,
+        oracle_queue: ctx.accounts.oracle_queue.key(),
+        callback_program_id: crate::ID,
+        callback_discriminator: crate::instruction::CallbackIdentifyRelic::DISCRIMINATOR.to_vec(),
+        accounts_metas: Some(vec![
+            meta(ctx.accounts.identify_request.key(), true),
+            meta(ctx.accounts.player_economy.key(), true),
+            meta(ctx.accounts.item_registry.key(), false),
+            meta(ctx.accounts.economy_stats.key(), true),
+            meta(hero, true),
+            meta(owner, false),
+            meta(curse_signer, false),
+            meta(HERO_CORE_PROGRAM_ID, false),
+        ]),
+        caller_seed,
+        callback_args: None,
+    });
+    ctx.accounts
+        .invoke_signed_vrf(&ctx.accounts.owner.to_account_info(), &ix)?;
+
+    emit!(RelicIdentifyRequested { owner, hero });
+
+    Ok(())
+}
+
+pub fn callback_identify_relic(
+    ctx: Context<CallbackIdentifyRelic>,
+    randomness: [u8; 32],
+) -> Result<()> {
+    require!(
+        ctx.accounts.identify_request.pending,
+        PlayerEconomyError::UnexpectedCallback
+    );
+    let owner = ctx.accounts.identify_request.owner;
+    let hero = ctx.accounts.identify_request.hero;
+
+    let roll = u16::from_le_bytes([randomness[0], randomness[1]]);
+    let (reward, gold, item, quantity) = match *roll_relic_reward(roll) {
+        RelicReward::Gold(amount) => (RELIC_REWARD_GOLD, amount, 0, 0),
+        RelicReward::Item { item, quantity } => {
+            let registry = &ctx.accounts.item_registry;
+            let account = &mut ctx.accounts.player_economy;
+            let granted = registry
+                .definition(item)
+                .and_then(|definition| account.add_items(definition, quantity));
+            if granted.is_ok() {
+                (RELIC_REWARD_ITEM, 0, item, quantity)
+            } else {
+                // No room for the item, so pay out its value instead
+                let unit = registry
+                    .get(item)
+                    .and_then(|definition| definition.sell_price)
+                    .unwrap_or(RELIC_FALLBACK_GOLD);
+                (RELIC_REWARD_GOLD, unit * quantity as u64, 0, 0)
+            }
+        }
+        RelicReward::Curse => {
+            apply_curse(&ctx, randomness[2])?;
+            (RELIC_REWARD_CURSE, 0, 0, 0)
+        }
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    if gold > 0 {
+        let account = &mut ctx.accounts.player_economy;
+        account.gold = account
+            .gold
+            .checked_add(gold)
+            .ok_or(PlayerEconomyError::MathOverflow)?;
+        ctx.accounts.economy_stats.record_gold_minted(now, gold);
+    }
+    if quantity > 0 {
+        ctx.accounts
+            .economy_stats
+            .record_items_created(now, item, quantity);
+    }
+    ctx.accounts.identify_request.pending = false;
+
+    emit!(RelicIdentified {
+        owner,
+        hero,
+        reward,
+        gold,
+        item,
+        quantity,
+    });
+
+    Ok(())
+}
+
+/// Return the relic and fee of an identify whose VRF callback never arrived.
+pub fn cancel_identify_relic(ctx: Context<CancelIdentifyRelic>) -> Result<()> {
+    let request = &mut ctx.accounts.identify_request;
+    require!(request.pending, PlayerEconomyError::NoPendingRequest);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        vrf_request_expired(request.requested_at, now),
+        PlayerEconomyError::RequestNotExpired
+    );
+
+    let account = &mut ctx.accounts.player_economy;
+    account.return_items(ITEM_MYSTERY_RELIC, 1)?;
+    account.gold = account
+        .gold
+        .checked_add(IDENTIFY_RELIC_FEE)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    let stats = &mut ctx.accounts.economy_stats;
+    stats.record_items_created(now, ITEM_MYSTERY_RELIC, 1);
+    stats.record_gold_minted(now, IDENTIFY_RELIC_FEE);
+    request.pending = false;
+
+    emit!(RelicIdentifyCancelled {
+        owner: request.owner,
+        hero: request.hero,
+    });
+
+    Ok(())
+}
+
+fn apply_curse(ctx: &Context<CallbackIdentifyRelic>, trait_roll: u8) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let mut data = APPLY_RELIC_CURSE_DISCRIMINATOR.to_vec();
+    data.push(trait_roll);
+    let ix = Instruction {
+        program_id: HERO_CORE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.curse_signer.key(), true),
+            AccountMeta::new_readonly(accounts.owner.key(), false),
+            AccountMeta::new(accounts.hero_mint.key(), false),
+        ],
+        data,
+    };
+    let bump = [ctx.bumps.curse_signer];
+    let signer_seeds: &[&[&[u8]]] = &[&[RELIC_CURSE_SIGNER_SEED, &bump]];
+    invoke_signed(
+        &ix,
+        &[
+            accounts.curse_signer.to_account_info(),
+            accounts.owner.to_account_info(),
+            accounts.hero_mint.to_account_info(),
+            accounts.hero_core_program.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeShop<'info> {
+    #[account(mut, address = ECONOMY_ADMIN @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = ShopState::LEN,
+        seeds = [SHOP_STATE_SEED],
+        bump
+    )]
+    pub shop_state: Account<'info, ShopState>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateShop<'info> {
+    #[account(constraint = authority.key() == shop_state.authority @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [SHOP_STATE_SEED], bump = shop_state.bump)]
+    pub shop_state: Account<'info, ShopState>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+}
+
+pub fn initialize_shop(ctx: Context<InitializeShop>) -> Result<()> {
+    let shop = &mut ctx.accounts.shop_state;
+    let authority = ctx.accounts.authority.key();
+    let epoch = Clock::get()?.unix_timestamp.div_euclid(SHOP_EPOCH_SECONDS);
+
+    shop.authority = authority;
+    shop.bump = ctx.bumps.shop_state;
+    shop.epoch = epoch;
+    shop.items = Vec::new();
+
+    emit!(ShopInitialized { authority, epoch });
+
+    Ok(())
+}
+
+/// Set how many units of `item` the shop restocks to each epoch. Stock is refilled immediately.
+pub fn set_shop_item_stock(ctx: Context<UpdateShop>, item: u8, daily_stock: u16) -> Result<()> {
+    ctx.accounts.item_registry.definition(item)?;
+
+    let shop = &mut ctx.accounts.shop_state;
+    shop.roll_epoch(Clock::get()?.unix_timestamp);
+
+    let entry = shop.entry_mut(item)?;
+    entry.daily_stock = daily_stock;
+    entry.stock = daily_stock;
+
+    emit!(ShopStockConfigured {
+        authority: ctx.accounts.authority.key(),
+        item,
+        daily_stock,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+#[instruction(shard: u8)]
+pub struct InitializeEconomyStats<'info> {
+    #[account(mut, address = ECONOMY_ADMIN @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = EconomyStats::LEN,
+        seeds = [ECONOMY_STATS_SEED, &[shard]],
+        bump
+    )]
+    pub economy_stats: Account<'info, EconomyStats>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_economy_stats(ctx: Context<InitializeEconomyStats>, shard: u8) -> Result<()> {
+    require!(
+        shard < ECONOMY_STATS_SHARDS,
+        PlayerEconomyError::InvalidStatsShard
+    );
+
+    let stats = &mut ctx.accounts.economy_stats;
+    stats.bump = ctx.bumps.economy_stats;
+    stats.shard = shard;
+    stats.gold_minted = 0;
+    stats.gold_burned = 0;
+    stats.items = Vec::new();
+    stats.daily = vec![DailyEconomyBucket::default(); ECONOMY_STATS_DAYS];
+
+    Ok(())
+}
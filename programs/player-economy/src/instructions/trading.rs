@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+
+use super::transfer::{prepare_ledger, record_gold_moved};
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+#[instruction(offer_id: u64)]
+pub struct PostOffer<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, seller.key().as_ref()],
+        bump = seller_economy.bump
+    )]
+    pub seller_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        init,
+        payer = seller,
+        space = TradeOffer::LEN,
+        seeds = [TRADE_OFFER_SEED, seller.key().as_ref(), &offer_id.to_le_bytes()],
+        bump
+    )]
+    pub offer: Account<'info, TradeOffer>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FillOffer<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, buyer.key().as_ref()],
+        bump = buyer_economy.bump
+    )]
+    pub buyer_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, offer.seller.as_ref()],
+        bump = seller_economy.bump
+    )]
+    pub seller_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [TRADE_OFFER_SEED, offer.seller.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump = offer.bump,
+        constraint = offer.seller != buyer.key() @ PlayerEconomyError::SelfTrade
+    )]
+    pub offer: Account<'info, TradeOffer>,
+    /// Receives the offer rent once the last unit is bought.
+    #[account(mut, address = offer.seller)]
+    pub seller: SystemAccount<'info>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(mut, seeds = [ECONOMY_STATS_SEED, &[economy_stats.shard]], bump = economy_stats.bump)]
+    pub economy_stats: Account<'info, EconomyStats>,
+    #[account(seeds = [TRANSFER_CONFIG_SEED], bump = transfer_config.bump)]
+    pub transfer_config: Account<'info, TransferConfig>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = TransferLedger::LEN,
+        seeds = [TRANSFER_LEDGER_SEED, buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_ledger: Account<'info, TransferLedger>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = TransferLedger::LEN,
+        seeds = [TRANSFER_LEDGER_SEED, offer.seller.as_ref()],
+        bump
+    )]
+    pub seller_ledger: Account<'info, TransferLedger>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, seller.key().as_ref()],
+        bump = seller_economy.bump
+    )]
+    pub seller_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [TRADE_OFFER_SEED, seller.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, TradeOffer>,
+}
+
+pub fn post_offer(
+    ctx: Context<PostOffer>,
+    offer_id: u64,
+    item: u8,
+    quantity: u16,
+    price_per_unit: u64,
+) -> Result<()> {
+    require!(quantity > 0, PlayerEconomyError::InvalidQuantity);
+    require!(price_per_unit > 0, PlayerEconomyError::InvalidPrice);
+    ctx.accounts.item_registry.definition(item)?;
+
+    let seller = ctx.accounts.seller.key();
+    ctx.accounts.seller_economy.remove_items(item, quantity)?;
+
+    let offer = &mut ctx.accounts.offer;
+    offer.seller = seller;
+    offer.offer_id = offer_id;
+    offer.item = item;
+    offer.quantity = quantity;
+    offer.price_per_unit = price_per_unit;
+    offer.created_at = Clock::get()?.unix_timestamp;
+    offer.bump = ctx.bumps.offer;
+
+    emit!(OfferPosted {
+        seller,
+        offer_id,
+        item,
+        quantity,
+        price_per_unit,
+    });
+
+    Ok(())
+}
+
+pub fn fill_offer(ctx: Context<FillOffer>, quantity: u16) -> Result<()> {
+    require!(quantity > 0, PlayerEconomyError::InvalidQuantity);
+
+    let offer = &mut ctx.accounts.offer;
+    require!(
+        quantity <= offer.quantity,
+        PlayerEconomyError::OfferQuantityExceeded
+    );
+
+    let definition = ctx.accounts.item_registry.definition(offer.item)?;
+    let total_price = offer
+        .price_per_unit
+        .checked_mul(quantity as u64)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    // The fee is burned by crediting the seller only the remainder. A 1-gold sale pays none,
+    // since the minimum fee would take the whole price.
+    let fee = total_price
+        .checked_mul(TRADE_FEE_BPS)
+        .ok_or(PlayerEconomyError::MathOverflow)?
+        / 10_000;
+    let fee = if total_price > 1 { fee.max(1) } else { 0 };
+
+    // Trades move gold between players, so they share the transfer limits
+    let buyer_key = ctx.accounts.buyer.key();
+    let limit = ctx.accounts.transfer_config.daily_gold_limit;
+    let buyer_ledger = &mut ctx.accounts.buyer_ledger;
+    prepare_ledger(buyer_ledger, buyer_key, ctx.bumps.buyer_ledger)?;
+    let seller_ledger = &mut ctx.accounts.seller_ledger;
+    prepare_ledger(seller_ledger, offer.seller, ctx.bumps.seller_ledger)?;
+    record_gold_moved(buyer_ledger, seller_ledger, limit, total_price)?;
+
+    let buyer = &mut ctx.accounts.buyer_economy;
+    require!(
+        buyer.gold >= total_price,
+        PlayerEconomyError::InsufficientGold
+    );
+    buyer.add_items(definition, quantity)?;
+    buyer.gold -= total_price;
+
+    let seller = &mut ctx.accounts.seller_economy;
+    seller.gold = seller
+        .gold
+        .checked_add(total_price - fee)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+
+    offer.quantity -= quantity;
+    ctx.accounts
+        .economy_stats
+        .record_gold_burned(Clock::get()?.unix_timestamp, fee);
+
+    emit!(OfferFilled {
+        seller: offer.seller,
+        buyer: buyer.owner,
+        offer_id: offer.offer_id,
+        item: offer.item,
+        quantity,
+        price_per_unit: offer.price_per_unit,
+        fee,
+        remaining: offer.quantity,
+    });
+
+    if offer.quantity == 0 {
+        offer.close(ctx.accounts.seller.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    ctx.accounts
+        .seller_economy
+        .return_items(offer.item, offer.quantity)?;
+
+    emit!(OfferCancelled {
+        seller: offer.seller,
+        offer_id: offer.offer_id,
+        item: offer.item,
+        returned: offer.quantity,
+    });
+
+    Ok(())
+}
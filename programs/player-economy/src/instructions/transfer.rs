@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::PlayerEconomyError, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeTransferConfig<'info> {
+    #[account(mut, address = ECONOMY_ADMIN @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = TransferConfig::LEN,
+        seeds = [TRANSFER_CONFIG_SEED],
+        bump
+    )]
+    pub transfer_config: Account<'info, TransferConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTransferConfig<'info> {
+    #[account(constraint = authority.key() == transfer_config.authority @ PlayerEconomyError::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [TRANSFER_CONFIG_SEED], bump = transfer_config.bump)]
+    pub transfer_config: Account<'info, TransferConfig>,
+}
+
+#[derive(Accounts)]
+pub struct TransferGold<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, sender.key().as_ref()],
+        bump = sender_economy.bump
+    )]
+    pub sender_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, recipient_economy.owner.as_ref()],
+        bump = recipient_economy.bump,
+        constraint = recipient_economy.owner != sender.key() @ PlayerEconomyError::SelfTransfer
+    )]
+    pub recipient_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [TRANSFER_CONFIG_SEED], bump = transfer_config.bump)]
+    pub transfer_config: Account<'info, TransferConfig>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = TransferLedger::LEN,
+        seeds = [TRANSFER_LEDGER_SEED, sender.key().as_ref()],
+        bump
+    )]
+    pub sender_ledger: Account<'info, TransferLedger>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = TransferLedger::LEN,
+        seeds = [TRANSFER_LEDGER_SEED, recipient_economy.owner.as_ref()],
+        bump
+    )]
+    pub recipient_ledger: Account<'info, TransferLedger>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferItems<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, sender.key().as_ref()],
+        bump = sender_economy.bump
+    )]
+    pub sender_economy: Account<'info, PlayerEconomy>,
+    #[account(
+        mut,
+        seeds = [PLAYER_ECONOMY_SEED, recipient_economy.owner.as_ref()],
+        bump = recipient_economy.bump,
+        constraint = recipient_economy.owner != sender.key() @ PlayerEconomyError::SelfTransfer
+    )]
+    pub recipient_economy: Account<'info, PlayerEconomy>,
+    #[account(seeds = [ITEM_REGISTRY_SEED], bump = item_registry.bump)]
+    pub item_registry: Account<'info, ItemRegistry>,
+    #[account(seeds = [TRANSFER_CONFIG_SEED], bump = transfer_config.bump)]
+    pub transfer_config: Account<'info, TransferConfig>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = TransferLedger::LEN,
+        seeds = [TRANSFER_LEDGER_SEED, sender.key().as_ref()],
+        bump
+    )]
+    pub sender_ledger: Account<'info, TransferLedger>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = TransferLedger::LEN,
+        seeds = [TRANSFER_LEDGER_SEED, recipient_economy.owner.as_ref()],
+        bump
+    )]
+    pub recipient_ledger: Account<'info, TransferLedger>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_transfer_config(
+    ctx: Context<InitializeTransferConfig>,
+    daily_gold_limit: u64,
+    daily_item_limit: u32,
+) -> Result<()> {
+    let config = &mut ctx.accounts.transfer_config;
+    let authority = ctx.accounts.authority.key();
+
+    config.authority = authority;
+    config.bump = ctx.bumps.transfer_config;
+    config.daily_gold_limit = daily_gold_limit;
+    config.daily_item_limit = daily_item_limit;
+
+    emit!(TransferConfigUpdated {
+        authority,
+        daily_gold_limit,
+        daily_item_limit,
+    });
+
+    Ok(())
+}
+
+pub fn update_transfer_config(
+    ctx: Context<UpdateTransferConfig>,
+    daily_gold_limit: u64,
+    daily_item_limit: u32,
+) -> Result<()> {
+    let config = &mut ctx.accounts.transfer_config;
+    config.daily_gold_limit = daily_gold_limit;
+    config.daily_item_limit = daily_item_limit;
+
+    emit!(TransferConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        daily_gold_limit,
+        daily_item_limit,
+    });
+
+    Ok(())
+}
+
+pub fn transfer_gold(ctx: Context<TransferGold>, amount: u64) -> Result<()> {
+    require!(amount > 0, PlayerEconomyError::InvalidQuantity);
+
+    let sender = ctx.accounts.sender.key();
+    let recipient = ctx.accounts.recipient_economy.owner;
+    let limit = ctx.accounts.transfer_config.daily_gold_limit;
+    let ledger = &mut ctx.accounts.sender_ledger;
+    prepare_ledger(ledger, sender, ctx.bumps.sender_ledger)?;
+    let recipient_ledger = &mut ctx.accounts.recipient_ledger;
+    prepare_ledger(recipient_ledger, recipient, ctx.bumps.recipient_ledger)?;
+
+    record_gold_moved(ledger, recipient_ledger, limit, amount)?;
+
+    let from = &mut ctx.accounts.sender_economy;
+    require!(from.gold >= amount, PlayerEconomyError::InsufficientGold);
+    let to = &mut ctx.accounts.recipient_economy;
+    to.gold = to
+        .gold
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    from.gold -= amount;
+
+    emit!(GoldTransferred {
+        from: sender,
+        to: to.owner,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn transfer_items(ctx: Context<TransferItems>, items: Vec<TransferItem>) -> Result<()> {
+    require!(!items.is_empty(), PlayerEconomyError::InvalidQuantity);
+
+    let sender = ctx.accounts.sender.key();
+    let recipient = ctx.accounts.recipient_economy.owner;
+    let limit = ctx.accounts.transfer_config.daily_item_limit;
+    let ledger = &mut ctx.accounts.sender_ledger;
+    prepare_ledger(ledger, sender, ctx.bumps.sender_ledger)?;
+    let recipient_ledger = &mut ctx.accounts.recipient_ledger;
+    prepare_ledger(recipient_ledger, recipient, ctx.bumps.recipient_ledger)?;
+
+    let mut units: u32 = 0;
+    for transfer in &items {
+        require!(transfer.quantity > 0, PlayerEconomyError::InvalidQuantity);
+        units += transfer.quantity as u32;
+    }
+    let items_sent = ledger
+        .items_sent
+        .checked_add(units)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    let items_received = recipient_ledger
+        .items_received
+        .checked_add(units)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    if limit > 0 {
+        require!(
+            items_sent <= limit,
+            PlayerEconomyError::TransferLimitExceeded
+        );
+        require!(
+            items_received <= limit,
+            PlayerEconomyError::RecipientLimitExceeded
+        );
+    }
+
+    let registry = &ctx.accounts.item_registry;
+    let from = &mut ctx.accounts.sender_economy;
+    let to = &mut ctx.accounts.recipient_economy;
+
+    // Any shortfall or receiver stack overflow fails the whole transfer
+    for transfer in &items {
+        let definition = registry.definition(transfer.item)?;
+        from.remove_items(transfer.item, transfer.quantity)?;
+        to.add_items(definition, transfer.quantity)?;
+    }
+    ledger.items_sent = items_sent;
+    recipient_ledger.items_received = items_received;
+
+    emit!(ItemsTransferred {
+        from: sender,
+        to: to.owner,
+        items,
+    });
+
+    Ok(())
+}
+
+/// Count `amount` gold moving between two players against both ledgers' daily limits.
+pub(crate) fn record_gold_moved(
+    sender_ledger: &mut TransferLedger,
+    recipient_ledger: &mut TransferLedger,
+    limit: u64,
+    amount: u64,
+) -> Result<()> {
+    let gold_sent = sender_ledger
+        .gold_sent
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    let gold_received = recipient_ledger
+        .gold_received
+        .checked_add(amount)
+        .ok_or(PlayerEconomyError::MathOverflow)?;
+    if limit > 0 {
+        require!(
+            gold_sent <= limit,
+            PlayerEconomyError::TransferLimitExceeded
+        );
+        require!(
+            gold_received <= limit,
+            PlayerEconomyError::RecipientLimitExceeded
+        );
+    }
+    sender_ledger.gold_sent = gold_sent;
+    recipient_ledger.gold_received = gold_received;
+    Ok(())
+}
+
+pub(crate) fn prepare_ledger(ledger: &mut TransferLedger, owner: Pubkey, bump: u8) -> Result<()> {
+    if ledger.owner == Pubkey::default() {
+        ledger.owner = owner;
+        ledger.bump = bump;
+    }
+    ledger.roll_window(Clock::get()?.unix_timestamp);
+    Ok(())
+}
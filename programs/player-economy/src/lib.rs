@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod helpers;
 pub mod instructions;
+pub mod recipes;
+pub mod relics;
 pub mod state;
 
 pub use constants::*;
@@ -10,14 +13,41 @@ pub use errors::*;
 pub use state::*;
 
 // Import client account functions for Anchor macro
+pub(crate) use instructions::bridge::{
+    __client_accounts_bridge_gold, __client_accounts_initialize_gold_bridge,
+    __client_accounts_update_gold_bridge,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::bridge::{
+    __cpi_client_accounts_bridge_gold, __cpi_client_accounts_initialize_gold_bridge,
+    __cpi_client_accounts_update_gold_bridge,
+};
+pub(crate) use instructions::crafting::{
+    __client_accounts_callback_craft, __client_accounts_cancel_craft,
+    __client_accounts_claim_crafted_items, __client_accounts_craft,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::crafting::{
+    __cpi_client_accounts_callback_craft, __cpi_client_accounts_cancel_craft,
+    __cpi_client_accounts_claim_crafted_items, __cpi_client_accounts_craft,
+};
 pub(crate) use instructions::economy::{
-    __client_accounts_grant_hourly_gold, __client_accounts_initialize_player_economy,
-    __client_accounts_spend_gold,
+    __client_accounts_initialize_player_economy, __client_accounts_migrate_player_economy,
+    __client_accounts_spend_gold, __client_accounts_upgrade_stash,
 };
 #[cfg(feature = "cpi")]
 pub(crate) use instructions::economy::{
-    __cpi_client_accounts_grant_hourly_gold, __cpi_client_accounts_initialize_player_economy,
-    __cpi_client_accounts_spend_gold,
+    __cpi_client_accounts_initialize_player_economy, __cpi_client_accounts_migrate_player_economy,
+    __cpi_client_accounts_spend_gold, __cpi_client_accounts_upgrade_stash,
+};
+pub(crate) use instructions::faucet::{
+    __client_accounts_claim_daily_gold, __client_accounts_initialize_faucet_config,
+    __client_accounts_update_faucet_config,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::faucet::{
+    __cpi_client_accounts_claim_daily_gold, __cpi_client_accounts_initialize_faucet_config,
+    __cpi_client_accounts_update_faucet_config,
 };
 pub(crate) use instructions::items::{
     __client_accounts_consume_items, __client_accounts_deposit_loot,
@@ -28,10 +58,62 @@ pub(crate) use instructions::items::{
     __cpi_client_accounts_consume_items, __cpi_client_accounts_deposit_loot,
     __cpi_client_accounts_modify_item_stock,
 };
+pub(crate) use instructions::registry::{
+    __client_accounts_initialize_item_registry, __client_accounts_update_item_registry,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::registry::{
+    __cpi_client_accounts_initialize_item_registry, __cpi_client_accounts_update_item_registry,
+};
+pub(crate) use instructions::relic::{
+    __client_accounts_callback_identify_relic, __client_accounts_cancel_identify_relic,
+    __client_accounts_identify_relic,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::relic::{
+    __cpi_client_accounts_callback_identify_relic, __cpi_client_accounts_cancel_identify_relic,
+    __cpi_client_accounts_identify_relic,
+};
+pub(crate) use instructions::shop::{
+    __client_accounts_initialize_shop, __client_accounts_update_shop,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::shop::{
+    __cpi_client_accounts_initialize_shop, __cpi_client_accounts_update_shop,
+};
+pub(crate) use instructions::stats::__client_accounts_initialize_economy_stats;
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::stats::__cpi_client_accounts_initialize_economy_stats;
+pub(crate) use instructions::trading::{
+    __client_accounts_cancel_offer, __client_accounts_fill_offer, __client_accounts_post_offer,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::trading::{
+    __cpi_client_accounts_cancel_offer, __cpi_client_accounts_fill_offer,
+    __cpi_client_accounts_post_offer,
+};
+pub(crate) use instructions::transfer::{
+    __client_accounts_initialize_transfer_config, __client_accounts_transfer_gold,
+    __client_accounts_transfer_items, __client_accounts_update_transfer_config,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use instructions::transfer::{
+    __cpi_client_accounts_initialize_transfer_config, __cpi_client_accounts_transfer_gold,
+    __cpi_client_accounts_transfer_items, __cpi_client_accounts_update_transfer_config,
+};
 
 pub use instructions::{
-    economy::{GrantHourlyGold, InitializePlayerEconomy, SpendGold},
+    bridge::{BridgeGold, InitializeGoldBridge, UpdateGoldBridge},
+    crafting::{CallbackCraft, CancelCraft, ClaimCraftedItems, Craft},
+    economy::{InitializePlayerEconomy, MigratePlayerEconomy, SpendGold, UpgradeStash},
+    faucet::{ClaimDailyGold, InitializeFaucetConfig, UpdateFaucetConfig},
     items::{ConsumeItems, DepositLoot, ModifyItemStock},
+    registry::{InitializeItemRegistry, UpdateItemRegistry},
+    relic::{CallbackIdentifyRelic, CancelIdentifyRelic, IdentifyRelic},
+    shop::{InitializeShop, UpdateShop},
+    stats::InitializeEconomyStats,
+    trading::{CancelOffer, FillOffer, PostOffer},
+    transfer::{InitializeTransferConfig, TransferGold, TransferItems, UpdateTransferConfig},
 };
 
 declare_id!("8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ");
@@ -40,19 +122,51 @@ declare_id!("8YrnrrGJpPaghXZUQ7Pwz2ST972HqRcxVsAbThPpA5bZ");
 pub mod player_economy {
     use super::*;
     use instructions::{
-        economy::{GrantHourlyGold, InitializePlayerEconomy, SpendGold},
+        bridge::{BridgeGold, InitializeGoldBridge, UpdateGoldBridge},
+        crafting::{CallbackCraft, CancelCraft, ClaimCraftedItems, Craft},
+        economy::{InitializePlayerEconomy, MigratePlayerEconomy, SpendGold, UpgradeStash},
+        faucet::{ClaimDailyGold, InitializeFaucetConfig, UpdateFaucetConfig},
         items::{ConsumeItems, DepositLoot, ModifyItemStock},
+        registry::{InitializeItemRegistry, UpdateItemRegistry},
+        relic::{CallbackIdentifyRelic, CancelIdentifyRelic, IdentifyRelic},
+        shop::{InitializeShop, UpdateShop},
+        stats::InitializeEconomyStats,
+        trading::{CancelOffer, FillOffer, PostOffer},
+        transfer::{InitializeTransferConfig, TransferGold, TransferItems, UpdateTransferConfig},
     };
 
     pub fn initialize_player_economy(ctx: Context<InitializePlayerEconomy>) -> Result<()> {
         instructions::economy::initialize_player_economy(ctx)
     }
 
-    pub fn buy_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) -> Result<()> {
+    pub fn migrate_player_economy(ctx: Context<MigratePlayerEconomy>) -> Result<()> {
+        instructions::economy::migrate_player_economy(ctx)
+    }
+
+    pub fn initialize_item_registry(ctx: Context<InitializeItemRegistry>) -> Result<()> {
+        instructions::registry::initialize_item_registry(ctx)
+    }
+
+    pub fn set_item_definition(
+        ctx: Context<UpdateItemRegistry>,
+        definition: ItemDefinition,
+    ) -> Result<()> {
+        instructions::registry::set_item_definition(ctx, definition)
+    }
+
+    pub fn initialize_shop(ctx: Context<InitializeShop>) -> Result<()> {
+        instructions::shop::initialize_shop(ctx)
+    }
+
+    pub fn set_shop_item_stock(ctx: Context<UpdateShop>, item: u8, daily_stock: u16) -> Result<()> {
+        instructions::shop::set_shop_item_stock(ctx, item, daily_stock)
+    }
+
+    pub fn buy_item(ctx: Context<ModifyItemStock>, item: u8, quantity: u16) -> Result<()> {
         instructions::items::buy_item(ctx, item, quantity)
     }
 
-    pub fn sell_item(ctx: Context<ModifyItemStock>, item: ItemKey, quantity: u16) -> Result<()> {
+    pub fn sell_item(ctx: Context<ModifyItemStock>, item: u8, quantity: u16) -> Result<()> {
         instructions::items::sell_item(ctx, item, quantity)
     }
 
@@ -60,8 +174,26 @@ pub mod player_economy {
         instructions::economy::spend_gold(ctx, amount)
     }
 
-    pub fn grant_hourly_gold(ctx: Context<GrantHourlyGold>) -> Result<()> {
-        instructions::economy::grant_hourly_gold(ctx)
+    pub fn upgrade_stash(ctx: Context<UpgradeStash>) -> Result<()> {
+        instructions::economy::upgrade_stash(ctx)
+    }
+
+    pub fn initialize_faucet_config(
+        ctx: Context<InitializeFaucetConfig>,
+        params: FaucetParams,
+    ) -> Result<()> {
+        instructions::faucet::initialize_faucet_config(ctx, params)
+    }
+
+    pub fn update_faucet_config(
+        ctx: Context<UpdateFaucetConfig>,
+        params: FaucetParams,
+    ) -> Result<()> {
+        instructions::faucet::update_faucet_config(ctx, params)
+    }
+
+    pub fn claim_daily_gold(ctx: Context<ClaimDailyGold>) -> Result<()> {
+        instructions::faucet::claim_daily_gold(ctx)
     }
 
     pub fn consume_items(ctx: Context<ConsumeItems>, items: Vec<ItemConsumption>) -> Result<()> {
@@ -75,4 +207,111 @@ pub mod player_economy {
     ) -> Result<()> {
         instructions::items::deposit_loot(ctx, gold, items)
     }
+
+    pub fn initialize_transfer_config(
+        ctx: Context<InitializeTransferConfig>,
+        daily_gold_limit: u64,
+        daily_item_limit: u32,
+    ) -> Result<()> {
+        instructions::transfer::initialize_transfer_config(ctx, daily_gold_limit, daily_item_limit)
+    }
+
+    pub fn update_transfer_config(
+        ctx: Context<UpdateTransferConfig>,
+        daily_gold_limit: u64,
+        daily_item_limit: u32,
+    ) -> Result<()> {
+        instructions::transfer::update_transfer_config(ctx, daily_gold_limit, daily_item_limit)
+    }
+
+    pub fn transfer_gold(ctx: Context<TransferGold>, amount: u64) -> Result<()> {
+        instructions::transfer::transfer_gold(ctx, amount)
+    }
+
+    pub fn transfer_items(ctx: Context<TransferItems>, items: Vec<TransferItem>) -> Result<()> {
+        instructions::transfer::transfer_items(ctx, items)
+    }
+
+    pub fn post_offer(
+        ctx: Context<PostOffer>,
+        offer_id: u64,
+        item: u8,
+        quantity: u16,
+        price_per_unit: u64,
+    ) -> Result<()> {
+        instructions::trading::post_offer(ctx, offer_id, item, quantity, price_per_unit)
+    }
+
+    pub fn fill_offer(ctx: Context<FillOffer>, quantity: u16) -> Result<()> {
+        instructions::trading::fill_offer(ctx, quantity)
+    }
+
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        instructions::trading::cancel_offer(ctx)
+    }
+
+    pub fn craft(ctx: Context<Craft>, recipe_id: u8, times: u16) -> Result<()> {
+        instructions::crafting::craft(ctx, recipe_id, times)
+    }
+
+    pub fn callback_craft(ctx: Context<CallbackCraft>, randomness: [u8; 32]) -> Result<()> {
+        instructions::crafting::callback_craft(ctx, randomness)
+    }
+
+    pub fn cancel_craft(ctx: Context<CancelCraft>) -> Result<()> {
+        instructions::crafting::cancel_craft(ctx)
+    }
+
+    pub fn claim_crafted_items(ctx: Context<ClaimCraftedItems>) -> Result<()> {
+        instructions::crafting::claim_crafted_items(ctx)
+    }
+
+    pub fn identify_relic(ctx: Context<IdentifyRelic>) -> Result<()> {
+        instructions::relic::identify_relic(ctx)
+    }
+
+    pub fn callback_identify_relic(
+        ctx: Context<CallbackIdentifyRelic>,
+        randomness: [u8; 32],
+    ) -> Result<()> {
+        instructions::relic::callback_identify_relic(ctx, randomness)
+    }
+
+    pub fn cancel_identify_relic(ctx: Context<CancelIdentifyRelic>) -> Result<()> {
+        instructions::relic::cancel_identify_relic(ctx)
+    }
+
+    pub fn initialize_gold_bridge(
+        ctx: Context<InitializeGoldBridge>,
+        daily_withdraw_limit: u64,
+        daily_deposit_limit: u64,
+    ) -> Result<()> {
+        instructions::bridge::initialize_gold_bridge(ctx, daily_withdraw_limit, daily_deposit_limit)
+    }
+
+    pub fn update_gold_bridge(
+        ctx: Context<UpdateGoldBridge>,
+        paused: bool,
+        daily_withdraw_limit: u64,
+        daily_deposit_limit: u64,
+    ) -> Result<()> {
+        instructions::bridge::update_gold_bridge(
+            ctx,
+            paused,
+            daily_withdraw_limit,
+            daily_deposit_limit,
+        )
+    }
+
+    pub fn withdraw_gold(ctx: Context<BridgeGold>, amount: u64) -> Result<()> {
+        instructions::bridge::withdraw_gold(ctx, amount)
+    }
+
+    pub fn deposit_gold(ctx: Context<BridgeGold>, amount: u64) -> Result<()> {
+        instructions::bridge::deposit_gold(ctx, amount)
+    }
+
+    pub fn initialize_economy_stats(ctx: Context<InitializeEconomyStats>, shard: u8) -> Result<()> {
+        instructions::stats::initialize_economy_stats(ctx, shard)
+    }
 }
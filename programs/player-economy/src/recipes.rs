@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::PlayerEconomyError;
+
+pub struct RecipeInput {
+    pub item: u8,
+    pub quantity: u16,
+}
+
+pub enum RecipeOutput {
+    Item {
+        item: u8,
+        quantity: u16,
+    },
+    /// One item drawn uniformly from the pool per craft, resolved through VRF.
+    Random(&'static [u8]),
+}
+
+pub struct Recipe {
+    pub id: u8,
+    pub inputs: &'static [RecipeInput],
+    /// Gold burned per craft on top of the inputs.
+    pub gold_cost: u64,
+    pub output: RecipeOutput,
+}
+
+pub const RECIPES: [Recipe; 2] = [
+    Recipe {
+        id: RECIPE_GREATER_SALVE,
+        inputs: &[RecipeInput {
+            item: ITEM_HEALING_SALVE,
+            quantity: 3,
+        }],
+        gold_cost: 0,
+        output: RecipeOutput::Item {
+            item: ITEM_GREATER_SALVE,
+            quantity: 1,
+        },
+    },
+    Recipe {
+        id: RECIPE_RELIC_TRINKET,
+        inputs: &[RecipeInput {
+            item: ITEM_MYSTERY_RELIC,
+            quantity: 1,
+        }],
+        gold_cost: 50,
+        output: RecipeOutput::Random(&[ITEM_LUCKY_CHARM, ITEM_EMBER_CHARM, ITEM_WARDING_CHARM]),
+    },
+];
+
+pub fn recipe(id: u8) -> Result<&'static Recipe> {
+    RECIPES
+        .iter()
+        .find(|recipe| recipe.id == id)
+        .ok_or_else(|| error!(PlayerEconomyError::UnknownRecipe))
+}
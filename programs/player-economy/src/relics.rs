@@ -0,0 +1,78 @@
+use crate::constants::*;
+
+pub enum RelicReward {
+    Gold(u64),
+    Item {
+        item: u8,
+        quantity: u16,
+    },
+    /// Negative trait on the hero chosen when identifying.
+    Curse,
+}
+
+pub struct RelicRewardEntry {
+    pub weight: u16,
+    pub reward: RelicReward,
+}
+
+pub const RELIC_REWARDS: [RelicRewardEntry; 8] = [
+    RelicRewardEntry {
+        weight: 30,
+        reward: RelicReward::Gold(60),
+    },
+    RelicRewardEntry {
+        weight: 10,
+        reward: RelicReward::Gold(150),
+    },
+    RelicRewardEntry {
+        weight: 15,
+        reward: RelicReward::Item {
+            item: ITEM_GREATER_SALVE,
+            quantity: 2,
+        },
+    },
+    RelicRewardEntry {
+        weight: 10,
+        reward: RelicReward::Item {
+            item: ITEM_PHOENIX_FEATHER,
+            quantity: 1,
+        },
+    },
+    RelicRewardEntry {
+        weight: 8,
+        reward: RelicReward::Item {
+            item: ITEM_LUCKY_CHARM,
+            quantity: 1,
+        },
+    },
+    RelicRewardEntry {
+        weight: 8,
+        reward: RelicReward::Item {
+            item: ITEM_EMBER_CHARM,
+            quantity: 1,
+        },
+    },
+    RelicRewardEntry {
+        weight: 8,
+        reward: RelicReward::Item {
+            item: ITEM_WARDING_CHARM,
+            quantity: 1,
+        },
+    },
+    RelicRewardEntry {
+        weight: 11,
+        reward: RelicReward::Curse,
+    },
+];
+
+pub fn roll_relic_reward(roll: u16) -> &'static RelicReward {
+    let total: u16 = RELIC_REWARDS.iter().map(|entry| entry.weight).sum();
+    let mut remaining = roll % total;
+    for entry in RELIC_REWARDS.iter() {
+        if remaining < entry.weight {
+            return &entry.reward;
+        }
+        remaining -= entry.weight;
+    }
+    &RELIC_REWARDS[0].reward
+}
@@ -1,15 +1,20 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::ITEM_COUNT;
+use crate::constants::*;
+use crate::errors::PlayerEconomyError;
 
 #[account]
 pub struct PlayerEconomy {
     pub owner: Pubkey,
     pub gold: u64,
     pub last_grant_ts: i64,
-    pub items: [u16; ITEM_COUNT],
     pub bump: u8,
-    pub reserved: [u8; 5],
+    /// Index into `STASH_TIER_SLOTS`. Carved out of the old reserved bytes, so accounts that
+    /// predate stash tiers read as tier 0.
+    pub stash_tier: u8,
+    pub reserved: [u8; 4],
+    /// One stack per item id held; empty stacks are removed.
+    pub items: Vec<ItemStack>,
 }
 
 impl PlayerEconomy {
@@ -17,9 +22,565 @@ impl PlayerEconomy {
         + 32                   // owner
         + 8                    // gold
         + 8                    // last_grant_ts
-        + (2 * ITEM_COUNT)     // items
         + 1                    // bump
-        + 5; // reserved
+        + 1                    // stash_tier
+        + 4                    // reserved
+        + 4 + (ItemStack::SIZE * MAX_ITEM_TYPES); // items
+
+    pub fn stash_capacity(&self) -> u32 {
+        let tier = (self.stash_tier as usize).min(STASH_TIER_SLOTS.len() - 1);
+        STASH_TIER_SLOTS[tier] as u32
+    }
+
+    pub fn used_slots(&self) -> u32 {
+        self.items
+            .iter()
+            .map(|stack| slots_for(stack.quantity))
+            .sum()
+    }
+
+    /// Whether `quantity` more of `item` fits in the stash.
+    pub fn has_room_for(&self, item: u8, quantity: u16) -> bool {
+        let current = self.quantity(item);
+        let new_total = current as u32 + quantity as u32;
+        let grown = new_total.div_ceil(STASH_SLOT_SIZE as u32) - slots_for(current);
+        self.used_slots() + grown <= self.stash_capacity()
+    }
+
+    pub fn quantity(&self, item: u8) -> u16 {
+        self.items
+            .iter()
+            .find(|stack| stack.item == item)
+            .map_or(0, |stack| stack.quantity)
+    }
+
+    /// Add `quantity` of `item`, respecting the definition's `max_stack` (0 = unlimited).
+    pub fn add_items(&mut self, definition: &ItemDefinition, quantity: u16) -> Result<()> {
+        let new_total = self
+            .quantity(definition.key)
+            .checked_add(quantity)
+            .ok_or(PlayerEconomyError::InventoryOverflow)?;
+        if definition.max_stack > 0 {
+            require!(
+                new_total <= definition.max_stack,
+                PlayerEconomyError::StackLimitExceeded
+            );
+        }
+        require!(
+            self.has_room_for(definition.key, quantity),
+            PlayerEconomyError::StashFull
+        );
+        self.set_quantity(definition.key, new_total)
+    }
+
+    /// Give back items the player already owned, such as a cancelled offer's escrow. Stack and
+    /// stash limits are skipped so the return can't be blocked by what was gained since.
+    pub fn return_items(&mut self, item: u8, quantity: u16) -> Result<()> {
+        let new_total = self
+            .quantity(item)
+            .checked_add(quantity)
+            .ok_or(PlayerEconomyError::InventoryOverflow)?;
+        self.set_quantity(item, new_total)
+    }
+
+    fn set_quantity(&mut self, item: u8, quantity: u16) -> Result<()> {
+        if let Some(stack) = self.items.iter_mut().find(|stack| stack.item == item) {
+            stack.quantity = quantity;
+        } else {
+            require!(
+                self.items.len() < MAX_ITEM_TYPES,
+                PlayerEconomyError::InventoryFull
+            );
+            self.items.push(ItemStack { item, quantity });
+        }
+        Ok(())
+    }
+
+    pub fn remove_items(&mut self, item: u8, quantity: u16) -> Result<()> {
+        let position = self
+            .items
+            .iter()
+            .position(|stack| stack.item == item)
+            .ok_or(PlayerEconomyError::InsufficientStock)?;
+        let stack = &mut self.items[position];
+        require!(
+            stack.quantity >= quantity,
+            PlayerEconomyError::InsufficientStock
+        );
+        stack.quantity -= quantity;
+        if stack.quantity == 0 {
+            self.items.remove(position);
+        }
+        Ok(())
+    }
+}
+
+fn slots_for(quantity: u16) -> u32 {
+    (quantity as u32).div_ceil(STASH_SLOT_SIZE as u32)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemStack {
+    pub item: u8,
+    pub quantity: u16,
+}
+
+impl ItemStack {
+    pub const SIZE: usize = 1 + 2;
+}
+
+/// Admin-managed table of every item the economy knows about.
+#[account]
+pub struct ItemRegistry {
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub items: Vec<ItemDefinition>,
+}
+
+impl ItemRegistry {
+    pub const LEN: usize = 8 + 32 + 1 + 4 + (ItemDefinition::SIZE * MAX_ITEM_TYPES);
+
+    pub fn get(&self, item: u8) -> Option<&ItemDefinition> {
+        self.items.iter().find(|definition| definition.key == item)
+    }
+
+    pub fn definition(&self, item: u8) -> Result<&ItemDefinition> {
+        self.get(item)
+            .ok_or_else(|| error!(PlayerEconomyError::UnknownItem))
+    }
+}
+
+/// Global shop book: per-item stock, restocked each epoch, and the demand that drives prices.
+#[account]
+pub struct ShopState {
+    pub authority: Pubkey,
+    pub bump: u8,
+    /// Epoch (`unix_timestamp / SHOP_EPOCH_SECONDS`) the stock was last restocked in.
+    pub epoch: i64,
+    pub items: Vec<ShopItemState>,
+}
+
+impl ShopState {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 4 + (ShopItemState::SIZE * MAX_ITEM_TYPES);
+
+    /// Restock every item and let demand cool off once per elapsed epoch.
+    pub fn roll_epoch(&mut self, now: i64) {
+        let epoch = now.div_euclid(SHOP_EPOCH_SECONDS);
+        if epoch <= self.epoch {
+            return;
+        }
+
+        let elapsed = (epoch - self.epoch).min(30) as u32;
+        for entry in self.items.iter_mut() {
+            entry.stock = entry.daily_stock;
+            entry.demand /= 1 << elapsed;
+            entry.sold_today = 0;
+        }
+        self.epoch = epoch;
+    }
+
+    /// Shop entry for `item`, created with the default daily stock on first trade.
+    pub fn entry_mut(&mut self, item: u8) -> Result<&mut ShopItemState> {
+        let position = match self.items.iter().position(|entry| entry.item == item) {
+            Some(position) => position,
+            None => {
+                require!(
+                    self.items.len() < MAX_ITEM_TYPES,
+                    PlayerEconomyError::RegistryFull
+                );
+                self.items.push(ShopItemState {
+                    item,
+                    daily_stock: SHOP_DEFAULT_DAILY_STOCK,
+                    stock: SHOP_DEFAULT_DAILY_STOCK,
+                    demand: 0,
+                    sold_today: 0,
+                });
+                self.items.len() - 1
+            }
+        };
+        Ok(&mut self.items[position])
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShopItemState {
+    pub item: u8,
+    pub daily_stock: u16,
+    pub stock: u16,
+    /// Net units bought minus sold; halves every epoch.
+    pub demand: i32,
+    /// Units sold back this epoch, used to decay the sell price.
+    pub sold_today: u16,
+}
+
+impl ShopItemState {
+    pub const SIZE: usize = 1 + 2 + 2 + 4 + 2;
+
+    /// Unit price for buying `quantity`, priced at the midpoint of the demand the order creates.
+    pub fn buy_price(&self, base: u64, quantity: u16) -> Result<u64> {
+        let demand = self.demand as i64 + (quantity as i64 / 2);
+        scale_price(base, demand_multiplier_bps(demand))
+    }
+
+    /// Unit price for selling `quantity`, after demand and repeated-sale decay.
+    pub fn sell_price(&self, base: u64, quantity: u16) -> Result<u64> {
+        let half = quantity as i64 / 2;
+        let demand = self.demand as i64 - half;
+        let dumped = self.sold_today as i64 + half;
+        let decay_bps = (BPS_DENOMINATOR - dumped * SHOP_SELL_DECAY_BPS).max(SHOP_MIN_SELL_BPS);
+        let price = scale_price(base, demand_multiplier_bps(demand))?;
+        scale_price(price, decay_bps)
+    }
+
+    pub fn record_purchase(&mut self, quantity: u16) {
+        self.stock -= quantity;
+        self.demand = self
+            .demand
+            .saturating_add(quantity as i32)
+            .min(SHOP_MAX_DEMAND);
+    }
+
+    pub fn record_sale(&mut self, quantity: u16) {
+        self.sold_today = self.sold_today.saturating_add(quantity);
+        self.demand = self
+            .demand
+            .saturating_sub(quantity as i32)
+            .max(-SHOP_MAX_DEMAND);
+    }
+}
+
+fn demand_multiplier_bps(demand: i64) -> i64 {
+    (BPS_DENOMINATOR + demand * SHOP_DEMAND_STEP_BPS).clamp(SHOP_MIN_PRICE_BPS, SHOP_MAX_PRICE_BPS)
+}
+
+fn scale_price(price: u64, bps: i64) -> Result<u64> {
+    let scaled = (price as u128)
+        .checked_mul(bps as u128)
+        .ok_or(PlayerEconomyError::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    // Never round a priced item down to free
+    let scaled = if price > 0 { scaled.max(1) } else { 0 };
+    u64::try_from(scaled).map_err(|_| error!(PlayerEconomyError::MathOverflow))
+}
+
+/// Admin limits on player-to-player transfers. A limit of zero disables that check.
+#[account]
+pub struct TransferConfig {
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub daily_gold_limit: u64,
+    /// Total item units a player may send per window, across all item types.
+    pub daily_item_limit: u32,
+}
+
+impl TransferConfig {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 4;
+}
+
+/// Per-player running totals for the current transfer window. Receipts are capped by the same
+/// daily limits as sends, so many senders can't funnel into one account.
+#[account]
+pub struct TransferLedger {
+    pub owner: Pubkey,
+    pub bump: u8,
+    /// Window index (`unix_timestamp / TRANSFER_WINDOW_SECONDS`) the totals belong to.
+    pub window: i64,
+    pub gold_sent: u64,
+    pub items_sent: u32,
+    pub gold_received: u64,
+    pub items_received: u32,
+}
+
+impl TransferLedger {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + 4 + 8 + 4;
+
+    pub fn roll_window(&mut self, now: i64) {
+        let window = now.div_euclid(TRANSFER_WINDOW_SECONDS);
+        if window != self.window {
+            self.window = window;
+            self.gold_sent = 0;
+            self.items_sent = 0;
+            self.gold_received = 0;
+            self.items_received = 0;
+        }
+    }
+}
+
+/// Trading post listing; the offered items sit in escrow here until filled or cancelled.
+#[account]
+pub struct TradeOffer {
+    pub seller: Pubkey,
+    pub offer_id: u64,
+    pub item: u8,
+    pub quantity: u16,
+    pub price_per_unit: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl TradeOffer {
+    pub const LEN: usize = 8 + 32 + 8 + 1 + 2 + 8 + 8 + 1;
+}
+
+/// Per-player slot for a randomized craft waiting on VRF. Inputs are already consumed.
+#[account]
+pub struct CraftRequest {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub pending: bool,
+    pub recipe_id: u8,
+    pub times: u16,
+    pub requested_at: i64,
+    /// Rolled outputs that did not fit in the inventory, held until `claim_crafted_items`.
+    pub unclaimed: Vec<ItemStack>,
+}
+
+impl CraftRequest {
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 1 + 2 + 8 + 4 + (ItemStack::SIZE * MAX_ITEM_TYPES);
+
+    /// Hold `quantity` of `item` for a later claim.
+    pub fn hold(&mut self, item: u8, quantity: u16) -> Result<()> {
+        if let Some(stack) = self.unclaimed.iter_mut().find(|stack| stack.item == item) {
+            stack.quantity = stack
+                .quantity
+                .checked_add(quantity)
+                .ok_or(PlayerEconomyError::InventoryOverflow)?;
+        } else {
+            require!(
+                self.unclaimed.len() < MAX_ITEM_TYPES,
+                PlayerEconomyError::InventoryFull
+            );
+            self.unclaimed.push(ItemStack { item, quantity });
+        }
+        Ok(())
+    }
+}
+
+/// Per-player slot for a relic identification waiting on VRF. Relic and fee are already paid.
+#[account]
+pub struct IdentifyRequest {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub pending: bool,
+    /// Hero that takes the curse if one is rolled.
+    pub hero: Pubkey,
+    pub requested_at: i64,
+}
+
+impl IdentifyRequest {
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 32 + 8;
+}
+
+/// Whether a request still waiting on VRF at `now` may be cancelled.
+pub fn vrf_request_expired(requested_at: i64, now: i64) -> bool {
+    now >= requested_at.saturating_add(VRF_REQUEST_TIMEOUT_SECONDS)
+}
+
+/// Admin-tuned parameters of the daily gold faucet.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct FaucetParams {
+    pub base_reward: u64,
+    /// Added per consecutive day after the first.
+    pub streak_bonus: u64,
+    /// Streak length after which the reward stops growing.
+    pub max_streak: u16,
+    /// Most gold one account can ever draw from the faucet; 0 = unlimited.
+    pub lifetime_cap: u64,
+    /// Above this balance the grant shrinks in proportion; 0 = never.
+    pub diminish_threshold: u64,
+}
+
+impl FaucetParams {
+    pub const SIZE: usize = 8 + 8 + 2 + 8 + 8;
+
+    /// Grant for a claim on day `streak` of a streak, before the lifetime cap.
+    pub fn reward(&self, streak: u16, gold: u64) -> u64 {
+        let bonus_days = streak.min(self.max_streak).saturating_sub(1) as u64;
+        let reward = self
+            .base_reward
+            .saturating_add(self.streak_bonus.saturating_mul(bonus_days));
+        if self.diminish_threshold > 0 && gold > self.diminish_threshold {
+            (reward as u128 * self.diminish_threshold as u128 / gold as u128) as u64
+        } else {
+            reward
+        }
+    }
+}
+
+#[account]
+pub struct FaucetConfig {
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub params: FaucetParams,
+}
+
+impl FaucetConfig {
+    pub const LEN: usize = 8 + 32 + 1 + FaucetParams::SIZE;
+}
+
+/// Per-player streak and lifetime totals for the daily faucet.
+#[account]
+pub struct FaucetLedger {
+    pub owner: Pubkey,
+    pub bump: u8,
+    /// Faucet day (`unix_timestamp / FAUCET_DAY_SECONDS`) of the last claim.
+    pub last_claim_day: i64,
+    pub streak: u16,
+    pub lifetime_granted: u64,
+}
+
+impl FaucetLedger {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 2 + 8;
+}
+
+/// Bridge between internal gold and the SPL gold mint. The bridge PDA is the mint authority.
+#[account]
+pub struct GoldBridge {
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub paused: bool,
+    /// Per-player limits per window; 0 = unlimited.
+    pub daily_withdraw_limit: u64,
+    pub daily_deposit_limit: u64,
+}
+
+impl GoldBridge {
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 1 + 8 + 8;
+}
+
+/// Per-player bridge volume in the current window.
+#[account]
+pub struct BridgeLedger {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub window: i64,
+    pub withdrawn: u64,
+    pub deposited: u64,
+}
+
+impl BridgeLedger {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + 8;
+
+    pub fn roll_window(&mut self, now: i64) {
+        let window = now.div_euclid(BRIDGE_WINDOW_SECONDS);
+        if window != self.window {
+            self.window = window;
+            self.withdrawn = 0;
+            self.deposited = 0;
+        }
+    }
+}
+
+/// Global supply counters. Every instruction that creates or destroys gold or items records
+/// it here, both lifetime and in a ring of daily buckets.
+#[account]
+pub struct EconomyStats {
+    pub bump: u8,
+    pub shard: u8,
+    pub gold_minted: u64,
+    pub gold_burned: u64,
+    pub items: Vec<ItemSupply>,
+    /// Indexed by `day % ECONOMY_STATS_DAYS`.
+    pub daily: Vec<DailyEconomyBucket>,
+}
+
+impl EconomyStats {
+    pub const LEN: usize = 8
+        + 1
+        + 1
+        + 8
+        + 8
+        + 4
+        + (ItemSupply::SIZE * MAX_ITEM_TYPES)
+        + 4
+        + (DailyEconomyBucket::SIZE * ECONOMY_STATS_DAYS);
+
+    pub fn record_gold_minted(&mut self, now: i64, amount: u64) {
+        self.gold_minted = self.gold_minted.saturating_add(amount);
+        let bucket = self.bucket_mut(now);
+        bucket.gold_minted = bucket.gold_minted.saturating_add(amount);
+    }
+
+    pub fn record_gold_burned(&mut self, now: i64, amount: u64) {
+        self.gold_burned = self.gold_burned.saturating_add(amount);
+        let bucket = self.bucket_mut(now);
+        bucket.gold_burned = bucket.gold_burned.saturating_add(amount);
+    }
+
+    pub fn record_items_created(&mut self, now: i64, item: u8, quantity: u16) {
+        if let Some(supply) = supply_mut(&mut self.items, item) {
+            supply.created = supply.created.saturating_add(quantity as u64);
+        }
+        if let Some(supply) = supply_mut(&mut self.bucket_mut(now).items, item) {
+            supply.created = supply.created.saturating_add(quantity as u64);
+        }
+    }
+
+    pub fn record_items_destroyed(&mut self, now: i64, item: u8, quantity: u16) {
+        if let Some(supply) = supply_mut(&mut self.items, item) {
+            supply.destroyed = supply.destroyed.saturating_add(quantity as u64);
+        }
+        if let Some(supply) = supply_mut(&mut self.bucket_mut(now).items, item) {
+            supply.destroyed = supply.destroyed.saturating_add(quantity as u64);
+        }
+    }
+
+    /// Today's bucket, cleared first if it still holds an older day.
+    fn bucket_mut(&mut self, now: i64) -> &mut DailyEconomyBucket {
+        let day = now.div_euclid(ECONOMY_STATS_DAY_SECONDS);
+        if self.daily.len() < ECONOMY_STATS_DAYS {
+            self.daily
+                .resize(ECONOMY_STATS_DAYS, DailyEconomyBucket::default());
+        }
+        let bucket = &mut self.daily[day.rem_euclid(ECONOMY_STATS_DAYS as i64) as usize];
+        if bucket.day != day {
+            *bucket = DailyEconomyBucket {
+                day,
+                ..DailyEconomyBucket::default()
+            };
+        }
+        bucket
+    }
+}
+
+/// Counter for `item`, or `None` once the table is full. Telemetry never blocks gameplay.
+fn supply_mut(items: &mut Vec<ItemSupply>, item: u8) -> Option<&mut ItemSupply> {
+    let position = match items.iter().position(|supply| supply.item == item) {
+        Some(position) => position,
+        None if items.len() < MAX_ITEM_TYPES => {
+            items.push(ItemSupply {
+                item,
+                ..ItemSupply::default()
+            });
+            items.len() - 1
+        }
+        None => return None,
+    };
+    Some(&mut items[position])
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct ItemSupply {
+    pub item: u8,
+    pub created: u64,
+    pub destroyed: u64,
+}
+
+impl ItemSupply {
+    pub const SIZE: usize = 1 + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct DailyEconomyBucket {
+    pub day: i64,
+    pub gold_minted: u64,
+    pub gold_burned: u64,
+    pub items: Vec<ItemSupply>,
+}
+
+impl DailyEconomyBucket {
+    pub const SIZE: usize = 8 + 8 + 8 + 4 + (ItemSupply::SIZE * MAX_ITEM_TYPES);
 }
 
 #[event]
@@ -32,7 +593,10 @@ pub struct ItemPurchased {
     pub owner: Pubkey,
     pub item: u8,
     pub quantity: u16,
+    pub base_price: u64,
+    /// Price actually charged per unit after the demand curve.
     pub unit_price: u64,
+    pub remaining_stock: u16,
 }
 
 #[event]
@@ -40,6 +604,8 @@ pub struct ItemSold {
     pub owner: Pubkey,
     pub item: u8,
     pub quantity: u16,
+    pub base_price: u64,
+    /// Price actually paid per unit after demand and sell-back decay.
     pub unit_price: u64,
 }
 
@@ -51,9 +617,25 @@ pub struct GoldSpent {
 }
 
 #[event]
-pub struct HourlyGrantClaimed {
+pub struct StashUpgraded {
+    pub owner: Pubkey,
+    pub tier: u8,
+    pub capacity: u32,
+    pub cost: u64,
+}
+
+#[event]
+pub struct FaucetConfigUpdated {
+    pub authority: Pubkey,
+    pub params: FaucetParams,
+}
+
+#[event]
+pub struct DailyGrantClaimed {
     pub owner: Pubkey,
     pub amount: u64,
+    pub streak: u16,
+    pub lifetime_granted: u64,
     pub next_available_at: i64,
 }
 
@@ -64,97 +646,320 @@ pub struct ItemConsumed {
     pub quantity: u16,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
-pub struct ItemConsumption {
-    pub item: ItemKey,
+#[event]
+pub struct ItemRegistryInitialized {
+    pub authority: Pubkey,
+    pub item_count: u8,
+}
+
+#[event]
+pub struct ItemDefinitionUpdated {
+    pub authority: Pubkey,
+    pub item: u8,
+}
+
+#[event]
+pub struct PlayerEconomyMigrated {
+    pub owner: Pubkey,
+    pub item_types: u8,
+}
+
+#[event]
+pub struct ShopInitialized {
+    pub authority: Pubkey,
+    pub epoch: i64,
+}
+
+#[event]
+pub struct ShopStockConfigured {
+    pub authority: Pubkey,
+    pub item: u8,
+    pub daily_stock: u16,
+}
+
+#[event]
+pub struct TransferConfigUpdated {
+    pub authority: Pubkey,
+    pub daily_gold_limit: u64,
+    pub daily_item_limit: u32,
+}
+
+#[event]
+pub struct GoldTransferred {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ItemsTransferred {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub items: Vec<TransferItem>,
+}
+
+#[event]
+pub struct OfferPosted {
+    pub seller: Pubkey,
+    pub offer_id: u64,
+    pub item: u8,
     pub quantity: u16,
+    pub price_per_unit: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
-pub struct LootDepositItem {
-    pub item: ItemKey,
+#[event]
+pub struct OfferFilled {
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub offer_id: u64,
+    pub item: u8,
     pub quantity: u16,
+    pub price_per_unit: u64,
+    /// Gold burned from the seller's proceeds.
+    pub fee: u64,
+    pub remaining: u16,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
-#[repr(u8)]
-pub enum ItemKey {
-    PouchGold = 0,
-    StressTonic = 1,
-    MinorTorch = 2,
-    HealingSalve = 3,
-    MysteryRelic = 4,
-    CalmingIncense = 5,
-    PhoenixFeather = 6,
+#[event]
+pub struct OfferCancelled {
+    pub seller: Pubkey,
+    pub offer_id: u64,
+    pub item: u8,
+    pub returned: u16,
 }
 
-impl ItemKey {
-    pub fn index(self) -> usize {
-        self as usize
-    }
+#[event]
+pub struct CraftRequested {
+    pub owner: Pubkey,
+    pub recipe_id: u8,
+    pub times: u16,
+}
 
-    pub fn definition(self) -> &'static ItemDefinition {
-        &ITEM_DEFINITIONS[self.index()]
-    }
+#[event]
+pub struct ItemsCrafted {
+    pub owner: Pubkey,
+    pub recipe_id: u8,
+    pub times: u16,
+    pub gold_spent: u64,
+    pub outputs: Vec<ItemStack>,
+}
+
+#[event]
+pub struct CraftCancelled {
+    pub owner: Pubkey,
+    pub recipe_id: u8,
+    pub times: u16,
+}
+
+#[event]
+pub struct CraftedItemsClaimed {
+    pub owner: Pubkey,
+    pub items: Vec<ItemStack>,
 }
 
-#[derive(Clone, Copy)]
+#[event]
+pub struct RelicIdentifyRequested {
+    pub owner: Pubkey,
+    pub hero: Pubkey,
+}
+
+#[event]
+pub struct RelicIdentifyCancelled {
+    pub owner: Pubkey,
+    pub hero: Pubkey,
+}
+
+#[event]
+pub struct RelicIdentified {
+    pub owner: Pubkey,
+    pub hero: Pubkey,
+    /// One of the `RELIC_REWARD_*` kinds.
+    pub reward: u8,
+    pub gold: u64,
+    pub item: u8,
+    pub quantity: u16,
+}
+
+#[event]
+pub struct GoldBridgeUpdated {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub daily_withdraw_limit: u64,
+    pub daily_deposit_limit: u64,
+}
+
+#[event]
+pub struct GoldWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct GoldDeposited {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ItemConsumption {
+    pub item: u8,
+    pub quantity: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TransferItem {
+    pub item: u8,
+    pub quantity: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct LootDepositItem {
+    pub item: u8,
+    pub quantity: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ItemDefinition {
-    pub key: ItemKey,
+    pub key: u8,
     pub buy_price: Option<u64>,
     pub sell_price: Option<u64>,
+    /// Most a player may hold; 0 = unlimited.
     pub max_stack: u16,
     pub usable: bool,
+    /// Only found in dungeons; cannot be packed for an adventure.
+    pub loot_only: bool,
+    /// `ITEM_EFFECT_*` applied when the item is used.
+    pub effect_id: u8,
+    /// Strength of the effect, e.g. HP healed or torch restored.
+    pub effect_value: u16,
 }
 
-const ITEM_DEFINITIONS: [ItemDefinition; ITEM_COUNT] = [
+impl ItemDefinition {
+    pub const SIZE: usize = 1 + 9 + 9 + 2 + 1 + 1 + 1 + 2;
+}
+
+/// What a fresh registry is seeded with.
+pub const DEFAULT_ITEM_DEFINITIONS: [ItemDefinition; 12] = [
     ItemDefinition {
-        key: ItemKey::PouchGold,
+        key: ITEM_POUCH_GOLD,
         buy_price: None,
         sell_price: Some(25),
         max_stack: 0,
         usable: false,
+        loot_only: true,
+        effect_id: ITEM_EFFECT_NONE,
+        effect_value: 0,
     },
     ItemDefinition {
-        key: ItemKey::StressTonic,
+        key: ITEM_STRESS_TONIC,
         buy_price: Some(21),
         sell_price: Some(10),
         max_stack: 0,
         usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_RELIEVE_STRESS,
+        effect_value: 20,
     },
     ItemDefinition {
-        key: ItemKey::MinorTorch,
+        key: ITEM_MINOR_TORCH,
         buy_price: Some(14),
         sell_price: Some(7),
         max_stack: 0,
         usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_RESTORE_TORCH,
+        effect_value: 25,
     },
     ItemDefinition {
-        key: ItemKey::HealingSalve,
+        key: ITEM_HEALING_SALVE,
         buy_price: Some(32),
         sell_price: Some(16),
         max_stack: 0,
         usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_HEAL,
+        effect_value: 30,
     },
     ItemDefinition {
-        key: ItemKey::MysteryRelic,
+        key: ITEM_MYSTERY_RELIC,
         buy_price: None,
         sell_price: Some(70),
         max_stack: 0,
         usable: false,
+        loot_only: true,
+        effect_id: ITEM_EFFECT_NONE,
+        effect_value: 0,
     },
     ItemDefinition {
-        key: ItemKey::CalmingIncense,
+        key: ITEM_CALMING_INCENSE,
         buy_price: None,
         sell_price: Some(45),
         max_stack: 3,
         usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_CALM,
+        effect_value: 30,
     },
     ItemDefinition {
-        key: ItemKey::PhoenixFeather,
+        key: ITEM_PHOENIX_FEATHER,
         buy_price: None,
         sell_price: Some(110),
         max_stack: 1,
+        usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_REVIVE,
+        effect_value: 0,
+    },
+    ItemDefinition {
+        key: ITEM_FIREWOOD,
+        buy_price: Some(18),
+        sell_price: Some(8),
+        max_stack: 0,
         usable: false,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_NONE,
+        effect_value: 0,
+    },
+    ItemDefinition {
+        key: ITEM_GREATER_SALVE,
+        buy_price: None,
+        sell_price: Some(40),
+        max_stack: 5,
+        usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_HEAL,
+        effect_value: 60,
+    },
+    ItemDefinition {
+        key: ITEM_LUCKY_CHARM,
+        buy_price: None,
+        sell_price: Some(45),
+        max_stack: 3,
+        usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_RELIEVE_STRESS,
+        effect_value: 40,
+    },
+    ItemDefinition {
+        key: ITEM_EMBER_CHARM,
+        buy_price: None,
+        sell_price: Some(45),
+        max_stack: 3,
+        usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_RESTORE_TORCH,
+        effect_value: 50,
+    },
+    ItemDefinition {
+        key: ITEM_WARDING_CHARM,
+        buy_price: None,
+        sell_price: Some(45),
+        max_stack: 3,
+        usable: true,
+        loot_only: false,
+        effect_id: ITEM_EFFECT_CALM,
+        effect_value: 50,
     },
 ];